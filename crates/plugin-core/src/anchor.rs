@@ -0,0 +1,2 @@
+pub const DISCRIMINATOR_SIZE: usize = 8;
+pub const PUBKEY_SIZE: usize = 32;
@@ -1,6 +1,5 @@
-use crate::state::VoterWeightAction;
+use crate::voter_weight_action::VoterWeightAction;
 use enum_dispatch::enum_dispatch;
-use num_traits::FromPrimitive;
 use solana_program::pubkey::Pubkey;
 use spl_governance::state::token_owner_record::TokenOwnerRecordV2;
 
@@ -43,16 +42,8 @@ impl GenericVoterWeight for spl_governance_addin_api::voter_weight::VoterWeightR
         self.voter_weight
     }
 
-    // The GenericVoterWeight interface expects a crate-defined VoterWeightAction.
-    // This is identical to spl_governance_addin_api::voter_weight::VoterWeightAction, but added here
-    // so that Anchor will create the mapping correctly in the IDL.
-    // This function converts the spl_governance_addin_api::voter_weight::VoterWeightAction to the
-    // crate-defined VoterWeightAction by mapping the enum values by integer.
-    // Note - it is imperative that the two enums stay in sync to avoid errors here.
     fn get_weight_action(&self) -> Option<VoterWeightAction> {
-        self.weight_action
-            .clone()
-            .map(|x| FromPrimitive::from_u32(x as u32).unwrap())
+        self.weight_action.clone().map(VoterWeightAction::from)
     }
 
     fn get_weight_action_target(&self) -> Option<Pubkey> {
@@ -63,3 +54,35 @@ impl GenericVoterWeight for spl_governance_addin_api::voter_weight::VoterWeightR
         self.voter_weight_expiry
     }
 }
+
+// Add the generic voter weight trait to TokenOwnerRecord structs
+// so that they can be used as input voter weights into the plugin
+impl GenericVoterWeight for TokenOwnerRecordV2 {
+    fn get_governing_token_mint(&self) -> Pubkey {
+        self.governing_token_mint
+    }
+
+    fn get_governing_token_owner(&self) -> Pubkey {
+        self.governing_token_owner
+    }
+
+    fn get_realm(&self) -> Pubkey {
+        self.realm
+    }
+
+    fn get_voter_weight(&self) -> u64 {
+        self.governing_token_deposit_amount
+    }
+
+    fn get_weight_action(&self) -> Option<VoterWeightAction> {
+        None
+    }
+
+    fn get_weight_action_target(&self) -> Option<Pubkey> {
+        None
+    }
+
+    fn get_voter_weight_expiry(&self) -> Option<u64> {
+        None
+    }
+}
@@ -0,0 +1,261 @@
+use crate::generic_voter_weight::{GenericVoterWeight, GenericVoterWeightEnum};
+use crate::voter_weight_action::VoterWeightAction;
+use anchor_lang::prelude::*;
+use spl_governance::state::token_owner_record::get_token_owner_record_data_for_realm_and_governing_mint;
+use spl_governance_tools::account::get_account_data;
+
+/// Errors returned when the input voter weight can't be resolved
+/// Plugins map them to their own error codes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InputVoterWeightError {
+    /// The input account is not a TokenOwnerRecord for the realm and governing_token_mint
+    InvalidTokenOwnerRecord,
+
+    /// The input account is not a VoterWeightRecord owned by the predecessor plugin
+    InvalidVoterWeightRecord,
+
+    /// The input voter weight is for a different realm
+    InvalidRealm,
+
+    /// The input voter weight is for a different governing_token_mint
+    InvalidGoverningTokenMint,
+
+    /// The input voter weight is for a different governing_token_owner
+    InvalidGoverningTokenOwner,
+
+    /// The input voter weight expired before the current slot
+    VoterWeightExpired,
+
+    /// The input voter weight is for a different weight_action
+    InvalidWeightAction,
+
+    /// The input voter weight is for a different weight_action_target
+    InvalidWeightActionTarget,
+}
+
+/// The plugin configuration and the voter weight scope the input voter weight is resolved for
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputVoterWeightScope {
+    /// spl-governance instance the realm belongs to
+    pub governance_program_id: Pubkey,
+
+    /// The predecessor plugin which must own the input VoterWeightRecord
+    /// If None then the input voter weight is the voter TokenOwnerRecord
+    pub previous_voter_weight_plugin_program_id: Option<Pubkey>,
+
+    /// The realm of the plugin Registrar
+    pub realm: Pubkey,
+
+    /// The governing_token_mint of the plugin Registrar
+    pub governing_token_mint: Pubkey,
+
+    /// The voter the voter weight is resolved for
+    pub governing_token_owner: Pubkey,
+
+    /// The action the plugin evaluates the voter weight for
+    /// None if the plugin takes the action from the input voter weight
+    pub weight_action: Option<VoterWeightAction>,
+
+    /// The target of the action the plugin evaluates the voter weight for
+    /// None if the plugin takes the target from the input voter weight
+    pub weight_action_target: Option<Pubkey>,
+
+    /// The current slot used to reject stale input voter weight
+    pub current_slot: u64,
+}
+
+/// Resolves the input voter weight of the plugin
+/// If the plugin has a predecessor plugin then the input account must be the predecessor VoterWeightRecord
+/// Otherwise the input account must be the voter TokenOwnerRecord for the realm and governing_token_mint
+///
+/// Every plugin in a chain resolves the VoterWeightRecord of its immediate predecessor only
+/// and because the expiry, action and target are validated and carried forward at every step the chain can be of any depth
+pub fn resolve_input_voter_weight(
+    input_account: &AccountInfo,
+    scope: &InputVoterWeightScope,
+) -> std::result::Result<GenericVoterWeightEnum, InputVoterWeightError> {
+    let input_voter_weight = match scope.previous_voter_weight_plugin_program_id {
+        None => {
+            // If there is no predecessor plugin, then the input account must be a TokenOwnerRecordV2
+            let record = get_token_owner_record_data_for_realm_and_governing_mint(
+                &scope.governance_program_id,
+                input_account,
+                &scope.realm,
+                &scope.governing_token_mint,
+            )
+            .map_err(|_| InputVoterWeightError::InvalidTokenOwnerRecord)?;
+
+            GenericVoterWeightEnum::TokenOwnerRecord(record)
+        }
+        Some(predecessor) => {
+            // If there is a predecessor plugin, then the input account must be a VoterWeightRecord owned by the predecessor
+            let record: spl_governance_addin_api::voter_weight::VoterWeightRecord =
+                get_account_data(&predecessor, input_account)
+                    .map_err(|_| InputVoterWeightError::InvalidVoterWeightRecord)?;
+
+            GenericVoterWeightEnum::VoterWeightRecord(record)
+        }
+    };
+
+    assert_input_voter_weight_scope(&input_voter_weight, scope)?;
+
+    Ok(input_voter_weight)
+}
+
+/// Asserts the input voter weight is for the realm, governing_token_mint and governing_token_owner of the given scope
+/// It also asserts the input voter weight is not stale and it's compatible with the action and target of the scope
+pub fn assert_input_voter_weight_scope(
+    input_voter_weight: &impl GenericVoterWeight,
+    scope: &InputVoterWeightScope,
+) -> std::result::Result<(), InputVoterWeightError> {
+    if input_voter_weight.get_realm() != scope.realm {
+        return Err(InputVoterWeightError::InvalidRealm);
+    }
+
+    if input_voter_weight.get_governing_token_mint() != scope.governing_token_mint {
+        return Err(InputVoterWeightError::InvalidGoverningTokenMint);
+    }
+
+    if input_voter_weight.get_governing_token_owner() != scope.governing_token_owner {
+        return Err(InputVoterWeightError::InvalidGoverningTokenOwner);
+    }
+
+    if let Some(voter_weight_expiry) = input_voter_weight.get_voter_weight_expiry() {
+        if voter_weight_expiry < scope.current_slot {
+            return Err(InputVoterWeightError::VoterWeightExpired);
+        }
+    }
+
+    if let (Some(input_weight_action), Some(weight_action)) =
+        (input_voter_weight.get_weight_action(), scope.weight_action)
+    {
+        if input_weight_action != weight_action {
+            return Err(InputVoterWeightError::InvalidWeightAction);
+        }
+    }
+
+    if let (Some(input_weight_action_target), Some(weight_action_target)) = (
+        input_voter_weight.get_weight_action_target(),
+        scope.weight_action_target,
+    ) {
+        if input_weight_action_target != weight_action_target {
+            return Err(InputVoterWeightError::InvalidWeightActionTarget);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use spl_governance_addin_api::voter_weight::VoterWeightRecord;
+
+    fn create_test_scope() -> InputVoterWeightScope {
+        InputVoterWeightScope {
+            governance_program_id: Pubkey::new_unique(),
+            previous_voter_weight_plugin_program_id: Some(Pubkey::new_unique()),
+            realm: Pubkey::new_unique(),
+            governing_token_mint: Pubkey::new_unique(),
+            governing_token_owner: Pubkey::new_unique(),
+            weight_action: Some(VoterWeightAction::CastVote),
+            weight_action_target: Some(Pubkey::new_unique()),
+            current_slot: 100,
+        }
+    }
+
+    fn create_test_voter_weight_record(scope: &InputVoterWeightScope) -> VoterWeightRecord {
+        VoterWeightRecord {
+            account_discriminator: VoterWeightRecord::ACCOUNT_DISCRIMINATOR,
+            realm: scope.realm,
+            governing_token_mint: scope.governing_token_mint,
+            governing_token_owner: scope.governing_token_owner,
+            voter_weight: 10,
+            voter_weight_expiry: Some(scope.current_slot),
+            weight_action: Some(
+                spl_governance_addin_api::voter_weight::VoterWeightAction::CastVote,
+            ),
+            weight_action_target: scope.weight_action_target,
+            reserved: [0; 8],
+        }
+    }
+
+    #[test]
+    fn test_assert_input_voter_weight_scope() {
+        // Arrange
+        let scope = create_test_scope();
+        let voter_weight_record = create_test_voter_weight_record(&scope);
+
+        // Act
+        let result = assert_input_voter_weight_scope(&voter_weight_record, &scope);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_assert_input_voter_weight_scope_with_expired_voter_weight_error() {
+        // Arrange
+        let scope = create_test_scope();
+        let mut voter_weight_record = create_test_voter_weight_record(&scope);
+        voter_weight_record.voter_weight_expiry = Some(scope.current_slot - 1);
+
+        // Act
+        let result = assert_input_voter_weight_scope(&voter_weight_record, &scope);
+
+        // Assert
+        assert_eq!(result, Err(InputVoterWeightError::VoterWeightExpired));
+    }
+
+    #[test]
+    fn test_assert_input_voter_weight_scope_with_invalid_weight_action_error() {
+        // Arrange
+        let scope = create_test_scope();
+        let mut voter_weight_record = create_test_voter_weight_record(&scope);
+        voter_weight_record.weight_action =
+            Some(spl_governance_addin_api::voter_weight::VoterWeightAction::CreateProposal);
+
+        // Act
+        let result = assert_input_voter_weight_scope(&voter_weight_record, &scope);
+
+        // Assert
+        assert_eq!(result, Err(InputVoterWeightError::InvalidWeightAction));
+    }
+
+    #[test]
+    fn test_assert_input_voter_weight_scope_with_invalid_weight_action_target_error() {
+        // Arrange
+        let scope = create_test_scope();
+        let mut voter_weight_record = create_test_voter_weight_record(&scope);
+        voter_weight_record.weight_action_target = Some(Pubkey::new_unique());
+
+        // Act
+        let result = assert_input_voter_weight_scope(&voter_weight_record, &scope);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(InputVoterWeightError::InvalidWeightActionTarget)
+        );
+    }
+
+    #[test]
+    fn test_assert_input_voter_weight_scope_with_action_taken_from_input() {
+        // Arrange
+        let mut scope = create_test_scope();
+        let mut voter_weight_record = create_test_voter_weight_record(&scope);
+        voter_weight_record.weight_action =
+            Some(spl_governance_addin_api::voter_weight::VoterWeightAction::CreateProposal);
+        voter_weight_record.weight_action_target = Some(Pubkey::new_unique());
+
+        scope.weight_action = None;
+        scope.weight_action_target = None;
+
+        // Act
+        let result = assert_input_voter_weight_scope(&voter_weight_record, &scope);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+}
@@ -0,0 +1,20 @@
+//! Shared account types and helpers for SPL Governance voter weight plugins
+//!
+//! Anchor accounts are owned by the program which declares them and hence VoterWeightRecord and MaxVoterWeightRecord
+//! are provided as macros which must be invoked inside the plugin program crate
+
+pub mod anchor;
+
+pub mod generic_voter_weight;
+
+pub mod input_voter_weight;
+
+pub mod max_voter_weight_record;
+
+pub mod realm;
+
+pub mod registrar;
+
+pub mod voter_weight_action;
+
+pub mod voter_weight_record;
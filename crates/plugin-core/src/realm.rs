@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use spl_governance::state::realm::get_realm_data_for_governing_token_mint;
+
+/// Returns true if the given realm_authority is the authority of the Realm
+/// The Realm must be owned by the given spl-governance instance and have the given governing_token_mint
+///
+/// Note: Realm without authority has no realm_authority and false is returned
+pub fn is_realm_authority(
+    governance_program_id: &Pubkey,
+    realm_info: &AccountInfo,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+) -> Result<bool> {
+    let realm = get_realm_data_for_governing_token_mint(
+        governance_program_id,
+        realm_info,
+        governing_token_mint,
+    )?;
+
+    Ok(realm.authority == Some(*realm_authority))
+}
@@ -0,0 +1,22 @@
+use solana_program::pubkey::Pubkey;
+
+/// Returns Registrar PDA seeds
+pub fn get_registrar_seeds<'a>(
+    realm: &'a Pubkey,
+    governing_token_mint: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [b"registrar", realm.as_ref(), governing_token_mint.as_ref()]
+}
+
+/// Returns Registrar PDA address for the given plugin program
+pub fn get_registrar_address(
+    program_id: &Pubkey,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_registrar_seeds(realm, governing_token_mint),
+        program_id,
+    )
+    .0
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use borsh::BorshSchema;
+
+/// VoterWeightAction enum as defined in spl-governance-addin-api
+/// It's redefined here for Anchor to serialize it as part of the plugin accounts
+#[derive(AnchorSerialize, AnchorDeserialize, BorshSchema, Debug, Clone, Copy, PartialEq)]
+pub enum VoterWeightAction {
+    /// Cast vote for a proposal. Target: Proposal
+    CastVote,
+
+    /// Comment a proposal. Target: Proposal
+    CommentProposal,
+
+    /// Create Governance within a realm. Target: Realm
+    CreateGovernance,
+
+    /// Create a proposal for a governance. Target: Governance
+    CreateProposal,
+
+    /// Signs off a proposal for a governance. Target: Proposal
+    /// Note: SignOffProposal is not supported in the current version
+    SignOffProposal,
+}
+
+/// Converts spl-governance-addin-api VoterWeightAction to the plugin VoterWeightAction
+/// The variants are matched explicitly so the conversion fails to compile if the enums get out of sync
+impl From<spl_governance_addin_api::voter_weight::VoterWeightAction> for VoterWeightAction {
+    fn from(weight_action: spl_governance_addin_api::voter_weight::VoterWeightAction) -> Self {
+        use spl_governance_addin_api::voter_weight::VoterWeightAction as AddinVoterWeightAction;
+
+        match weight_action {
+            AddinVoterWeightAction::CastVote => VoterWeightAction::CastVote,
+            AddinVoterWeightAction::CommentProposal => VoterWeightAction::CommentProposal,
+            AddinVoterWeightAction::CreateGovernance => VoterWeightAction::CreateGovernance,
+            AddinVoterWeightAction::CreateProposal => VoterWeightAction::CreateProposal,
+            AddinVoterWeightAction::SignOffProposal => VoterWeightAction::SignOffProposal,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_voter_weight_action_from_addin_voter_weight_action() {
+        // Arrange
+        let addin_weight_action =
+            spl_governance_addin_api::voter_weight::VoterWeightAction::SignOffProposal;
+
+        // Act
+        let weight_action = VoterWeightAction::from(addin_weight_action);
+
+        // Assert
+        assert_eq!(weight_action, VoterWeightAction::SignOffProposal);
+    }
+
+    #[test]
+    fn test_voter_weight_action_has_addin_layout() {
+        // Arrange
+        let addin_weight_action =
+            spl_governance_addin_api::voter_weight::VoterWeightAction::CreateProposal;
+
+        // Act
+        let weight_action =
+            VoterWeightAction::try_from_slice(&addin_weight_action.try_to_vec().unwrap()).unwrap();
+
+        // Assert
+        assert_eq!(weight_action, VoterWeightAction::from(addin_weight_action));
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use gpl_plugin_core::input_voter_weight::InputVoterWeightError;
 
 #[error_code]
 pub enum GatewayError {
@@ -34,4 +35,100 @@ pub enum GatewayError {
 
     #[msg("Previous voter weight plugin required but not provided")]
     MissingPreviousVoterWeightPlugin,
+
+    #[msg("Invalid TokenOwner for VoterWeightRecord")]
+    InvalidTokenOwnerForVoterWeightRecord,
+
+    #[msg("VoterWeightRecord must be expired")]
+    VoterWeightRecordMustBeExpired,
+
+    #[msg("Input VoterWeightRecord expired")]
+    PredecessorVoterWeightRecordExpired,
+
+    #[msg("Invalid MaxVoterWeightRecord realm")]
+    InvalidMaxVoterWeightRecordRealm,
+
+    #[msg("Invalid MaxVoterWeightRecord mint")]
+    InvalidMaxVoterWeightRecordMint,
+
+    #[msg("Invalid governing token mint for the provided registrar")]
+    InvalidGoverningTokenMintForRegistrar,
+
+    #[msg("Previous voter weight plugin MaxVoterWeightRecord required but not provided")]
+    MissingPredecessorMaxVoterWeightRecord,
+
+    #[msg("Governing token mint required but not provided")]
+    MissingGoverningTokenMint,
+
+    #[msg(
+        "Invalid MaxVoterWeightRecord as input max voter weight (expecting MaxVoterWeightRecord)"
+    )]
+    InvalidPredecessorMaxVoterWeightRecord,
+
+    #[msg("Invalid MaxVoterWeightRecord realm for input max voter weight")]
+    InvalidPredecessorMaxVoterWeightRecordRealm,
+
+    #[msg("Invalid MaxVoterWeightRecord governance token mint for input max voter weight")]
+    InvalidPredecessorMaxVoterWeightRecordGovTokenMint,
+
+    #[msg("Input MaxVoterWeightRecord expired")]
+    PredecessorMaxVoterWeightRecordExpired,
+
+    #[msg("Too many gatekeeper networks")]
+    TooManyGatekeeperNetworks,
+
+    #[msg("Gatekeeper network already configured")]
+    DuplicateGatekeeperNetwork,
+
+    #[msg("Gateway token required for each gatekeeper network but not provided")]
+    MissingGatewayToken,
+
+    #[msg("Invalid gatekeeper policy for the action")]
+    InvalidActionGatekeeperPolicy,
+
+    #[msg("Gateway token expired")]
+    GatewayTokenExpired,
+
+    #[msg("Gateway token frozen")]
+    GatewayTokenFrozen,
+
+    #[msg("Gateway token revoked")]
+    GatewayTokenRevoked,
+
+    #[msg("Gateway token must be owned by the gateway program")]
+    InvalidGatewayTokenAccountOwner,
+
+    #[msg("Max voter weight must be provided for fixed voter weight mode")]
+    MissingMaxVoterWeight,
+
+    #[msg("Gatekeeper network is used by an action gatekeeper policy")]
+    GatekeeperNetworkUsedByActionPolicy,
+}
+
+impl From<InputVoterWeightError> for GatewayError {
+    fn from(error: InputVoterWeightError) -> Self {
+        match error {
+            InputVoterWeightError::InvalidTokenOwnerRecord => {
+                GatewayError::InvalidPredecessorTokenOwnerRecord
+            }
+            // The plugin takes the action and target from the input voter weight and never restricts them
+            InputVoterWeightError::InvalidVoterWeightRecord
+            | InputVoterWeightError::InvalidWeightAction
+            | InputVoterWeightError::InvalidWeightActionTarget => {
+                GatewayError::InvalidPredecessorVoterWeightRecord
+            }
+            InputVoterWeightError::InvalidRealm => {
+                GatewayError::InvalidPredecessorVoterWeightRecordRealm
+            }
+            InputVoterWeightError::InvalidGoverningTokenMint => {
+                GatewayError::InvalidPredecessorVoterWeightRecordGovTokenMint
+            }
+            InputVoterWeightError::InvalidGoverningTokenOwner => {
+                GatewayError::InvalidPredecessorVoterWeightRecordGovTokenOwner
+            }
+            InputVoterWeightError::VoterWeightExpired => {
+                GatewayError::PredecessorVoterWeightRecordExpired
+            }
+        }
+    }
 }
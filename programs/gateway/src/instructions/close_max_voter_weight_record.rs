@@ -0,0 +1,53 @@
+use crate::error::GatewayError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+/// Closes MaxVoterWeightRecord and recovers the rent from the account
+/// It can be used when the Realm no longer uses the plugin and the instruction must be signed by the Realm authority
+#[derive(Accounts)]
+pub struct CloseMaxVoterWeightRecord<'info> {
+    /// The Gateway Plugin Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        address = registrar.realm @ GatewayError::InvalidRealmForRegistrar,
+        owner = registrar.governance_program_id.key()
+     )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match realm.authority
+    pub realm_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = beneficiary,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ GatewayError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ GatewayError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+
+    /// CHECK: The beneficiary who receives lamports from the closed MaxVoterWeightRecord can be any account
+    #[account(mut)]
+    pub beneficiary: UncheckedAccount<'info>,
+}
+
+pub fn close_max_voter_weight_record(ctx: Context<CloseMaxVoterWeightRecord>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        GatewayError::InvalidRealmAuthority
+    );
+
+    Ok(())
+}
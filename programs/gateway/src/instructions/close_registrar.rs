@@ -0,0 +1,43 @@
+use crate::error::GatewayError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+/// Closes Registrar and recovers the rent from the account
+/// It can be used when the Realm no longer uses the plugin and the instruction must be signed by the Realm authority
+#[derive(Accounts)]
+pub struct CloseRegistrar<'info> {
+    /// The Registrar to close
+    #[account(mut, close = beneficiary)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        address = registrar.realm @ GatewayError::InvalidRealmForRegistrar,
+        owner = registrar.governance_program_id.key()
+     )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// CHECK: The beneficiary who receives lamports from the closed Registrar can be any account
+    #[account(mut)]
+    pub beneficiary: UncheckedAccount<'info>,
+}
+
+pub fn close_registrar(ctx: Context<CloseRegistrar>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        GatewayError::InvalidRealmAuthority
+    );
+
+    Ok(())
+}
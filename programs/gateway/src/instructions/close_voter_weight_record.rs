@@ -0,0 +1,43 @@
+use crate::error::GatewayError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Closes VoterWeightRecord and recovers the rent from the account
+/// The account can only be closed by the governing_token_owner, for example when the member leaves the DAO
+#[derive(Accounts)]
+pub struct CloseVoterWeightRecord<'info> {
+    /// The Gateway Plugin Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        close = beneficiary,
+        constraint = voter_weight_record.realm == registrar.realm
+        @ GatewayError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ GatewayError::InvalidVoterWeightRecordMint,
+
+        constraint = voter_weight_record.governing_token_owner == governing_token_owner.key()
+        @ GatewayError::InvalidTokenOwnerForVoterWeightRecord,
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    /// The owner of the VoterWeightRecord must sign the instruction
+    pub governing_token_owner: Signer<'info>,
+
+    /// CHECK: The beneficiary who receives lamports from the closed VoterWeightRecord can be any account
+    #[account(mut)]
+    pub beneficiary: UncheckedAccount<'info>,
+}
+
+pub fn close_voter_weight_record(ctx: Context<CloseVoterWeightRecord>) -> Result<()> {
+    // Prevent closing VoterWeightRecord within its expiration period when it can still be used by spl-gov
+    // VoterWeightRecord without expiry never expires and it can't be closed
+    match ctx.accounts.voter_weight_record.voter_weight_expiry {
+        Some(voter_weight_expiry) if voter_weight_expiry < Clock::get()?.slot => {}
+        _ => return err!(GatewayError::VoterWeightRecordMustBeExpired),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use crate::error::GatewayError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+/// Configures the gatekeeper networks accepted in addition to Registrar.gatekeeper_network
+/// and whether a gateway token is required from any or from all of them
+#[derive(Accounts)]
+pub struct ConfigureGatekeeperNetworks<'info> {
+    /// The Gateway Plugin Registrar to be updated
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    /// An spl-governance Realm
+    ///
+    /// Realm is validated in the instruction:
+    /// - Realm is owned by the governance_program_id
+    /// - realm_authority is realm.authority
+    /// CHECK: Owned by spl-governance instance specified in governance_program_id
+    #[account(
+        address = registrar.realm @ GatewayError::InvalidRealmForRegistrar,
+        owner = registrar.governance_program_id.key()
+    )]
+    pub realm: UncheckedAccount<'info>,
+
+    /// realm_authority must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+}
+
+/// Configures the additional gatekeeper networks and the gatekeeper network policy
+/// Passing an empty list of additional networks restores the single gatekeeper network configuration
+///
+/// The additional networks referenced by ActionGatekeeperPolicy::Network can't be removed or moved to other indices
+/// The policies must be reconfigured using ConfigureRegistrar first
+pub fn configure_gatekeeper_networks(
+    ctx: Context<ConfigureGatekeeperNetworks>,
+    gatekeeper_network_policy: GatekeeperNetworkPolicy,
+    additional_gatekeeper_networks: Vec<Pubkey>,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        GatewayError::InvalidRealmAuthority
+    );
+
+    require!(
+        additional_gatekeeper_networks.len() <= MAX_ADDITIONAL_GATEKEEPER_NETWORKS,
+        GatewayError::TooManyGatekeeperNetworks
+    );
+
+    // Each gatekeeper network can only be configured once
+    for (index, gatekeeper_network) in additional_gatekeeper_networks.iter().enumerate() {
+        require!(
+            *gatekeeper_network != registrar.gatekeeper_network
+                && !additional_gatekeeper_networks[..index].contains(gatekeeper_network),
+            GatewayError::DuplicateGatekeeperNetwork
+        );
+    }
+
+    // ActionGatekeeperPolicy::Network references the gatekeeper network by its index
+    // and the referenced network must stay at the same index to not silently change the policy
+    for action_gatekeeper_policy in registrar.action_gatekeeper_policies.iter() {
+        if let ActionGatekeeperPolicy::Network(index) = action_gatekeeper_policy {
+            // Index 0 is Registrar.gatekeeper_network which isn't changed by the instruction
+            if *index == 0 {
+                continue;
+            }
+
+            let additional_index = *index as usize - 1;
+
+            require!(
+                additional_gatekeeper_networks.get(additional_index)
+                    == registrar
+                        .additional_gatekeeper_networks
+                        .get(additional_index),
+                GatewayError::GatekeeperNetworkUsedByActionPolicy
+            );
+        }
+    }
+
+    registrar.gatekeeper_network_policy = gatekeeper_network_policy;
+    registrar.additional_gatekeeper_networks = additional_gatekeeper_networks;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use crate::error::GatewayError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+/// Configures the options used to verify the gateway tokens presented by voters
+/// The options only control the expiry checks and a maximum pass age is not supported
+#[derive(Accounts)]
+pub struct ConfigureGatewayTokenVerification<'info> {
+    /// The Gateway Plugin Registrar to be updated
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    /// An spl-governance Realm
+    ///
+    /// Realm is validated in the instruction:
+    /// - Realm is owned by the governance_program_id
+    /// - realm_authority is realm.authority
+    /// CHECK: Owned by spl-governance instance specified in governance_program_id
+    #[account(
+        address = registrar.realm @ GatewayError::InvalidRealmForRegistrar,
+        owner = registrar.governance_program_id.key()
+    )]
+    pub realm: UncheckedAccount<'info>,
+
+    /// realm_authority must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+}
+
+/// Configures the gateway token expiry checks
+/// Passing the default options restores the strict expiry check without any tolerance
+pub fn configure_gateway_token_verification(
+    ctx: Context<ConfigureGatewayTokenVerification>,
+    gateway_token_verification_options: GatewayTokenVerificationOptions,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        GatewayError::InvalidRealmAuthority
+    );
+
+    registrar.gateway_token_verification_options = gateway_token_verification_options;
+
+    Ok(())
+}
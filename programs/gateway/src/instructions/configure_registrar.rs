@@ -1,10 +1,10 @@
 use crate::error::GatewayError;
 use crate::state::*;
 use anchor_lang::prelude::*;
-use spl_governance::state::realm;
+use gpl_plugin_core::realm::is_realm_authority;
 
 /// Configures the Gateway Registrar,
-/// allowing the gatekeeper network or previous plugin to be updated
+/// allowing the gatekeeper network, previous plugin or action gatekeeper policies to be updated
 #[derive(Accounts)]
 #[instruction(use_previous_voter_weight_plugin:bool)]
 pub struct ConfigureRegistrar<'info> {
@@ -35,14 +35,30 @@ pub struct ConfigureRegistrar<'info> {
     pub gatekeeper_network: UncheckedAccount<'info>,
 }
 
-/// Configures a Registrar, updating the gatekeeperNetwork or the previous plugin program ID
+/// Configures a Registrar, updating the gatekeeperNetwork, the previous plugin program ID and the action gatekeeper policies
+/// The actions not included in action_gatekeeper_policies are reset to ActionGatekeeperPolicy::Default
 pub fn configure_registrar(
     ctx: Context<ConfigureRegistrar>,
     use_previous_voter_weight_plugin: bool,
+    action_gatekeeper_policies: Vec<ActionGatekeeperPolicyConfig>,
 ) -> Result<()> {
     let registrar = &mut ctx.accounts.registrar;
     registrar.gatekeeper_network = ctx.accounts.gatekeeper_network.key();
 
+    registrar.action_gatekeeper_policies = Default::default();
+
+    for action_gatekeeper_policy in action_gatekeeper_policies {
+        if let ActionGatekeeperPolicy::Network(index) = action_gatekeeper_policy.policy {
+            require!(
+                (index as usize) < registrar.get_gatekeeper_networks().len(),
+                GatewayError::InvalidActionGatekeeperPolicy
+            );
+        }
+
+        registrar.action_gatekeeper_policies[action_gatekeeper_policy.action as usize] =
+            action_gatekeeper_policy.policy;
+    }
+
     let remaining_accounts = &ctx.remaining_accounts;
 
     // If the plugin has a previous plugin, it "inherits" the vote weight from a vote_weight_account owned
@@ -59,14 +75,13 @@ pub fn configure_registrar(
 
     // Verify that realm_authority is the expected authority of the Realm
     // and that the mint matches one of the realm mints too.
-    let realm = realm::get_realm_data_for_governing_token_mint(
-        &registrar.governance_program_id,
-        &ctx.accounts.realm,
-        &registrar.governing_token_mint,
-    )?;
-    require_eq!(
-        realm.authority.unwrap(),
-        ctx.accounts.realm_authority.key(),
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
         GatewayError::InvalidRealmAuthority
     );
 
@@ -0,0 +1,82 @@
+use crate::error::GatewayError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+/// Configures how the voter weight is derived from the input voter weight of a verified voter
+/// For VoterWeightMode::Fixed it also sets MaxVoterWeightRecord.max_voter_weight to the provided value
+#[derive(Accounts)]
+pub struct ConfigureVoterWeightMode<'info> {
+    /// The Gateway Plugin Registrar to be updated
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    /// An spl-governance Realm
+    ///
+    /// Realm is validated in the instruction:
+    /// - Realm is owned by the governance_program_id
+    /// - realm_authority is realm.authority
+    /// CHECK: Owned by spl-governance instance specified in governance_program_id
+    #[account(
+        address = registrar.realm @ GatewayError::InvalidRealmForRegistrar,
+        owner = registrar.governance_program_id.key()
+    )]
+    pub realm: UncheckedAccount<'info>,
+
+    /// realm_authority must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// MaxVoterWeightRecord for the given registrar.realm and registrar.governing_token_mint
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ GatewayError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ GatewayError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+/// Configures the voter weight mode of the Registrar
+///
+/// In VoterWeightMode::Fixed the number of verified voters isn't known on chain and max_voter_weight must be provided
+/// The max voter weight is static for the mode and it never expires
+/// For the other modes max_voter_weight is ignored and MaxVoterWeightRecord must be refreshed using update_max_voter_weight_record
+pub fn configure_voter_weight_mode(
+    ctx: Context<ConfigureVoterWeightMode>,
+    voter_weight_mode: VoterWeightMode,
+    max_voter_weight: Option<u64>,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        GatewayError::InvalidRealmAuthority
+    );
+
+    registrar.voter_weight_mode = voter_weight_mode;
+
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    match voter_weight_mode {
+        VoterWeightMode::Fixed(_) => {
+            max_voter_weight_record.max_voter_weight =
+                max_voter_weight.ok_or(GatewayError::MissingMaxVoterWeight)?;
+
+            // max_voter_weight can only be updated using this instruction and it never expires
+            max_voter_weight_record.max_voter_weight_expiry = None;
+        }
+        VoterWeightMode::Passthrough | VoterWeightMode::Capped(_) => {
+            // Expire the record to prevent the static max voter weight from being used until it's updated
+            max_voter_weight_record.max_voter_weight_expiry = Some(0);
+        }
+    }
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use crate::error::GatewayError;
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
-use spl_governance::state::realm;
+use gpl_plugin_core::realm::is_realm_authority;
 
 /// Creates a Plugin Registrar for spl-gov Realm
 /// This instruction should only be executed once per realm/governing_token_mint to create the account
@@ -85,15 +85,13 @@ pub fn create_registrar(
 
     // Verify that realm_authority is the expected authority of the Realm
     // and that the mint matches one of the realm mints.
-    let realm = realm::get_realm_data_for_governing_token_mint(
-        &registrar.governance_program_id,
-        &ctx.accounts.realm,
-        &registrar.governing_token_mint,
-    )?;
-
-    require_eq!(
-        realm.authority.unwrap(),
-        ctx.accounts.realm_authority.key(),
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
         GatewayError::InvalidRealmAuthority
     );
 
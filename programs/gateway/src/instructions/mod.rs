@@ -4,8 +4,32 @@ mod create_registrar;
 pub use configure_registrar::*;
 mod configure_registrar;
 
+pub use configure_gatekeeper_networks::*;
+mod configure_gatekeeper_networks;
+
+pub use configure_gateway_token_verification::*;
+mod configure_gateway_token_verification;
+
+pub use configure_voter_weight_mode::*;
+mod configure_voter_weight_mode;
+
+pub use close_registrar::*;
+mod close_registrar;
+
 pub use create_voter_weight_record::*;
 mod create_voter_weight_record;
 
+pub use close_voter_weight_record::*;
+mod close_voter_weight_record;
+
 pub use update_voter_weight_record::*;
 mod update_voter_weight_record;
+
+pub use create_max_voter_weight_record::*;
+mod create_max_voter_weight_record;
+
+pub use update_max_voter_weight_record::*;
+mod update_max_voter_weight_record;
+
+pub use close_max_voter_weight_record::*;
+mod close_max_voter_weight_record;
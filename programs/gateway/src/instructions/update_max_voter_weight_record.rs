@@ -0,0 +1,108 @@
+use crate::error::GatewayError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use spl_governance_addin_api::max_voter_weight;
+use spl_governance_tools::account::get_account_data;
+
+/// Updates MaxVoterWeightRecord to reflect the max voter weight of the voting population
+/// If the Registrar has a predecessor plugin then the max voter weight is copied from the predecessor MaxVoterWeightRecord
+/// which must be provided as the first remaining account
+/// Otherwise the max voter weight is the supply of the governing_token_mint
+/// which must be provided as the first remaining account
+///
+/// In VoterWeightMode::Passthrough the voter weight is unchanged and hence the max voter weight doesn't change either
+/// In VoterWeightMode::Capped the capped voter weights can't exceed the input max voter weight which is used as the upper bound
+/// In VoterWeightMode::Fixed the max voter weight is static, configured using configure_voter_weight_mode, and the record is not changed
+/// The instruction is permissionless to allow anybody to keep the max voter weight accurate
+#[derive(Accounts)]
+pub struct UpdateMaxVoterWeightRecord<'info> {
+    /// The Gateway Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ GatewayError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ GatewayError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+pub fn update_max_voter_weight_record(ctx: Context<UpdateMaxVoterWeightRecord>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+    let current_slot = Clock::get()?.slot;
+
+    // The max voter weight is static in Fixed mode and it can only be set using configure_voter_weight_mode
+    if let VoterWeightMode::Fixed(_) = registrar.voter_weight_mode {
+        return Ok(());
+    }
+
+    match registrar.previous_voter_weight_plugin_program_id {
+        Some(previous_voter_weight_plugin_program_id) => {
+            let predecessor_max_voter_weight_record_info = ctx
+                .remaining_accounts
+                .get(0)
+                .ok_or(GatewayError::MissingPredecessorMaxVoterWeightRecord)?;
+
+            let predecessor_max_voter_weight_record: max_voter_weight::MaxVoterWeightRecord =
+                get_account_data(
+                    &previous_voter_weight_plugin_program_id,
+                    predecessor_max_voter_weight_record_info,
+                )
+                .map_err(|_| error!(GatewayError::InvalidPredecessorMaxVoterWeightRecord))?;
+
+            require_keys_eq!(
+                predecessor_max_voter_weight_record.realm,
+                registrar.realm,
+                GatewayError::InvalidPredecessorMaxVoterWeightRecordRealm
+            );
+
+            require_keys_eq!(
+                predecessor_max_voter_weight_record.governing_token_mint,
+                registrar.governing_token_mint,
+                GatewayError::InvalidPredecessorMaxVoterWeightRecordGovTokenMint
+            );
+
+            if let Some(max_voter_weight_expiry) =
+                predecessor_max_voter_weight_record.max_voter_weight_expiry
+            {
+                require_gte!(
+                    max_voter_weight_expiry,
+                    current_slot,
+                    GatewayError::PredecessorMaxVoterWeightRecordExpired
+                );
+            }
+
+            // The record mirrors the predecessor record and it's valid for as long as the predecessor record is
+            max_voter_weight_record.max_voter_weight =
+                predecessor_max_voter_weight_record.max_voter_weight;
+            max_voter_weight_record.max_voter_weight_expiry =
+                predecessor_max_voter_weight_record.max_voter_weight_expiry;
+        }
+        None => {
+            let governing_token_mint_info = ctx
+                .remaining_accounts
+                .get(0)
+                .ok_or(GatewayError::MissingGoverningTokenMint)?;
+
+            require_keys_eq!(
+                governing_token_mint_info.key(),
+                registrar.governing_token_mint,
+                GatewayError::InvalidGoverningTokenMintForRegistrar
+            );
+
+            let governing_token_mint = Account::<Mint>::try_from(governing_token_mint_info)?;
+
+            max_voter_weight_record.max_voter_weight = governing_token_mint.supply;
+
+            // The mint supply can change at any time and the record is only valid as of the current slot
+            max_voter_weight_record.max_voter_weight_expiry = Some(current_slot);
+        }
+    }
+
+    Ok(())
+}
@@ -1,10 +1,9 @@
 use crate::error::GatewayError;
 use crate::state::*;
+use crate::tools::gateway_token::{get_gateway_token_expiry_slot, verify_gateway_tokens};
 use anchor_lang::prelude::*;
-use solana_gateway::Gateway;
-use spl_governance::state::token_owner_record::get_token_owner_record_data_for_realm_and_governing_mint;
-use spl_governance_tools::account::get_account_data;
-use std::cmp::max;
+use gpl_plugin_core::input_voter_weight::{resolve_input_voter_weight, InputVoterWeightScope};
+use std::cmp::min;
 
 /// Updates VoterWeightRecord to evaluate governance power for non voting use cases: CreateProposal, CreateGovernance etc...
 /// This instruction updates VoterWeightRecord which is valid for the current Slot and the given target action only
@@ -23,6 +22,9 @@ pub struct UpdateVoterWeightRecord<'info> {
 
     /// A gateway token from the gatekeeper network in the registrar.
     /// Proves that the holder is permitted to take an action.
+    /// If the registrar requires gateway tokens from multiple gatekeeper networks then the tokens
+    /// for the other networks must be provided as remaining accounts
+    /// If the input action is exempt from the gatekeeper policy then the account is not checked
     /// CHECK: Checked in the gateway library.
     #[account()]
     pub gateway_token: UncheckedAccount<'info>,
@@ -38,106 +40,74 @@ pub struct UpdateVoterWeightRecord<'info> {
     pub voter_weight_record: Account<'info, VoterWeightRecord>,
 }
 
-/// Sets the voter weight record value to the default voter weight, if the voter has a valid
-/// Civic Pass, or throws an error if not.
-pub fn update_voter_weight_record(ctx: Context<UpdateVoterWeightRecord>) -> Result<()> {
-    // Gateway: Check if the voter has a valid gateway token and fail if not
-    Gateway::verify_gateway_token_account_info(
-        &ctx.accounts.gateway_token.to_account_info(),
-        &ctx.accounts.voter_weight_record.governing_token_owner,
-        &ctx.accounts.registrar.gatekeeper_network,
-        None,
-    )
-    .map_err(|_| error!(GatewayError::InvalidGatewayToken))?;
-
+/// Sets the voter weight record value to the voter weight derived from the input voter weight
+/// according to the Registrar voter_weight_mode, if the voter has a valid Civic Pass, or throws an error if not.
+/// If the input action is exempt from the gatekeeper policy then the voter isn't verified
+/// and the voter weight doesn't include the fixed voter weight granted to verified voters
+pub fn update_voter_weight_record<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateVoterWeightRecord<'info>>,
+) -> Result<()> {
     let voter_weight_record = &mut ctx.accounts.voter_weight_record;
 
     let input_voter_weight_account = ctx.accounts.input_voter_weight.to_account_info();
 
-    let clone_record = voter_weight_record.clone();
+    let registrar = &ctx.accounts.registrar;
+    let clock = Clock::get()?;
+    let current_slot = clock.slot;
+
+    // Gateway doesn't evaluate any specific action and takes the action and target from the input voter weight
     let input_voter_weight_record = resolve_input_voter_weight(
         &input_voter_weight_account,
-        &clone_record,
-        &ctx.accounts.registrar,
+        &InputVoterWeightScope {
+            governance_program_id: registrar.governance_program_id,
+            previous_voter_weight_plugin_program_id: registrar
+                .previous_voter_weight_plugin_program_id,
+            realm: registrar.realm,
+            governing_token_mint: registrar.governing_token_mint,
+            governing_token_owner: voter_weight_record.governing_token_owner,
+            weight_action: None,
+            weight_action_target: None,
+            current_slot,
+        },
+    )
+    .map_err(GatewayError::from)?;
+
+    // Gateway: Check if the voter has valid gateway tokens required for the input action and fail if not
+    let gateway_token_expire_time = verify_gateway_tokens(
+        registrar,
+        &voter_weight_record.governing_token_owner,
+        input_voter_weight_record.get_weight_action(),
+        &ctx.accounts.gateway_token.to_account_info(),
+        ctx.remaining_accounts,
     )?;
 
     msg!(
         "input_voter_weight_record.voter_weight: {}",
         input_voter_weight_record.get_voter_weight()
     );
-    voter_weight_record.voter_weight = input_voter_weight_record.get_voter_weight();
+    voter_weight_record.voter_weight =
+        if registrar.is_action_exempt(input_voter_weight_record.get_weight_action()) {
+            registrar.get_exempt_voter_weight(input_voter_weight_record.get_voter_weight())
+        } else {
+            registrar.get_voter_weight(input_voter_weight_record.get_voter_weight())
+        };
     voter_weight_record.weight_action = input_voter_weight_record.get_weight_action();
     voter_weight_record.weight_action_target = input_voter_weight_record.get_weight_action_target();
 
-    // If the input voter weight record has an expiry, use the max between that and the current slot
+    // If the input voter weight record has an expiry, carry it forward (stale input is rejected by the resolver)
     // Otherwise use the current slot
-    let current_slot = Clock::get()?.slot;
-    voter_weight_record.voter_weight_expiry =
-        input_voter_weight_record.get_voter_weight_expiry().map_or(
-            Some(current_slot), // no previous expiry, use current slot
-            |previous_expiry| Some(max(previous_expiry, current_slot)),
-        );
+    let voter_weight_expiry = input_voter_weight_record
+        .get_voter_weight_expiry()
+        .unwrap_or(current_slot);
+
+    // The voter weight can't outlive the gateway tokens it was verified with
+    voter_weight_record.voter_weight_expiry = Some(match gateway_token_expire_time {
+        Some(expire_time) => min(
+            voter_weight_expiry,
+            get_gateway_token_expiry_slot(expire_time, &clock),
+        ),
+        None => voter_weight_expiry,
+    });
 
     Ok(())
 }
-
-/// Attempt to parse the input account as a VoterWeightRecord or a TokenOwnerRecordV2
-fn resolve_input_voter_weight<'a>(
-    input_account: &'a AccountInfo,
-    voter_weight_record_to_update: &'a VoterWeightRecord,
-    registrar: &'a Registrar,
-) -> Result<GenericVoterWeightEnum> {
-    let predecessor_generic_voter_weight_record =
-        get_generic_voter_weight_record_data(input_account, registrar)?;
-
-    // ensure that the correct governance token is used
-    require_eq!(
-        voter_weight_record_to_update.governing_token_mint,
-        predecessor_generic_voter_weight_record.get_governing_token_mint(),
-        GatewayError::InvalidPredecessorVoterWeightRecordGovTokenMint
-    );
-
-    // Ensure that the correct governance token is used
-    require_eq!(
-        voter_weight_record_to_update.governing_token_owner,
-        predecessor_generic_voter_weight_record.get_governing_token_owner(),
-        GatewayError::InvalidPredecessorVoterWeightRecordGovTokenOwner
-    );
-
-    // Ensure that the realm matches the current realm
-    require_eq!(
-        registrar.realm,
-        predecessor_generic_voter_weight_record.get_realm(),
-        GatewayError::InvalidPredecessorVoterWeightRecordRealm
-    );
-
-    Ok(predecessor_generic_voter_weight_record)
-}
-
-fn get_generic_voter_weight_record_data<'a>(
-    input_account: &'a AccountInfo,
-    registrar: &'a Registrar,
-) -> Result<GenericVoterWeightEnum> {
-    match registrar.previous_voter_weight_plugin_program_id {
-        None => {
-            // If there is no predecessor plugin registrar, then the input account must be a TokenOwnerRecordV2
-            let record = get_token_owner_record_data_for_realm_and_governing_mint(
-                &registrar.governance_program_id,
-                input_account,
-                &registrar.realm,
-                &registrar.governing_token_mint,
-            )
-            .map_err(|_| error!(GatewayError::InvalidPredecessorTokenOwnerRecord))?;
-
-            Ok(GenericVoterWeightEnum::TokenOwnerRecord(record))
-        }
-        Some(predecessor) => {
-            // If there is a predecessor plugin registrar, then the input account must be a VoterWeightRecord
-            let record: spl_governance_addin_api::voter_weight::VoterWeightRecord =
-                get_account_data(&predecessor, input_account)
-                    .map_err(|_| error!(GatewayError::InvalidPredecessorVoterWeightRecord))?;
-
-            Ok(GenericVoterWeightEnum::VoterWeightRecord(record))
-        }
-    }
-}
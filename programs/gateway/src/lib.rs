@@ -9,6 +9,8 @@ pub mod state;
 
 pub mod tools;
 
+use crate::state::*;
+
 declare_id!("GgathUhdrCWRHowoRKACjgWhYHfxCEdBi5ViqYN6HVxk");
 
 #[program]
@@ -24,9 +26,41 @@ pub mod gateway {
     pub fn configure_registrar(
         ctx: Context<ConfigureRegistrar>,
         use_previous_voter_weight_plugin: bool,
+        action_gatekeeper_policies: Vec<ActionGatekeeperPolicyConfig>,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_registrar(
+            ctx,
+            use_previous_voter_weight_plugin,
+            action_gatekeeper_policies,
+        )
+    }
+    pub fn configure_gatekeeper_networks(
+        ctx: Context<ConfigureGatekeeperNetworks>,
+        gatekeeper_network_policy: GatekeeperNetworkPolicy,
+        additional_gatekeeper_networks: Vec<Pubkey>,
     ) -> Result<()> {
         log_version();
-        instructions::configure_registrar(ctx, use_previous_voter_weight_plugin)
+        instructions::configure_gatekeeper_networks(
+            ctx,
+            gatekeeper_network_policy,
+            additional_gatekeeper_networks,
+        )
+    }
+    pub fn configure_gateway_token_verification(
+        ctx: Context<ConfigureGatewayTokenVerification>,
+        gateway_token_verification_options: GatewayTokenVerificationOptions,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_gateway_token_verification(ctx, gateway_token_verification_options)
+    }
+    pub fn configure_voter_weight_mode(
+        ctx: Context<ConfigureVoterWeightMode>,
+        voter_weight_mode: VoterWeightMode,
+        max_voter_weight: Option<u64>,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_voter_weight_mode(ctx, voter_weight_mode, max_voter_weight)
     }
     pub fn create_voter_weight_record(
         ctx: Context<CreateVoterWeightRecord>,
@@ -35,10 +69,32 @@ pub mod gateway {
         log_version();
         instructions::create_voter_weight_record(ctx, governing_token_owner)
     }
-    pub fn update_voter_weight_record(ctx: Context<UpdateVoterWeightRecord>) -> Result<()> {
+    pub fn update_voter_weight_record<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateVoterWeightRecord<'info>>,
+    ) -> Result<()> {
         log_version();
         instructions::update_voter_weight_record(ctx)
     }
+    pub fn close_voter_weight_record(ctx: Context<CloseVoterWeightRecord>) -> Result<()> {
+        log_version();
+        instructions::close_voter_weight_record(ctx)
+    }
+    pub fn create_max_voter_weight_record(ctx: Context<CreateMaxVoterWeightRecord>) -> Result<()> {
+        log_version();
+        instructions::create_max_voter_weight_record(ctx)
+    }
+    pub fn update_max_voter_weight_record(ctx: Context<UpdateMaxVoterWeightRecord>) -> Result<()> {
+        log_version();
+        instructions::update_max_voter_weight_record(ctx)
+    }
+    pub fn close_max_voter_weight_record(ctx: Context<CloseMaxVoterWeightRecord>) -> Result<()> {
+        log_version();
+        instructions::close_max_voter_weight_record(ctx)
+    }
+    pub fn close_registrar(ctx: Context<CloseRegistrar>) -> Result<()> {
+        log_version();
+        instructions::close_registrar(ctx)
+    }
 }
 
 fn log_version() {
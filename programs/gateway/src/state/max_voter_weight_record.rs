@@ -0,0 +1,38 @@
+use crate::id;
+use anchor_lang::prelude::*;
+
+pub use gpl_plugin_core::max_voter_weight_record::get_max_voter_weight_record_seeds;
+
+gpl_plugin_core::max_voter_weight_record_account!();
+
+/// Returns MaxVoterWeightRecord PDA address
+pub fn get_max_voter_weight_record_address(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+) -> Pubkey {
+    gpl_plugin_core::max_voter_weight_record::get_max_voter_weight_record_address(
+        &id(),
+        realm,
+        governing_token_mint,
+    )
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::tools::anchor::DISCRIMINATOR_SIZE;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = MaxVoterWeightRecord::get_space();
+
+        // Act
+        let actual_space =
+            DISCRIMINATOR_SIZE + MaxVoterWeightRecord::default().try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+}
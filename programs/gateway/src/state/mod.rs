@@ -4,8 +4,7 @@ pub mod registrar;
 pub use voter_weight_record::*;
 pub mod voter_weight_record;
 
-pub use generic_voter_weight::*;
-pub mod generic_voter_weight;
+pub use max_voter_weight_record::*;
+pub mod max_voter_weight_record;
 
-pub use token_owner_record::*;
-pub mod token_owner_record;
+pub use gpl_plugin_core::generic_voter_weight::*;
@@ -3,6 +3,104 @@ use crate::{
     tools::anchor::{DISCRIMINATOR_SIZE, PUBKEY_SIZE},
 };
 use anchor_lang::prelude::*;
+use gpl_plugin_core::voter_weight_action::VoterWeightAction;
+
+/// Max number of gatekeeper networks which can be configured in addition to Registrar.gatekeeper_network
+/// The additional networks are stored in the space previously reserved for future upgrades
+pub const MAX_ADDITIONAL_GATEKEEPER_NETWORKS: usize = 3;
+
+/// Defines how the gateway tokens of the configured gatekeeper networks are required
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum GatekeeperNetworkPolicy {
+    /// The voter must present a valid gateway token from any of the configured gatekeeper networks
+    AnyOf,
+
+    /// The voter must present a valid gateway token from each of the configured gatekeeper networks
+    AllOf,
+}
+
+impl Default for GatekeeperNetworkPolicy {
+    fn default() -> Self {
+        GatekeeperNetworkPolicy::AnyOf
+    }
+}
+
+/// The number of VoterWeightAction variants a gatekeeper policy can be configured for
+pub const VOTER_WEIGHT_ACTION_COUNT: usize = 5;
+
+/// Defines which gateway tokens are required for a specific VoterWeightAction
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum ActionGatekeeperPolicy {
+    /// The action requires gateway tokens according to Registrar.gatekeeper_network_policy
+    Default,
+
+    /// The action doesn't require any gateway token and the input voter weight is passed through
+    Exempt,
+
+    /// The action requires a gateway token from any of the configured gatekeeper networks
+    AnyOf,
+
+    /// The action requires a gateway token from each of the configured gatekeeper networks
+    AllOf,
+
+    /// The action requires a gateway token from the gatekeeper network with the given index
+    /// Index 0 is Registrar.gatekeeper_network followed by Registrar.additional_gatekeeper_networks
+    /// Note: ConfigureGatekeeperNetworks keeps the networks referenced by the policies at their indices
+    Network(u8),
+}
+
+impl Default for ActionGatekeeperPolicy {
+    fn default() -> Self {
+        ActionGatekeeperPolicy::Default
+    }
+}
+
+/// ActionGatekeeperPolicy configured for the given VoterWeightAction
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct ActionGatekeeperPolicyConfig {
+    /// The action the policy is configured for
+    pub action: VoterWeightAction,
+
+    /// The gatekeeper policy for the action
+    pub policy: ActionGatekeeperPolicy,
+}
+
+/// Options used to verify the gateway tokens presented by voters
+/// The default options check the gateway token expiry without any tolerance
+///
+/// Note: A maximum pass age is not implemented because gateway tokens only store expire_time
+/// and not the time they were issued or refreshed and hence the pass age can't be checked on chain
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct GatewayTokenVerificationOptions {
+    /// If true then expired gateway tokens are accepted
+    /// Note: The flag is negated for the options of the existing Registrar accounts to check the expiry
+    pub ignore_expiry: bool,
+
+    /// Grace window in seconds after expire_time during which expired gateway tokens are still accepted
+    pub expiry_tolerance_seconds: u32,
+}
+
+/// Defines how the voter weight is derived from the input voter weight of a verified voter
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum VoterWeightMode {
+    /// The input voter weight is passed through unchanged
+    Passthrough,
+
+    /// Every verified voter gets the given voter weight regardless of the input voter weight
+    /// It gives one-person-one-vote when the gateway tokens prove unique humans
+    /// Voters exempt from the gatekeeper policy are not verified and they get 0 voter weight
+    /// The max voter weight for the mode is static and it's configured in MaxVoterWeightRecord
+    Fixed(u64),
+
+    /// The input voter weight is capped at the given voter weight
+    Capped(u64),
+}
+
+impl Default for VoterWeightMode {
+    fn default() -> Self {
+        VoterWeightMode::Passthrough
+    }
+}
 
 /// Registrar which stores Civic Pass voting configuration for the given Realm
 #[account]
@@ -28,27 +126,94 @@ pub struct Registrar {
     /// If set, then update_voter_weight_record will expect a voter_weight_record owned by this program
     pub previous_voter_weight_plugin_program_id: Option<Pubkey>,
 
+    /// Defines whether a gateway token is required from any or from all of the configured gatekeeper networks
+    pub gatekeeper_network_policy: GatekeeperNetworkPolicy,
+
+    /// Gatekeeper networks accepted in addition to gatekeeper_network
+    pub additional_gatekeeper_networks: Vec<Pubkey>,
+
+    /// Gatekeeper policies indexed by VoterWeightAction
+    pub action_gatekeeper_policies: [ActionGatekeeperPolicy; VOTER_WEIGHT_ACTION_COUNT],
+
+    /// Options used to verify the gateway tokens
+    pub gateway_token_verification_options: GatewayTokenVerificationOptions,
+
+    /// Defines how the voter weight is derived from the input voter weight
+    pub voter_weight_mode: VoterWeightMode,
+
     /// Reserved for future upgrades
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 3],
 }
 
 impl Registrar {
     pub fn get_space() -> usize {
-        DISCRIMINATOR_SIZE + PUBKEY_SIZE * 4 + (PUBKEY_SIZE + 1) + 128
+        DISCRIMINATOR_SIZE
+            + PUBKEY_SIZE * 4
+            + (PUBKEY_SIZE + 1)
+            + 1
+            + 4
+            + PUBKEY_SIZE * MAX_ADDITIONAL_GATEKEEPER_NETWORKS
+            + 2 * VOTER_WEIGHT_ACTION_COUNT
+            + 1
+            + 4
+            + 1
+            + 8
+            + 3
+    }
+
+    /// Returns the gatekeeper policy configured for the given action
+    pub fn get_action_gatekeeper_policy(
+        &self,
+        action: VoterWeightAction,
+    ) -> ActionGatekeeperPolicy {
+        self.action_gatekeeper_policies[action as usize]
     }
-}
 
-/// Returns Registrar PDA seeds
-pub fn get_registrar_seeds<'a>(
-    realm: &'a Pubkey,
-    governing_token_mint: &'a Pubkey,
-) -> [&'a [u8]; 3] {
-    [b"registrar", realm.as_ref(), governing_token_mint.as_ref()]
+    /// Returns the voter weight of a verified voter for the given input voter weight according to voter_weight_mode
+    pub fn get_voter_weight(&self, input_voter_weight: u64) -> u64 {
+        match self.voter_weight_mode {
+            VoterWeightMode::Passthrough => input_voter_weight,
+            VoterWeightMode::Fixed(voter_weight) => voter_weight,
+            VoterWeightMode::Capped(max_voter_weight) => input_voter_weight.min(max_voter_weight),
+        }
+    }
+
+    /// Returns true if the given weight_action is exempt from the gatekeeper policy
+    /// If weight_action is None then the voter weight can be used for any action and all the actions must be exempt
+    pub fn is_action_exempt(&self, weight_action: Option<VoterWeightAction>) -> bool {
+        match weight_action {
+            Some(weight_action) => {
+                self.get_action_gatekeeper_policy(weight_action) == ActionGatekeeperPolicy::Exempt
+            }
+            None => self
+                .action_gatekeeper_policies
+                .iter()
+                .all(|policy| *policy == ActionGatekeeperPolicy::Exempt),
+        }
+    }
+
+    /// Returns the voter weight of a voter who wasn't verified because the action is exempt from the gatekeeper policy
+    /// The fixed voter weight is only granted to voters verified with gateway tokens and exempt voters get 0 in Fixed mode
+    pub fn get_exempt_voter_weight(&self, input_voter_weight: u64) -> u64 {
+        match self.voter_weight_mode {
+            VoterWeightMode::Fixed(_) => 0,
+            _ => self.get_voter_weight(input_voter_weight),
+        }
+    }
+
+    /// Returns all the configured gatekeeper networks starting with gatekeeper_network
+    pub fn get_gatekeeper_networks(&self) -> Vec<Pubkey> {
+        let mut gatekeeper_networks = vec![self.gatekeeper_network];
+        gatekeeper_networks.extend(self.additional_gatekeeper_networks.iter());
+        gatekeeper_networks
+    }
 }
 
+pub use gpl_plugin_core::registrar::get_registrar_seeds;
+
 /// Returns Registrar PDA address
 pub fn get_registrar_address(realm: &Pubkey, governing_token_mint: &Pubkey) -> Pubkey {
-    Pubkey::find_program_address(&get_registrar_seeds(realm, governing_token_mint), &id()).0
+    gpl_plugin_core::registrar::get_registrar_address(&id(), realm, governing_token_mint)
 }
 
 #[cfg(test)]
@@ -67,7 +232,16 @@ mod test {
             realm: Pubkey::default(),
             governing_token_mint: Pubkey::default(),
             gatekeeper_network: Pubkey::default(),
-            reserved: [0; 128],
+            gatekeeper_network_policy: GatekeeperNetworkPolicy::AllOf,
+            additional_gatekeeper_networks: vec![
+                Pubkey::default();
+                MAX_ADDITIONAL_GATEKEEPER_NETWORKS
+            ],
+            action_gatekeeper_policies: [ActionGatekeeperPolicy::Network(0);
+                VOTER_WEIGHT_ACTION_COUNT],
+            gateway_token_verification_options: GatewayTokenVerificationOptions::default(),
+            voter_weight_mode: VoterWeightMode::Fixed(0),
+            reserved: [0; 3],
         };
 
         // Act
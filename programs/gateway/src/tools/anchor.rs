@@ -1,2 +1 @@
-pub const DISCRIMINATOR_SIZE: usize = 8;
-pub const PUBKEY_SIZE: usize = 32;
+pub use gpl_plugin_core::anchor::{DISCRIMINATOR_SIZE, PUBKEY_SIZE};
@@ -0,0 +1,218 @@
+use crate::error::GatewayError;
+use crate::state::{ActionGatekeeperPolicy, GatekeeperNetworkPolicy, Registrar, VoterWeightAction};
+use anchor_lang::prelude::*;
+use solana_gateway::{state::GatewayTokenState, Gateway, VerificationOptions};
+use solana_program::clock::{UnixTimestamp, DEFAULT_MS_PER_SLOT};
+use solana_program::pubkey;
+
+/// The program id of the Civic gateway program which owns gateway tokens
+pub const GATEWAY_PROGRAM_ID: Pubkey = pubkey!("gatem74V238djXdzWnJf94Wo1DcnuGkfijbf3AuBhfs");
+
+/// Verifies the voter holds valid gateway tokens required by the Registrar for the given weight_action
+/// and returns the time the verified gateway tokens expire at or None if they never expire
+///
+/// The gateway tokens are gateway_token followed by additional_gateway_tokens and they can be provided in any order
+/// If weight_action is None then the voter weight can be used for any action
+/// and the gateway tokens must satisfy the gatekeeper policies of all the actions
+pub fn verify_gateway_tokens<'a, 'info>(
+    registrar: &Registrar,
+    governing_token_owner: &Pubkey,
+    weight_action: Option<VoterWeightAction>,
+    gateway_token_info: &'a AccountInfo<'info>,
+    additional_gateway_token_infos: &'a [AccountInfo<'info>],
+) -> Result<Option<UnixTimestamp>> {
+    let gateway_token_infos: Vec<&AccountInfo> = std::iter::once(gateway_token_info)
+        .chain(additional_gateway_token_infos.iter())
+        .collect();
+
+    let action_gatekeeper_policies = match weight_action {
+        Some(weight_action) => vec![registrar.get_action_gatekeeper_policy(weight_action)],
+        None => registrar.action_gatekeeper_policies.to_vec(),
+    };
+
+    let mut expire_time = None;
+
+    for action_gatekeeper_policy in action_gatekeeper_policies {
+        let policy_expire_time = verify_action_gatekeeper_policy(
+            registrar,
+            governing_token_owner,
+            action_gatekeeper_policy,
+            &gateway_token_infos,
+        )?;
+
+        expire_time = min_expire_time(expire_time, policy_expire_time);
+    }
+
+    Ok(expire_time)
+}
+
+/// Returns the slot the gateway token expire_time falls on
+/// The slot is estimated from the current clock using the default slot duration
+/// and a gateway token which already expired (accepted within the tolerance) expires at the current slot
+pub fn get_gateway_token_expiry_slot(expire_time: UnixTimestamp, clock: &Clock) -> u64 {
+    let seconds_to_expiry = expire_time.saturating_sub(clock.unix_timestamp).max(0) as u64;
+
+    clock
+        .slot
+        .saturating_add(seconds_to_expiry.saturating_mul(1000) / DEFAULT_MS_PER_SLOT)
+}
+
+fn verify_action_gatekeeper_policy(
+    registrar: &Registrar,
+    governing_token_owner: &Pubkey,
+    action_gatekeeper_policy: ActionGatekeeperPolicy,
+    gateway_token_infos: &[&AccountInfo],
+) -> Result<Option<UnixTimestamp>> {
+    let gatekeeper_networks = registrar.get_gatekeeper_networks();
+
+    let gatekeeper_network_policy = match action_gatekeeper_policy {
+        ActionGatekeeperPolicy::Exempt => return Ok(None),
+        ActionGatekeeperPolicy::Default => registrar.gatekeeper_network_policy,
+        ActionGatekeeperPolicy::AnyOf => GatekeeperNetworkPolicy::AnyOf,
+        ActionGatekeeperPolicy::AllOf => GatekeeperNetworkPolicy::AllOf,
+        ActionGatekeeperPolicy::Network(index) => {
+            let gatekeeper_network = gatekeeper_networks
+                .get(index as usize)
+                .ok_or(GatewayError::InvalidActionGatekeeperPolicy)?;
+
+            return verify_gateway_token_for_network(
+                registrar,
+                governing_token_owner,
+                gatekeeper_network,
+                gateway_token_infos,
+            );
+        }
+    };
+
+    match gatekeeper_network_policy {
+        GatekeeperNetworkPolicy::AnyOf => {
+            let mut valid_expire_times = vec![];
+            let mut first_error = None;
+
+            for gatekeeper_network in gatekeeper_networks.iter() {
+                match verify_gateway_token_for_network(
+                    registrar,
+                    governing_token_owner,
+                    gatekeeper_network,
+                    gateway_token_infos,
+                ) {
+                    Ok(expire_time) => valid_expire_times.push(expire_time),
+                    Err(error) => {
+                        first_error.get_or_insert(error);
+                    }
+                }
+            }
+
+            // The voter weight is bound by the valid gateway token which lasts the longest
+            match valid_expire_times.into_iter().reduce(max_expire_time) {
+                Some(expire_time) => Ok(expire_time),
+                None => Err(first_error.unwrap()),
+            }
+        }
+        GatekeeperNetworkPolicy::AllOf => {
+            require!(
+                gateway_token_infos.len() >= gatekeeper_networks.len(),
+                GatewayError::MissingGatewayToken
+            );
+
+            let mut expire_time = None;
+
+            // The voter weight is bound by the gateway token which expires first
+            for gatekeeper_network in gatekeeper_networks.iter() {
+                let network_expire_time = verify_gateway_token_for_network(
+                    registrar,
+                    governing_token_owner,
+                    gatekeeper_network,
+                    gateway_token_infos,
+                )?;
+
+                expire_time = min_expire_time(expire_time, network_expire_time);
+            }
+
+            Ok(expire_time)
+        }
+    }
+}
+
+/// Verifies any of the given gateway tokens is valid for the owner and the gatekeeper network
+/// and returns the expire_time of the valid gateway token which lasts the longest
+///
+/// If none of the gateway tokens is valid then the error is specific to the state of the voter's token for the network
+fn verify_gateway_token_for_network(
+    registrar: &Registrar,
+    governing_token_owner: &Pubkey,
+    gatekeeper_network: &Pubkey,
+    gateway_token_infos: &[&AccountInfo],
+) -> Result<Option<UnixTimestamp>> {
+    let options = registrar.gateway_token_verification_options;
+
+    let mut valid_expire_times = vec![];
+    let mut error = GatewayError::InvalidGatewayToken;
+
+    for gateway_token_info in gateway_token_infos.iter() {
+        let gateway_token = match Gateway::parse_gateway_token(gateway_token_info) {
+            Ok(gateway_token) => gateway_token,
+            Err(_) => continue,
+        };
+
+        if gateway_token.owner_wallet != *governing_token_owner
+            || gateway_token.gatekeeper_network != *gatekeeper_network
+        {
+            continue;
+        }
+
+        // The gateway token is parsed from the account data and hence the account must be owned by the gateway program
+        // otherwise anybody could provide an account with the same layout as a valid pass
+        if *gateway_token_info.owner != GATEWAY_PROGRAM_ID {
+            error = GatewayError::InvalidGatewayTokenAccountOwner;
+            continue;
+        }
+
+        match Gateway::verify_gateway_token(
+            &gateway_token,
+            governing_token_owner,
+            gatekeeper_network,
+            gateway_token_info.lamports(),
+            Some(VerificationOptions {
+                check_expiry: !options.ignore_expiry,
+                expiry_tolerance_seconds: Some(options.expiry_tolerance_seconds),
+            }),
+        ) {
+            Ok(()) => valid_expire_times.push(gateway_token.expire_time),
+            Err(_) => {
+                error = match gateway_token.state {
+                    GatewayTokenState::Frozen => GatewayError::GatewayTokenFrozen,
+                    GatewayTokenState::Revoked => GatewayError::GatewayTokenRevoked,
+                    GatewayTokenState::Active => GatewayError::GatewayTokenExpired,
+                }
+            }
+        }
+    }
+
+    valid_expire_times
+        .into_iter()
+        .reduce(max_expire_time)
+        .ok_or_else(|| error.into())
+}
+
+/// Returns the earlier of the two expire times where None means the gateway token never expires
+fn min_expire_time(
+    expire_time_a: Option<UnixTimestamp>,
+    expire_time_b: Option<UnixTimestamp>,
+) -> Option<UnixTimestamp> {
+    match (expire_time_a, expire_time_b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (expire_time, None) | (None, expire_time) => expire_time,
+    }
+}
+
+/// Returns the later of the two expire times where None means the gateway token never expires
+fn max_expire_time(
+    expire_time_a: Option<UnixTimestamp>,
+    expire_time_b: Option<UnixTimestamp>,
+) -> Option<UnixTimestamp> {
+    match (expire_time_a, expire_time_b) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        _ => None,
+    }
+}
@@ -0,0 +1,98 @@
+use gpl_civic_gateway::error::GatewayError;
+use program_test::gateway_voter_test::GatewayVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_close_max_voter_weight_record() -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    let max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    gateway_voter_test
+        .close_max_voter_weight_record(&registrar_cookie, &max_voter_weight_record_cookie)
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = gateway_voter_test
+        .bench
+        .get_account(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(None, max_voter_weight_record);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_max_voter_weight_record_with_realm_authority_must_sign_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    let max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = gateway_voter_test
+        .close_max_voter_weight_record_using_ix(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_max_voter_weight_record_with_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    let max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = gateway_voter_test
+        .close_max_voter_weight_record_using_ix(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::InvalidRealmAuthority);
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use gpl_civic_gateway::error::GatewayError;
+use program_test::gateway_voter_test::GatewayVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_close_registrar() -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    // Act
+    gateway_voter_test
+        .close_registrar(&registrar_cookie)
+        .await?;
+
+    // Assert
+    let registrar = gateway_voter_test
+        .bench
+        .get_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(None, registrar);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_registrar_with_realm_authority_must_sign_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    // Act
+    let err = gateway_voter_test
+        .close_registrar_using_ix(
+            &registrar_cookie,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_registrar_with_invalid_realm_authority_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = gateway_voter_test
+        .close_registrar_using_ix(
+            &registrar_cookie,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::InvalidRealmAuthority);
+
+    Ok(())
+}
@@ -0,0 +1,202 @@
+use anchor_lang::AccountSerialize;
+use gpl_civic_gateway::error::GatewayError;
+use gpl_civic_gateway::state::VoterWeightRecord;
+use itertools::Either;
+use program_test::gateway_voter_test::GatewayVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::{account::Account, transport::TransportError};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_close_voter_weight_record() -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, registrar_cookie, _, _, voter_cookie) = gateway_voter_test.setup(false).await?;
+
+    let voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    gateway_voter_test
+        .close_voter_weight_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = gateway_voter_test
+        .bench
+        .get_account(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(None, voter_weight_record);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_voter_weight_record_with_unexpired_voter_weight_record_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, _, gateway_token_cookie, voter_cookie) =
+        gateway_voter_test.setup(false).await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let voter_token_owner_record_cookie = gateway_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, 100)
+        .await?;
+
+    gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Right(&voter_token_owner_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await?;
+
+    // Act
+    let err = gateway_voter_test
+        .close_voter_weight_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::VoterWeightRecordMustBeExpired);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_voter_weight_record_without_expiry_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, registrar_cookie, _, _, voter_cookie) = gateway_voter_test.setup(false).await?;
+
+    let voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Set the expiry of the VoterWeightRecord to None
+    let voter_weight_record_account = gateway_voter_test
+        .bench
+        .get_account(&voter_weight_record_cookie.address)
+        .await
+        .unwrap();
+
+    let mut voter_weight_record = gateway_voter_test
+        .bench
+        .get_anchor_account::<VoterWeightRecord>(voter_weight_record_cookie.address)
+        .await;
+
+    voter_weight_record.voter_weight_expiry = None;
+
+    let mut data = vec![];
+    voter_weight_record.try_serialize(&mut data).unwrap();
+    data.resize(voter_weight_record_account.data.len(), 0);
+
+    gateway_voter_test.bench.set_account(
+        &voter_weight_record_cookie.address,
+        &Account {
+            data,
+            ..voter_weight_record_account
+        },
+    );
+
+    // Act
+    let err = gateway_voter_test
+        .close_voter_weight_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::VoterWeightRecordMustBeExpired);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_voter_weight_record_with_invalid_governing_token_owner_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, registrar_cookie, _, _, voter_cookie) = gateway_voter_test.setup(false).await?;
+
+    let voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Try to close the record using a different wallet
+    let voter_cookie2 = gateway_voter_test.bench.with_wallet().await;
+
+    // Act
+    let err = gateway_voter_test
+        .close_voter_weight_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie2,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::InvalidTokenOwnerForVoterWeightRecord);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_voter_weight_record_with_governing_token_owner_must_sign_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, registrar_cookie, _, _, voter_cookie) = gateway_voter_test.setup(false).await?;
+
+    let voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = gateway_voter_test
+        .close_voter_weight_record_using_ix(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie,
+            |i| i.accounts[2].is_signer = false, // governing_token_owner
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
@@ -0,0 +1,321 @@
+use gpl_civic_gateway::error::GatewayError;
+use gpl_civic_gateway::state::{
+    ActionGatekeeperPolicy, ActionGatekeeperPolicyConfig, GatekeeperNetworkPolicy,
+    VoterWeightAction,
+};
+use program_test::{gateway_voter_test::GatewayVoterTest, tools::*};
+use solana_program_test::*;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_configure_gatekeeper_networks() -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    let gateway_cookie2 = gateway_voter_test.with_gateway().await?;
+    let gateway_cookie3 = gateway_voter_test.with_gateway().await?;
+
+    // Act
+    gateway_voter_test
+        .configure_gatekeeper_networks(
+            &mut registrar_cookie,
+            GatekeeperNetworkPolicy::AllOf,
+            &[&gateway_cookie2, &gateway_cookie3],
+        )
+        .await?;
+
+    // Assert
+    let registrar = gateway_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.gatekeeper_network_policy,
+        GatekeeperNetworkPolicy::AllOf
+    );
+    assert_eq!(
+        registrar.additional_gatekeeper_networks,
+        vec![
+            gateway_cookie2.gatekeeper_network.pubkey(),
+            gateway_cookie3.gatekeeper_network.pubkey()
+        ]
+    );
+
+    assert_eq!(registrar, registrar_cookie.account);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_gatekeeper_networks_with_additional_networks_removed(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    let gateway_cookie2 = gateway_voter_test.with_gateway().await?;
+
+    gateway_voter_test
+        .configure_gatekeeper_networks(
+            &mut registrar_cookie,
+            GatekeeperNetworkPolicy::AllOf,
+            &[&gateway_cookie2],
+        )
+        .await?;
+
+    // Act
+    gateway_voter_test
+        .configure_gatekeeper_networks(&mut registrar_cookie, GatekeeperNetworkPolicy::AnyOf, &[])
+        .await?;
+
+    // Assert
+    let registrar = gateway_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.additional_gatekeeper_networks, vec![]);
+    assert_eq!(registrar, registrar_cookie.account);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_gatekeeper_networks_with_too_many_networks_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    let additional_gatekeeper_networks = (0..4).map(|_| Pubkey::new_unique()).collect();
+
+    // Act
+    let err = gateway_voter_test
+        .configure_gatekeeper_networks_using_ix(
+            &mut registrar_cookie,
+            GatekeeperNetworkPolicy::AnyOf,
+            additional_gatekeeper_networks,
+            NopOverride,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::TooManyGatekeeperNetworks);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_gatekeeper_networks_with_duplicate_network_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, gateway_cookie, _, _) = gateway_voter_test.setup(false).await?;
+
+    // Act
+    let err = gateway_voter_test
+        .configure_gatekeeper_networks(
+            &mut registrar_cookie,
+            GatekeeperNetworkPolicy::AllOf,
+            &[&gateway_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::DuplicateGatekeeperNetwork);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_gatekeeper_networks_with_network_appended_after_action_policy_network(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, gateway_cookie, _, _) =
+        gateway_voter_test.setup(false).await?;
+
+    let gateway_cookie2 = gateway_voter_test.with_gateway().await?;
+    let gateway_cookie3 = gateway_voter_test.with_gateway().await?;
+
+    gateway_voter_test
+        .configure_gatekeeper_networks(
+            &mut registrar_cookie,
+            GatekeeperNetworkPolicy::AnyOf,
+            &[&gateway_cookie2],
+        )
+        .await?;
+
+    gateway_voter_test
+        .configure_registrar_with_action_gatekeeper_policies(
+            &realm_cookie,
+            &registrar_cookie,
+            &gateway_cookie,
+            None,
+            vec![ActionGatekeeperPolicyConfig {
+                action: VoterWeightAction::CreateGovernance,
+                policy: ActionGatekeeperPolicy::Network(1),
+            }],
+        )
+        .await?;
+
+    // Act
+    gateway_voter_test
+        .configure_gatekeeper_networks(
+            &mut registrar_cookie,
+            GatekeeperNetworkPolicy::AnyOf,
+            &[&gateway_cookie2, &gateway_cookie3],
+        )
+        .await?;
+
+    // Assert
+    let registrar = gateway_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.additional_gatekeeper_networks,
+        vec![
+            gateway_cookie2.gatekeeper_network.pubkey(),
+            gateway_cookie3.gatekeeper_network.pubkey()
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_gatekeeper_networks_with_action_policy_network_removed_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, gateway_cookie, _, _) =
+        gateway_voter_test.setup(false).await?;
+
+    let gateway_cookie2 = gateway_voter_test.with_gateway().await?;
+
+    gateway_voter_test
+        .configure_gatekeeper_networks(
+            &mut registrar_cookie,
+            GatekeeperNetworkPolicy::AnyOf,
+            &[&gateway_cookie2],
+        )
+        .await?;
+
+    gateway_voter_test
+        .configure_registrar_with_action_gatekeeper_policies(
+            &realm_cookie,
+            &registrar_cookie,
+            &gateway_cookie,
+            None,
+            vec![ActionGatekeeperPolicyConfig {
+                action: VoterWeightAction::CreateGovernance,
+                policy: ActionGatekeeperPolicy::Network(1),
+            }],
+        )
+        .await?;
+
+    // Act
+    let err = gateway_voter_test
+        .configure_gatekeeper_networks(&mut registrar_cookie, GatekeeperNetworkPolicy::AnyOf, &[])
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::GatekeeperNetworkUsedByActionPolicy);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_gatekeeper_networks_with_action_policy_network_reordered_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, gateway_cookie, _, _) =
+        gateway_voter_test.setup(false).await?;
+
+    let gateway_cookie2 = gateway_voter_test.with_gateway().await?;
+    let gateway_cookie3 = gateway_voter_test.with_gateway().await?;
+
+    gateway_voter_test
+        .configure_gatekeeper_networks(
+            &mut registrar_cookie,
+            GatekeeperNetworkPolicy::AnyOf,
+            &[&gateway_cookie2, &gateway_cookie3],
+        )
+        .await?;
+
+    gateway_voter_test
+        .configure_registrar_with_action_gatekeeper_policies(
+            &realm_cookie,
+            &registrar_cookie,
+            &gateway_cookie,
+            None,
+            vec![ActionGatekeeperPolicyConfig {
+                action: VoterWeightAction::CastVote,
+                policy: ActionGatekeeperPolicy::Network(2),
+            }],
+        )
+        .await?;
+
+    // Act
+    let err = gateway_voter_test
+        .configure_gatekeeper_networks(
+            &mut registrar_cookie,
+            GatekeeperNetworkPolicy::AnyOf,
+            &[&gateway_cookie3, &gateway_cookie2],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::GatekeeperNetworkUsedByActionPolicy);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_gatekeeper_networks_with_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = gateway_voter_test
+        .configure_gatekeeper_networks_using_ix(
+            &mut registrar_cookie,
+            GatekeeperNetworkPolicy::AnyOf,
+            vec![Pubkey::new_unique()],
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::InvalidRealmAuthority);
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use gpl_civic_gateway::error::GatewayError;
+use gpl_civic_gateway::state::GatewayTokenVerificationOptions;
+use program_test::{gateway_voter_test::GatewayVoterTest, tools::*};
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_configure_gateway_token_verification() -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    // Act
+    gateway_voter_test
+        .configure_gateway_token_verification(
+            &mut registrar_cookie,
+            GatewayTokenVerificationOptions {
+                ignore_expiry: false,
+                expiry_tolerance_seconds: 3600,
+            },
+        )
+        .await?;
+
+    // Assert
+    let registrar = gateway_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.gateway_token_verification_options,
+        GatewayTokenVerificationOptions {
+            ignore_expiry: false,
+            expiry_tolerance_seconds: 3600,
+        }
+    );
+
+    assert_eq!(registrar, registrar_cookie.account);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_gateway_token_verification_with_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = gateway_voter_test
+        .configure_gateway_token_verification_using_ix(
+            &mut registrar_cookie,
+            GatewayTokenVerificationOptions {
+                ignore_expiry: true,
+                expiry_tolerance_seconds: 0,
+            },
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::InvalidRealmAuthority);
+
+    Ok(())
+}
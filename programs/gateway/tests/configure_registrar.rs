@@ -4,6 +4,9 @@ use anchor_lang::prelude::Pubkey;
 use program_test::gateway_voter_test::GatewayVoterTest;
 
 use gpl_civic_gateway::error::GatewayError;
+use gpl_civic_gateway::state::{
+    ActionGatekeeperPolicy, ActionGatekeeperPolicyConfig, VoterWeightAction,
+};
 use solana_program::instruction::InstructionError;
 use solana_program_test::*;
 use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
@@ -74,6 +77,88 @@ async fn test_configure_registrar_new_previous_plugin() -> Result<(), TransportE
     Ok(())
 }
 
+#[tokio::test]
+async fn test_configure_registrar_with_action_gatekeeper_policies() -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, gateway_cookie, _, _) =
+        gateway_voter_test.setup(false).await?;
+
+    // Act
+    gateway_voter_test
+        .configure_registrar_with_action_gatekeeper_policies(
+            &realm_cookie,
+            &registrar_cookie,
+            &gateway_cookie,
+            None,
+            vec![
+                ActionGatekeeperPolicyConfig {
+                    action: VoterWeightAction::CommentProposal,
+                    policy: ActionGatekeeperPolicy::Exempt,
+                },
+                ActionGatekeeperPolicyConfig {
+                    action: VoterWeightAction::CreateGovernance,
+                    policy: ActionGatekeeperPolicy::Network(0),
+                },
+            ],
+        )
+        .await?;
+
+    // Assert
+    let registrar = gateway_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.get_action_gatekeeper_policy(VoterWeightAction::CommentProposal),
+        ActionGatekeeperPolicy::Exempt
+    );
+    assert_eq!(
+        registrar.get_action_gatekeeper_policy(VoterWeightAction::CreateGovernance),
+        ActionGatekeeperPolicy::Network(0)
+    );
+    assert_eq!(
+        registrar.get_action_gatekeeper_policy(VoterWeightAction::CastVote),
+        ActionGatekeeperPolicy::Default
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_registrar_with_invalid_action_gatekeeper_network_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, gateway_cookie, _, _) =
+        gateway_voter_test.setup(false).await?;
+
+    // Act
+
+    // Only the primary gatekeeper network at index 0 is configured
+    let err = gateway_voter_test
+        .configure_registrar_with_action_gatekeeper_policies(
+            &realm_cookie,
+            &registrar_cookie,
+            &gateway_cookie,
+            None,
+            vec![ActionGatekeeperPolicyConfig {
+                action: VoterWeightAction::CreateProposal,
+                policy: ActionGatekeeperPolicy::Network(1),
+            }],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::InvalidActionGatekeeperPolicy);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_configure_registrar_missing_previous_plugin_error() -> Result<(), TransportError> {
     // Arrange
@@ -90,6 +175,7 @@ async fn test_configure_registrar_missing_previous_plugin_error() -> Result<(),
             &gateway_cookie,
             None,
             true, // This causes the error
+            vec![],
             NopOverride,
             None,
         )
@@ -126,6 +212,7 @@ async fn test_configure_registrar_with_invalid_realm_authority_error() -> Result
             &gateway_cookie,
             None,
             false,
+            vec![],
             NopOverride,
             Some(Some([&broken_realm_cookie.realm_authority].as_slice())),
         )
@@ -156,6 +243,7 @@ async fn test_configure_registrar_with_realm_authority_must_sign_error(
             &gateway_cookie,
             None,
             false,
+            vec![],
             |i| i.accounts[2].is_signer = false, // realm_authority
             Some(None), // Some(None) = Override the signers (Some) with nothing (None)
         )
@@ -189,6 +277,7 @@ async fn test_configure_registrar_with_invalid_spl_gov_program_id_error(
             &gateway_cookie,
             None,
             false,
+            vec![],
             |i| i.accounts[1].pubkey = governance_program_id, //governance_program_id
             None,
         )
@@ -218,6 +307,7 @@ async fn test_configure_registrar_with_invalid_realm_error() -> Result<(), Trans
             &gateway_cookie,
             None,
             false,
+            vec![],
             |i| i.accounts[1].pubkey = Pubkey::new_unique(), // realm
             None,
         )
@@ -0,0 +1,163 @@
+use gpl_civic_gateway::error::GatewayError;
+use gpl_civic_gateway::state::VoterWeightMode;
+use program_test::{gateway_voter_test::GatewayVoterTest, tools::*};
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_configure_voter_weight_mode_with_fixed_voter_weight() -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    let mut max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    gateway_voter_test
+        .configure_voter_weight_mode(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            VoterWeightMode::Fixed(1),
+            Some(500),
+        )
+        .await?;
+
+    // Assert
+    let registrar = gateway_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.voter_weight_mode, VoterWeightMode::Fixed(1));
+    assert_eq!(registrar, registrar_cookie.account);
+
+    let max_voter_weight_record = gateway_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 500);
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+    assert_eq!(
+        max_voter_weight_record,
+        max_voter_weight_record_cookie.account
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_voter_weight_mode_with_capped_voter_weight() -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    let mut max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    gateway_voter_test
+        .configure_voter_weight_mode(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            VoterWeightMode::Fixed(1),
+            Some(500),
+        )
+        .await?;
+
+    // Act
+    gateway_voter_test
+        .configure_voter_weight_mode(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            VoterWeightMode::Capped(100),
+            None,
+        )
+        .await?;
+
+    // Assert
+    let registrar = gateway_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.voter_weight_mode, VoterWeightMode::Capped(100));
+
+    // The static max voter weight of the Fixed mode must be refreshed before it can be used again
+    let max_voter_weight_record = gateway_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, Some(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_voter_weight_mode_with_fixed_voter_weight_and_missing_max_voter_weight_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    let mut max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = gateway_voter_test
+        .configure_voter_weight_mode_using_ix(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            VoterWeightMode::Fixed(1),
+            None,
+            NopOverride,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::MissingMaxVoterWeight);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_voter_weight_mode_with_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    let mut max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = gateway_voter_test
+        .configure_voter_weight_mode_using_ix(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            VoterWeightMode::Fixed(1),
+            Some(500),
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::InvalidRealmAuthority);
+
+    Ok(())
+}
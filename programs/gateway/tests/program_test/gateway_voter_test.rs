@@ -4,10 +4,12 @@ use std::sync::Arc;
 use anchor_lang::prelude::Pubkey;
 use itertools::Either;
 use solana_gateway::{
-    instruction::{add_gatekeeper, issue_vanilla},
-    state::{get_gatekeeper_address_with_seed, get_gateway_token_address_with_seed},
+    instruction::{add_gatekeeper, issue_vanilla, set_state},
+    state::{
+        get_gatekeeper_address_with_seed, get_gateway_token_address_with_seed, GatewayTokenState,
+    },
 };
-use solana_program::instruction::AccountMeta;
+use solana_program::{clock::UnixTimestamp, instruction::AccountMeta};
 
 use gpl_civic_gateway::state::{get_registrar_address, Registrar, *};
 use solana_sdk::{
@@ -40,6 +42,11 @@ pub struct VoterWeightRecordCookie {
     pub account: VoterWeightRecord,
 }
 
+pub struct MaxVoterWeightRecordCookie {
+    pub address: Pubkey,
+    pub account: MaxVoterWeightRecord,
+}
+
 pub struct GatewayCookie {
     pub gatekeeper_network: Keypair,
     pub gatekeeper: Keypair,
@@ -203,7 +210,12 @@ impl GatewayVoterTest {
             realm: realm_cookie.address,
             governing_token_mint: realm_cookie.account.community_mint,
             gatekeeper_network: gateway_cookie.gatekeeper_network.pubkey(),
-            reserved: [0; 128],
+            gatekeeper_network_policy: GatekeeperNetworkPolicy::AnyOf,
+            additional_gatekeeper_networks: vec![],
+            action_gatekeeper_policies: Default::default(),
+            gateway_token_verification_options: Default::default(),
+            voter_weight_mode: VoterWeightMode::Passthrough,
+            reserved: [0; 3],
         };
 
         Ok(RegistrarCookie {
@@ -294,15 +306,33 @@ impl GatewayVoterTest {
         gateway_cookie: &GatewayCookie,
         wallet_cookie: &WalletCookie,
     ) -> Result<GatewayTokenCookie, TransportError> {
-        self.with_gateway_token_using_ix(gateway_cookie, wallet_cookie, NopOverride, None)
+        self.with_gateway_token_using_ix(gateway_cookie, wallet_cookie, None, NopOverride, None)
             .await
     }
 
+    #[allow(dead_code)]
+    pub async fn with_gateway_token_using_expiry(
+        &mut self,
+        gateway_cookie: &GatewayCookie,
+        wallet_cookie: &WalletCookie,
+        expire_time: Option<UnixTimestamp>,
+    ) -> Result<GatewayTokenCookie, TransportError> {
+        self.with_gateway_token_using_ix(
+            gateway_cookie,
+            wallet_cookie,
+            expire_time,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
     #[allow(dead_code)]
     pub async fn with_gateway_token_using_ix<F: Fn(&mut Instruction)>(
         &mut self,
         gateway_cookie: &GatewayCookie,
         wallet_cookie: &WalletCookie,
+        expire_time: Option<UnixTimestamp>,
         instruction_override: F,
         signers_override: Option<&[&Keypair]>,
     ) -> Result<GatewayTokenCookie, TransportError> {
@@ -316,7 +346,7 @@ impl GatewayVoterTest {
             &gateway_cookie.gatekeeper.pubkey(),
             &gateway_cookie.gatekeeper_network.pubkey(),
             None,
-            None,
+            expire_time,
         );
 
         instruction_override(&mut issue_ix);
@@ -331,6 +361,46 @@ impl GatewayVoterTest {
         Ok(gateway_token_cookie)
     }
 
+    #[allow(dead_code)]
+    pub async fn freeze_gateway_token(
+        &mut self,
+        gateway_cookie: &GatewayCookie,
+        gateway_token_cookie: &GatewayTokenCookie,
+    ) -> Result<(), TransportError> {
+        let freeze_ix = set_state(
+            &gateway_token_cookie.address,
+            &gateway_cookie.gatekeeper.pubkey(),
+            &gateway_cookie.get_gatekeeper_account(),
+            GatewayTokenState::Frozen,
+        );
+
+        self.bench
+            .process_transaction(&[freeze_ix], Some(&[&gateway_cookie.gatekeeper]))
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn revoke_gateway_token(
+        &mut self,
+        gateway_cookie: &GatewayCookie,
+        gateway_token_cookie: &GatewayTokenCookie,
+    ) -> Result<(), TransportError> {
+        let revoke_ix = set_state(
+            &gateway_token_cookie.address,
+            &gateway_cookie.gatekeeper.pubkey(),
+            &gateway_cookie.get_gatekeeper_account(),
+            GatewayTokenState::Revoked,
+        );
+
+        self.bench
+            .process_transaction(&[revoke_ix], Some(&[&gateway_cookie.gatekeeper]))
+            .await?;
+
+        Ok(())
+    }
+
     #[allow(dead_code)]
     pub async fn with_voter_weight_record(
         &self,
@@ -402,6 +472,59 @@ impl GatewayVoterTest {
         })
     }
 
+    #[allow(dead_code)]
+    pub async fn close_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        voter_cookie: &WalletCookie,
+    ) -> Result<(), BanksClientError> {
+        self.close_voter_weight_record_using_ix(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            voter_cookie,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        voter_cookie: &WalletCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_civic_gateway::instruction::CloseVoterWeightRecord {},
+        );
+
+        let accounts = gpl_civic_gateway::accounts::CloseVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            governing_token_owner: voter_cookie.address,
+            beneficiary: self.bench.payer.pubkey(),
+        };
+
+        let mut close_voter_weight_record_ix = Instruction {
+            program_id: gpl_civic_gateway::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut close_voter_weight_record_ix);
+
+        let default_signers = &[&voter_cookie.signer];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[close_voter_weight_record_ix], Some(signers))
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn update_voter_weight_record(
         &self,
@@ -409,6 +532,25 @@ impl GatewayVoterTest {
         input_voter_weight_cookie: &mut Either<&VoterWeightRecordCookie, &TokenOwnerRecordCookie>,
         output_voter_weight_record_cookie: &mut VoterWeightRecordCookie,
         gateway_token_cookie: &GatewayTokenCookie,
+    ) -> Result<(), BanksClientError> {
+        self.update_voter_weight_record_with_additional_gateway_tokens(
+            registrar_cookie,
+            input_voter_weight_cookie,
+            output_voter_weight_record_cookie,
+            gateway_token_cookie,
+            &[],
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record_with_additional_gateway_tokens(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        input_voter_weight_cookie: &mut Either<&VoterWeightRecordCookie, &TokenOwnerRecordCookie>,
+        output_voter_weight_record_cookie: &mut VoterWeightRecordCookie,
+        gateway_token_cookie: &GatewayTokenCookie,
+        additional_gateway_token_cookies: &[&GatewayTokenCookie],
     ) -> Result<(), BanksClientError> {
         let data = anchor_lang::InstructionData::data(
             &gpl_civic_gateway::instruction::UpdateVoterWeightRecord {},
@@ -421,7 +563,15 @@ impl GatewayVoterTest {
             input_voter_weight: extract_voting_weight_address(input_voter_weight_cookie),
         };
 
-        let account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        // The gateway tokens for the additional gatekeeper networks are passed as remaining accounts
+        for additional_gateway_token_cookie in additional_gateway_token_cookies {
+            account_metas.push(AccountMeta::new_readonly(
+                additional_gateway_token_cookie.address,
+                false,
+            ));
+        }
 
         let instructions = vec![Instruction {
             program_id: gpl_civic_gateway::id(),
@@ -432,6 +582,170 @@ impl GatewayVoterTest {
         self.bench.process_transaction(&instructions, None).await
     }
 
+    #[allow(dead_code)]
+    pub async fn with_max_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+    ) -> Result<MaxVoterWeightRecordCookie, BanksClientError> {
+        self.with_max_voter_weight_record_using_ix(registrar_cookie, NopOverride)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_max_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        instruction_override: F,
+    ) -> Result<MaxVoterWeightRecordCookie, BanksClientError> {
+        let max_voter_weight_record_key = get_max_voter_weight_record_address(
+            &registrar_cookie.account.realm,
+            &registrar_cookie.account.governing_token_mint,
+        );
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_civic_gateway::instruction::CreateMaxVoterWeightRecord {},
+        );
+
+        let accounts = gpl_civic_gateway::accounts::CreateMaxVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            max_voter_weight_record: max_voter_weight_record_key,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut create_max_voter_weight_record_ix = Instruction {
+            program_id: gpl_civic_gateway::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut create_max_voter_weight_record_ix);
+
+        self.bench
+            .process_transaction(&[create_max_voter_weight_record_ix], None)
+            .await?;
+
+        let account = MaxVoterWeightRecord {
+            realm: registrar_cookie.account.realm,
+            governing_token_mint: registrar_cookie.account.governing_token_mint,
+            max_voter_weight: 0,
+            max_voter_weight_expiry: Some(0),
+            reserved: [0; 8],
+        };
+
+        Ok(MaxVoterWeightRecordCookie {
+            address: max_voter_weight_record_key,
+            account,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_max_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+    ) -> Result<(), BanksClientError> {
+        self.close_max_voter_weight_record_using_ix(
+            registrar_cookie,
+            max_voter_weight_record_cookie,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_max_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_civic_gateway::instruction::CloseMaxVoterWeightRecord {},
+        );
+
+        let accounts = gpl_civic_gateway::accounts::CloseMaxVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            beneficiary: self.bench.payer.pubkey(),
+        };
+
+        let mut close_max_voter_weight_record_ix = Instruction {
+            program_id: gpl_civic_gateway::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut close_max_voter_weight_record_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[close_max_voter_weight_record_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_max_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &mut MaxVoterWeightRecordCookie,
+        input_max_voter_weight_record_cookie: Option<&MaxVoterWeightRecordCookie>,
+    ) -> Result<(), BanksClientError> {
+        self.update_max_voter_weight_record_using_ix(
+            registrar_cookie,
+            max_voter_weight_record_cookie,
+            input_max_voter_weight_record_cookie,
+            NopOverride,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_max_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &mut MaxVoterWeightRecordCookie,
+        input_max_voter_weight_record_cookie: Option<&MaxVoterWeightRecordCookie>,
+        instruction_override: F,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_civic_gateway::instruction::UpdateMaxVoterWeightRecord {},
+        );
+
+        let mut accounts = anchor_lang::ToAccountMetas::to_account_metas(
+            &gpl_civic_gateway::accounts::UpdateMaxVoterWeightRecord {
+                registrar: registrar_cookie.address,
+                max_voter_weight_record: max_voter_weight_record_cookie.address,
+            },
+            None,
+        );
+
+        // The predecessor MaxVoterWeightRecord or the governing token mint is passed as the first remaining account
+        let remaining_account = input_max_voter_weight_record_cookie
+            .map(|cookie| cookie.address)
+            .unwrap_or(registrar_cookie.account.governing_token_mint);
+
+        accounts.push(AccountMeta::new_readonly(remaining_account, false));
+
+        let mut update_max_voter_weight_record_ix = Instruction {
+            program_id: gpl_civic_gateway::id(),
+            accounts,
+            data,
+        };
+
+        instruction_override(&mut update_max_voter_weight_record_ix);
+
+        self.bench
+            .process_transaction(&[update_max_voter_weight_record_ix], None)
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn configure_registrar(
         &self,
@@ -439,6 +753,25 @@ impl GatewayVoterTest {
         registrar_cookie: &RegistrarCookie,
         gateway_cookie: &GatewayCookie,
         predecessor_program_id: Option<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        self.configure_registrar_with_action_gatekeeper_policies(
+            realm_cookie,
+            registrar_cookie,
+            gateway_cookie,
+            predecessor_program_id,
+            vec![],
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_registrar_with_action_gatekeeper_policies(
+        &self,
+        realm_cookie: &RealmCookie,
+        registrar_cookie: &RegistrarCookie,
+        gateway_cookie: &GatewayCookie,
+        predecessor_program_id: Option<Pubkey>,
+        action_gatekeeper_policies: Vec<ActionGatekeeperPolicyConfig>,
     ) -> Result<(), BanksClientError> {
         self.configure_registrar_using_ix(
             realm_cookie,
@@ -446,6 +779,7 @@ impl GatewayVoterTest {
             gateway_cookie,
             predecessor_program_id,
             predecessor_program_id.is_some(),
+            action_gatekeeper_policies,
             NopOverride,
             None,
         )
@@ -460,12 +794,14 @@ impl GatewayVoterTest {
         gateway_cookie: &GatewayCookie,
         predecessor_program_id: Option<Pubkey>,
         use_previous_voter_weight_plugin: bool,
+        action_gatekeeper_policies: Vec<ActionGatekeeperPolicyConfig>,
         instruction_override: F,
         signers_override: Option<Option<&[&Keypair]>>,
     ) -> Result<(), BanksClientError> {
         let data = anchor_lang::InstructionData::data(
             &gpl_civic_gateway::instruction::ConfigureRegistrar {
                 use_previous_voter_weight_plugin,
+                action_gatekeeper_policies,
             },
         );
 
@@ -499,6 +835,246 @@ impl GatewayVoterTest {
             .await
     }
 
+    #[allow(dead_code)]
+    pub async fn configure_gatekeeper_networks(
+        &self,
+        registrar_cookie: &mut RegistrarCookie,
+        gatekeeper_network_policy: GatekeeperNetworkPolicy,
+        additional_gateway_cookies: &[&GatewayCookie],
+    ) -> Result<(), BanksClientError> {
+        let additional_gatekeeper_networks = additional_gateway_cookies
+            .iter()
+            .map(|gateway_cookie| gateway_cookie.gatekeeper_network.pubkey())
+            .collect();
+
+        self.configure_gatekeeper_networks_using_ix(
+            registrar_cookie,
+            gatekeeper_network_policy,
+            additional_gatekeeper_networks,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_gatekeeper_networks_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &mut RegistrarCookie,
+        gatekeeper_network_policy: GatekeeperNetworkPolicy,
+        additional_gatekeeper_networks: Vec<Pubkey>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_civic_gateway::instruction::ConfigureGatekeeperNetworks {
+                gatekeeper_network_policy,
+                additional_gatekeeper_networks: additional_gatekeeper_networks.clone(),
+            },
+        );
+
+        let accounts = gpl_civic_gateway::accounts::ConfigureGatekeeperNetworks {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+        };
+
+        let mut configure_gatekeeper_networks_ix = Instruction {
+            program_id: gpl_civic_gateway::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_gatekeeper_networks_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_gatekeeper_networks_ix], Some(signers))
+            .await?;
+
+        registrar_cookie.account.gatekeeper_network_policy = gatekeeper_network_policy;
+        registrar_cookie.account.additional_gatekeeper_networks = additional_gatekeeper_networks;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_gateway_token_verification(
+        &self,
+        registrar_cookie: &mut RegistrarCookie,
+        gateway_token_verification_options: GatewayTokenVerificationOptions,
+    ) -> Result<(), BanksClientError> {
+        self.configure_gateway_token_verification_using_ix(
+            registrar_cookie,
+            gateway_token_verification_options,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_gateway_token_verification_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &mut RegistrarCookie,
+        gateway_token_verification_options: GatewayTokenVerificationOptions,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_civic_gateway::instruction::ConfigureGatewayTokenVerification {
+                gateway_token_verification_options,
+            },
+        );
+
+        let accounts = gpl_civic_gateway::accounts::ConfigureGatewayTokenVerification {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+        };
+
+        let mut configure_gateway_token_verification_ix = Instruction {
+            program_id: gpl_civic_gateway::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_gateway_token_verification_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_gateway_token_verification_ix], Some(signers))
+            .await?;
+
+        registrar_cookie.account.gateway_token_verification_options =
+            gateway_token_verification_options;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_voter_weight_mode(
+        &self,
+        registrar_cookie: &mut RegistrarCookie,
+        max_voter_weight_record_cookie: &mut MaxVoterWeightRecordCookie,
+        voter_weight_mode: VoterWeightMode,
+        max_voter_weight: Option<u64>,
+    ) -> Result<(), BanksClientError> {
+        self.configure_voter_weight_mode_using_ix(
+            registrar_cookie,
+            max_voter_weight_record_cookie,
+            voter_weight_mode,
+            max_voter_weight,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_voter_weight_mode_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &mut RegistrarCookie,
+        max_voter_weight_record_cookie: &mut MaxVoterWeightRecordCookie,
+        voter_weight_mode: VoterWeightMode,
+        max_voter_weight: Option<u64>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_civic_gateway::instruction::ConfigureVoterWeightMode {
+                voter_weight_mode,
+                max_voter_weight,
+            },
+        );
+
+        let accounts = gpl_civic_gateway::accounts::ConfigureVoterWeightMode {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+        };
+
+        let mut configure_voter_weight_mode_ix = Instruction {
+            program_id: gpl_civic_gateway::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_voter_weight_mode_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_voter_weight_mode_ix], Some(signers))
+            .await?;
+
+        registrar_cookie.account.voter_weight_mode = voter_weight_mode;
+
+        match voter_weight_mode {
+            VoterWeightMode::Fixed(_) => {
+                max_voter_weight_record_cookie.account.max_voter_weight = max_voter_weight.unwrap();
+                max_voter_weight_record_cookie
+                    .account
+                    .max_voter_weight_expiry = None;
+            }
+            VoterWeightMode::Passthrough | VoterWeightMode::Capped(_) => {
+                max_voter_weight_record_cookie
+                    .account
+                    .max_voter_weight_expiry = Some(0);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_registrar(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+    ) -> Result<(), BanksClientError> {
+        self.close_registrar_using_ix(registrar_cookie, NopOverride, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_registrar_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_civic_gateway::instruction::CloseRegistrar {});
+
+        let accounts = gpl_civic_gateway::accounts::CloseRegistrar {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            beneficiary: self.bench.payer.pubkey(),
+        };
+
+        let mut close_registrar_ix = Instruction {
+            program_id: gpl_civic_gateway::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut close_registrar_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[close_registrar_ix], Some(signers))
+            .await
+    }
+
     /// Casts a vote
     #[allow(dead_code)]
     pub async fn cast_vote(
@@ -576,4 +1152,14 @@ impl GatewayVoterTest {
     pub async fn get_voter_weight_record(&self, voter_weight_record: &Pubkey) -> VoterWeightRecord {
         self.bench.get_anchor_account(*voter_weight_record).await
     }
+
+    #[allow(dead_code)]
+    pub async fn get_max_voter_weight_record(
+        &self,
+        max_voter_weight_record: &Pubkey,
+    ) -> MaxVoterWeightRecord {
+        self.bench
+            .get_anchor_account(*max_voter_weight_record)
+            .await
+    }
 }
@@ -2,13 +2,14 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use anchor_lang::prelude::Pubkey;
+use borsh::{BorshDeserialize, BorshSerialize};
 
 use gpl_civic_gateway::state::*;
 use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
 use spl_governance_addin_mock::instruction::*;
 
 use crate::program_test::{
-    gateway_voter_test::VoterWeightRecordCookie,
+    gateway_voter_test::{MaxVoterWeightRecordCookie, VoterWeightRecordCookie},
     governance_test::RealmCookie,
     program_test_bench::{ProgramTestBench, WalletCookie},
 };
@@ -39,10 +40,49 @@ impl PredecessorPluginTest {
         realm_cookie: &RealmCookie,
         voter_cookie: &WalletCookie,
         voter_weight: u64,
+    ) -> Result<VoterWeightRecordCookie, TransportError> {
+        self.with_voter_weight_record_using_expiry(realm_cookie, voter_cookie, voter_weight, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_voter_weight_record_using_expiry(
+        &self,
+        realm_cookie: &RealmCookie,
+        voter_cookie: &WalletCookie,
+        voter_weight: u64,
+        voter_weight_expiry: Option<u64>,
+    ) -> Result<VoterWeightRecordCookie, TransportError> {
+        self.with_voter_weight_record_using_action(
+            realm_cookie,
+            voter_cookie,
+            voter_weight,
+            voter_weight_expiry,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_voter_weight_record_using_action(
+        &self,
+        realm_cookie: &RealmCookie,
+        voter_cookie: &WalletCookie,
+        voter_weight: u64,
+        voter_weight_expiry: Option<u64>,
+        weight_action: Option<VoterWeightAction>,
     ) -> Result<VoterWeightRecordCookie, TransportError> {
         let governing_token_owner = voter_cookie.address;
         let voter_weight_record_account = Keypair::new();
 
+        // VoterWeightAction is redefined by the plugin and it has the same layout as the spl-governance-addin-api enum
+        let addin_weight_action = weight_action.map(|weight_action| {
+            spl_governance_addin_api::voter_weight::VoterWeightAction::try_from_slice(
+                &weight_action.try_to_vec().unwrap(),
+            )
+            .unwrap()
+        });
+
         let setup_voter_weight_record_ix = setup_voter_weight_record(
             &Self::program_id(),
             &realm_cookie.address,
@@ -51,8 +91,8 @@ impl PredecessorPluginTest {
             &voter_weight_record_account.pubkey(),
             &self.bench.payer.pubkey(),
             voter_weight,
-            Some(0),
-            None,
+            voter_weight_expiry,
+            addin_weight_action,
             None,
         );
 
@@ -67,9 +107,9 @@ impl PredecessorPluginTest {
             realm: realm_cookie.address,
             governing_token_mint: realm_cookie.account.community_mint,
             governing_token_owner,
-            voter_weight: 0,
-            voter_weight_expiry: Some(0),
-            weight_action: None,
+            voter_weight,
+            voter_weight_expiry,
+            weight_action,
             weight_action_target: None,
             reserved: [0; 8],
         };
@@ -79,4 +119,44 @@ impl PredecessorPluginTest {
             account,
         })
     }
+
+    #[allow(dead_code)]
+    pub async fn with_max_voter_weight_record(
+        &self,
+        realm_cookie: &RealmCookie,
+        max_voter_weight: u64,
+        max_voter_weight_expiry: Option<u64>,
+    ) -> Result<MaxVoterWeightRecordCookie, TransportError> {
+        let max_voter_weight_record_account = Keypair::new();
+
+        let setup_max_voter_weight_record_ix = setup_max_voter_weight_record(
+            &Self::program_id(),
+            &realm_cookie.address,
+            &realm_cookie.account.community_mint,
+            &max_voter_weight_record_account.pubkey(),
+            &self.bench.payer.pubkey(),
+            max_voter_weight,
+            max_voter_weight_expiry,
+        );
+
+        self.bench
+            .process_transaction(
+                &[setup_max_voter_weight_record_ix],
+                Some(&[&max_voter_weight_record_account]),
+            )
+            .await?;
+
+        let account = MaxVoterWeightRecord {
+            realm: realm_cookie.address,
+            governing_token_mint: realm_cookie.account.community_mint,
+            max_voter_weight,
+            max_voter_weight_expiry,
+            reserved: [0; 8],
+        };
+
+        Ok(MaxVoterWeightRecordCookie {
+            address: max_voter_weight_record_account.pubkey(),
+            account,
+        })
+    }
 }
@@ -109,6 +109,13 @@ impl ProgramTestBench {
             .unwrap();
     }
 
+    #[allow(dead_code)]
+    pub async fn advance_clock_by_seconds(&self, seconds: i64) {
+        let mut clock = self.get_clock().await;
+        clock.unix_timestamp += seconds;
+        self.context.borrow_mut().set_sysvar(&clock);
+    }
+
     pub async fn with_mint(&self) -> Result<MintCookie, TransportError> {
         let mint_keypair = Keypair::new();
         let mint_authority = Keypair::new();
@@ -297,6 +304,13 @@ impl ProgramTestBench {
             .unwrap()
     }
 
+    #[allow(dead_code)]
+    pub fn set_account(&self, address: &Pubkey, account: &Account) {
+        self.context
+            .borrow_mut()
+            .set_account(address, &account.clone().into());
+    }
+
     #[allow(dead_code)]
     pub async fn get_borsh_account<T: BorshDeserialize>(&self, address: &Pubkey) -> T {
         self.get_account(address)
@@ -0,0 +1,312 @@
+use gpl_civic_gateway::error::GatewayError;
+use gpl_civic_gateway::state::VoterWeightMode;
+use program_test::{gateway_voter_test::GatewayVoterTest, tools::*};
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_mint_supply() -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, _, _, voter_cookie) =
+        gateway_voter_test.setup(false).await?;
+
+    // Mint governing tokens to increase the mint supply
+    gateway_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, 100)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+    let clock = gateway_voter_test.bench.get_clock().await;
+
+    // Act
+    gateway_voter_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = gateway_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 100);
+    assert_eq!(
+        max_voter_weight_record.max_voter_weight_expiry,
+        Some(clock.slot)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_fixed_voter_weight_mode(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, _, _, voter_cookie) =
+        gateway_voter_test.setup(false).await?;
+
+    // Mint governing tokens to increase the mint supply
+    gateway_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, 100)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    gateway_voter_test
+        .configure_voter_weight_mode(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            VoterWeightMode::Fixed(1),
+            Some(10),
+        )
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    gateway_voter_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    // Assert
+
+    // The static max voter weight configured for the Fixed mode is not replaced with the mint supply
+    let max_voter_weight_record = gateway_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 10);
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_predecessor_max_voter_weight_record(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, _, _, _) = gateway_voter_test.setup(true).await?;
+
+    let predecessor_max_voter_weight_record_cookie = gateway_voter_test
+        .predecessor_plugin
+        .with_max_voter_weight_record(&realm_cookie, 250, None)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    gateway_voter_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            Some(&predecessor_max_voter_weight_record_cookie),
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = gateway_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 250);
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_missing_predecessor_max_voter_weight_record_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, registrar_cookie, _, _, _) = gateway_voter_test.setup(true).await?;
+
+    let mut max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = gateway_voter_test
+        .update_max_voter_weight_record_using_ix(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            None,
+            |i| {
+                i.accounts.pop(); // predecessor_max_voter_weight_record
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::MissingPredecessorMaxVoterWeightRecord);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_expired_predecessor_max_voter_weight_record_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, _, _, _) = gateway_voter_test.setup(true).await?;
+
+    let predecessor_max_voter_weight_record_cookie = gateway_voter_test
+        .predecessor_plugin
+        .with_max_voter_weight_record(&realm_cookie, 250, Some(0))
+        .await?;
+
+    let mut max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = gateway_voter_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            Some(&predecessor_max_voter_weight_record_cookie),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::PredecessorMaxVoterWeightRecordExpired);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_predecessor_max_voter_weight_record_not_owned_by_predecessor_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, _, _, _) = gateway_voter_test.setup(true).await?;
+
+    let mut max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let predecessor_max_voter_weight_record_cookie = gateway_voter_test
+        .predecessor_plugin
+        .with_max_voter_weight_record(&realm_cookie, 250, None)
+        .await?;
+
+    // Gateway MaxVoterWeightRecord is not owned by the predecessor plugin
+    let gateway_max_voter_weight_record = max_voter_weight_record_cookie.address;
+
+    // Act
+    let err = gateway_voter_test
+        .update_max_voter_weight_record_using_ix(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            Some(&predecessor_max_voter_weight_record_cookie),
+            |i| i.accounts[2].pubkey = gateway_max_voter_weight_record, // predecessor_max_voter_weight_record
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::InvalidPredecessorMaxVoterWeightRecord);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_invalid_governing_token_mint_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    let mut max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let mint_cookie = gateway_voter_test.bench.with_mint().await?;
+
+    // Act
+    let err = gateway_voter_test
+        .update_max_voter_weight_record_using_ix(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            None,
+            |i| i.accounts[2].pubkey = mint_cookie.address, // governing_token_mint
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::InvalidGoverningTokenMintForRegistrar);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_missing_governing_token_mint_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (_, registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    let mut max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = gateway_voter_test
+        .update_max_voter_weight_record_using_ix(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            None,
+            |i| {
+                i.accounts.pop(); // governing_token_mint
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::MissingGoverningTokenMint);
+
+    Ok(())
+}
@@ -0,0 +1,356 @@
+use gpl_civic_gateway::error::GatewayError;
+use gpl_civic_gateway::state::{
+    ActionGatekeeperPolicy, ActionGatekeeperPolicyConfig, GatekeeperNetworkPolicy,
+    VoterWeightAction, VoterWeightMode,
+};
+use itertools::Either;
+use program_test::gateway_voter_test::{GatewayTokenCookie, GatewayVoterTest};
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+const EXPECTED_VOTES: u64 = 1000000;
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_exempt_action() -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, gateway_cookie, _, _) =
+        gateway_voter_test.setup(true).await?;
+
+    gateway_voter_test
+        .configure_registrar_with_action_gatekeeper_policies(
+            &realm_cookie,
+            &registrar_cookie,
+            &gateway_cookie,
+            registrar_cookie
+                .account
+                .previous_voter_weight_plugin_program_id,
+            vec![ActionGatekeeperPolicyConfig {
+                action: VoterWeightAction::CommentProposal,
+                policy: ActionGatekeeperPolicy::Exempt,
+            }],
+        )
+        .await?;
+
+    // The voter doesn't hold any gateway token
+    let voter_cookie = gateway_voter_test.bench.with_wallet().await;
+    let gateway_token_cookie = GatewayTokenCookie::new(&voter_cookie.address, &gateway_cookie);
+
+    let predecessor_voter_weight_record_cookie = gateway_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record_using_action(
+            &realm_cookie,
+            &voter_cookie,
+            EXPECTED_VOTES,
+            None,
+            Some(VoterWeightAction::CommentProposal),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Left(&predecessor_voter_weight_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = gateway_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, EXPECTED_VOTES);
+    assert_eq!(
+        voter_weight_record.weight_action,
+        Some(VoterWeightAction::CommentProposal)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_exempt_action_and_fixed_voter_weight(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, gateway_cookie, _, _) =
+        gateway_voter_test.setup(true).await?;
+
+    gateway_voter_test
+        .configure_registrar_with_action_gatekeeper_policies(
+            &realm_cookie,
+            &registrar_cookie,
+            &gateway_cookie,
+            registrar_cookie
+                .account
+                .previous_voter_weight_plugin_program_id,
+            vec![ActionGatekeeperPolicyConfig {
+                action: VoterWeightAction::CommentProposal,
+                policy: ActionGatekeeperPolicy::Exempt,
+            }],
+        )
+        .await?;
+
+    let mut max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    gateway_voter_test
+        .configure_voter_weight_mode(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            VoterWeightMode::Fixed(1),
+            Some(10),
+        )
+        .await?;
+
+    // The voter doesn't hold any gateway token
+    let voter_cookie = gateway_voter_test.bench.with_wallet().await;
+    let gateway_token_cookie = GatewayTokenCookie::new(&voter_cookie.address, &gateway_cookie);
+
+    let predecessor_voter_weight_record_cookie = gateway_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record_using_action(
+            &realm_cookie,
+            &voter_cookie,
+            EXPECTED_VOTES,
+            None,
+            Some(VoterWeightAction::CommentProposal),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Left(&predecessor_voter_weight_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = gateway_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // The fixed voter weight is only granted to voters verified with gateway tokens
+    assert_eq!(voter_weight_record.voter_weight, 0);
+    assert_eq!(
+        voter_weight_record.weight_action,
+        Some(VoterWeightAction::CommentProposal)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_not_exempt_action_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, gateway_cookie, _, _) =
+        gateway_voter_test.setup(true).await?;
+
+    gateway_voter_test
+        .configure_registrar_with_action_gatekeeper_policies(
+            &realm_cookie,
+            &registrar_cookie,
+            &gateway_cookie,
+            registrar_cookie
+                .account
+                .previous_voter_weight_plugin_program_id,
+            vec![ActionGatekeeperPolicyConfig {
+                action: VoterWeightAction::CommentProposal,
+                policy: ActionGatekeeperPolicy::Exempt,
+            }],
+        )
+        .await?;
+
+    // The voter doesn't hold any gateway token
+    let voter_cookie = gateway_voter_test.bench.with_wallet().await;
+    let gateway_token_cookie = GatewayTokenCookie::new(&voter_cookie.address, &gateway_cookie);
+
+    let predecessor_voter_weight_record_cookie = gateway_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record_using_action(
+            &realm_cookie,
+            &voter_cookie,
+            EXPECTED_VOTES,
+            None,
+            Some(VoterWeightAction::CastVote),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Left(&predecessor_voter_weight_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::InvalidGatewayToken);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_exempt_action_and_any_input_action_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, gateway_cookie, _, _) =
+        gateway_voter_test.setup(false).await?;
+
+    gateway_voter_test
+        .configure_registrar_with_action_gatekeeper_policies(
+            &realm_cookie,
+            &registrar_cookie,
+            &gateway_cookie,
+            None,
+            vec![ActionGatekeeperPolicyConfig {
+                action: VoterWeightAction::CommentProposal,
+                policy: ActionGatekeeperPolicy::Exempt,
+            }],
+        )
+        .await?;
+
+    // The voter doesn't hold any gateway token
+    let voter_cookie = gateway_voter_test.bench.with_wallet().await;
+    let gateway_token_cookie = GatewayTokenCookie::new(&voter_cookie.address, &gateway_cookie);
+
+    // TokenOwnerRecord as the input voter weight can be used for any action
+    let voter_token_owner_record_cookie = gateway_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, EXPECTED_VOTES)
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Right(&voter_token_owner_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::InvalidGatewayToken);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_action_network_policy_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, gateway_cookie, gateway_token_cookie, voter_cookie) =
+        gateway_voter_test.setup(true).await?;
+
+    let gateway_cookie2 = gateway_voter_test.with_gateway().await?;
+
+    gateway_voter_test
+        .configure_gatekeeper_networks(
+            &mut registrar_cookie,
+            GatekeeperNetworkPolicy::AnyOf,
+            &[&gateway_cookie2],
+        )
+        .await?;
+
+    // CreateGovernance requires a gateway token from the stricter additional network
+    gateway_voter_test
+        .configure_registrar_with_action_gatekeeper_policies(
+            &realm_cookie,
+            &registrar_cookie,
+            &gateway_cookie,
+            registrar_cookie
+                .account
+                .previous_voter_weight_plugin_program_id,
+            vec![ActionGatekeeperPolicyConfig {
+                action: VoterWeightAction::CreateGovernance,
+                policy: ActionGatekeeperPolicy::Network(1),
+            }],
+        )
+        .await?;
+
+    let predecessor_voter_weight_record_cookie = gateway_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record_using_action(
+            &realm_cookie,
+            &voter_cookie,
+            EXPECTED_VOTES,
+            None,
+            Some(VoterWeightAction::CreateGovernance),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+
+    // The voter only holds a gateway token from the primary gatekeeper network
+    let err = gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Left(&predecessor_voter_weight_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::InvalidGatewayToken);
+
+    Ok(())
+}
@@ -0,0 +1,220 @@
+use gpl_civic_gateway::error::GatewayError;
+use gpl_civic_gateway::state::GatekeeperNetworkPolicy;
+use itertools::Either;
+use program_test::gateway_voter_test::GatewayVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+const EXPECTED_VOTES: u64 = 1000000;
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_any_of_gatekeeper_networks(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, _, _, _) = gateway_voter_test.setup(false).await?;
+
+    let gateway_cookie2 = gateway_voter_test.with_gateway().await?;
+
+    gateway_voter_test
+        .configure_gatekeeper_networks(
+            &mut registrar_cookie,
+            GatekeeperNetworkPolicy::AnyOf,
+            &[&gateway_cookie2],
+        )
+        .await?;
+
+    // The voter only holds a gateway token from the additional gatekeeper network
+    let voter_cookie = gateway_voter_test.bench.with_wallet().await;
+    let gateway_token_cookie2 = gateway_voter_test
+        .with_gateway_token(&gateway_cookie2, &voter_cookie)
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let voter_token_owner_record_cookie = gateway_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, EXPECTED_VOTES)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Right(&voter_token_owner_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie2,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = gateway_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, EXPECTED_VOTES);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_all_of_gatekeeper_networks(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, _, gateway_token_cookie, voter_cookie) =
+        gateway_voter_test.setup(false).await?;
+
+    let gateway_cookie2 = gateway_voter_test.with_gateway().await?;
+
+    gateway_voter_test
+        .configure_gatekeeper_networks(
+            &mut registrar_cookie,
+            GatekeeperNetworkPolicy::AllOf,
+            &[&gateway_cookie2],
+        )
+        .await?;
+
+    let gateway_token_cookie2 = gateway_voter_test
+        .with_gateway_token(&gateway_cookie2, &voter_cookie)
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let voter_token_owner_record_cookie = gateway_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, EXPECTED_VOTES)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    gateway_voter_test
+        .update_voter_weight_record_with_additional_gateway_tokens(
+            &registrar_cookie,
+            &mut Either::Right(&voter_token_owner_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+            &[&gateway_token_cookie2],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = gateway_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, EXPECTED_VOTES);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_all_of_gatekeeper_networks_and_missing_gateway_token_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, _, gateway_token_cookie, voter_cookie) =
+        gateway_voter_test.setup(false).await?;
+
+    let gateway_cookie2 = gateway_voter_test.with_gateway().await?;
+
+    gateway_voter_test
+        .configure_gatekeeper_networks(
+            &mut registrar_cookie,
+            GatekeeperNetworkPolicy::AllOf,
+            &[&gateway_cookie2],
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let voter_token_owner_record_cookie = gateway_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, EXPECTED_VOTES)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Right(&voter_token_owner_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::MissingGatewayToken);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_all_of_gatekeeper_networks_and_invalid_gateway_token_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, _, gateway_token_cookie, voter_cookie) =
+        gateway_voter_test.setup(false).await?;
+
+    let gateway_cookie2 = gateway_voter_test.with_gateway().await?;
+
+    gateway_voter_test
+        .configure_gatekeeper_networks(
+            &mut registrar_cookie,
+            GatekeeperNetworkPolicy::AllOf,
+            &[&gateway_cookie2],
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let voter_token_owner_record_cookie = gateway_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, EXPECTED_VOTES)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+
+    // The gateway token from the primary gatekeeper network is not valid for the additional network
+    let err = gateway_voter_test
+        .update_voter_weight_record_with_additional_gateway_tokens(
+            &registrar_cookie,
+            &mut Either::Right(&voter_token_owner_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+            &[&gateway_token_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::InvalidGatewayToken);
+
+    Ok(())
+}
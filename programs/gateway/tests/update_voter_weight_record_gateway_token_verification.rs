@@ -0,0 +1,336 @@
+use gpl_civic_gateway::error::GatewayError;
+use gpl_civic_gateway::state::GatewayTokenVerificationOptions;
+use itertools::Either;
+use program_test::gateway_voter_test::{GatewayTokenCookie, GatewayVoterTest};
+use program_test::tools::*;
+use solana_program::clock::DEFAULT_MS_PER_SLOT;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+
+mod program_test;
+
+const EXPECTED_VOTES: u64 = 1000000;
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_expired_gateway_token_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, gateway_cookie, _, _) =
+        gateway_voter_test.setup(false).await?;
+
+    let clock = gateway_voter_test.bench.get_clock().await;
+
+    let voter_cookie = gateway_voter_test.bench.with_wallet().await;
+    let gateway_token_cookie = gateway_voter_test
+        .with_gateway_token_using_expiry(
+            &gateway_cookie,
+            &voter_cookie,
+            Some(clock.unix_timestamp + 100),
+        )
+        .await?;
+
+    let voter_token_owner_record_cookie = gateway_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, EXPECTED_VOTES)
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+    gateway_voter_test.bench.advance_clock_by_seconds(200).await;
+
+    // Act
+    let err = gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Right(&voter_token_owner_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::GatewayTokenExpired);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_expired_gateway_token_within_tolerance(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, gateway_cookie, _, _) =
+        gateway_voter_test.setup(false).await?;
+
+    gateway_voter_test
+        .configure_gateway_token_verification(
+            &mut registrar_cookie,
+            GatewayTokenVerificationOptions {
+                ignore_expiry: false,
+                expiry_tolerance_seconds: 300,
+            },
+        )
+        .await?;
+
+    let clock = gateway_voter_test.bench.get_clock().await;
+
+    let voter_cookie = gateway_voter_test.bench.with_wallet().await;
+    let gateway_token_cookie = gateway_voter_test
+        .with_gateway_token_using_expiry(
+            &gateway_cookie,
+            &voter_cookie,
+            Some(clock.unix_timestamp + 100),
+        )
+        .await?;
+
+    let voter_token_owner_record_cookie = gateway_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, EXPECTED_VOTES)
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+    gateway_voter_test.bench.advance_clock_by_seconds(200).await;
+
+    // Act
+    gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Right(&voter_token_owner_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = gateway_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    let clock = gateway_voter_test.bench.get_clock().await;
+
+    assert_eq!(voter_weight_record.voter_weight, EXPECTED_VOTES);
+
+    // The expired gateway token is only accepted for the current slot
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_voter_weight_expiry_capped_by_gateway_token(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, gateway_cookie, _, _) =
+        gateway_voter_test.setup(true).await?;
+
+    let clock = gateway_voter_test.bench.get_clock().await;
+    let gateway_token_expire_time = clock.unix_timestamp + 100;
+
+    let voter_cookie = gateway_voter_test.bench.with_wallet().await;
+    let gateway_token_cookie = gateway_voter_test
+        .with_gateway_token_using_expiry(
+            &gateway_cookie,
+            &voter_cookie,
+            Some(gateway_token_expire_time),
+        )
+        .await?;
+
+    // The predecessor voter weight outlives the gateway token
+    let predecessor_voter_weight_record_cookie = gateway_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record_using_expiry(
+            &realm_cookie,
+            &voter_cookie,
+            EXPECTED_VOTES,
+            Some(clock.slot + 1_000_000),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Left(&predecessor_voter_weight_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = gateway_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    let clock = gateway_voter_test.bench.get_clock().await;
+    let seconds_to_expiry = (gateway_token_expire_time - clock.unix_timestamp) as u64;
+
+    assert_eq!(voter_weight_record.voter_weight, EXPECTED_VOTES);
+    assert_eq!(
+        voter_weight_record.voter_weight_expiry,
+        Some(clock.slot + seconds_to_expiry * 1000 / DEFAULT_MS_PER_SLOT)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_frozen_gateway_token_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, gateway_cookie, gateway_token_cookie, voter_cookie) =
+        gateway_voter_test.setup(false).await?;
+
+    gateway_voter_test
+        .freeze_gateway_token(&gateway_cookie, &gateway_token_cookie)
+        .await?;
+
+    let voter_token_owner_record_cookie = gateway_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, EXPECTED_VOTES)
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Right(&voter_token_owner_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::GatewayTokenFrozen);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_revoked_gateway_token_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, gateway_cookie, gateway_token_cookie, voter_cookie) =
+        gateway_voter_test.setup(false).await?;
+
+    gateway_voter_test
+        .revoke_gateway_token(&gateway_cookie, &gateway_token_cookie)
+        .await?;
+
+    let voter_token_owner_record_cookie = gateway_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, EXPECTED_VOTES)
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Right(&voter_token_owner_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::GatewayTokenRevoked);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_gateway_token_not_owned_by_gateway_program_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, _, gateway_token_cookie, voter_cookie) =
+        gateway_voter_test.setup(false).await?;
+
+    // Copy a valid gateway token into an account owned by another program
+    let gateway_token_account = gateway_voter_test
+        .bench
+        .get_account(&gateway_token_cookie.address)
+        .await
+        .unwrap();
+
+    let forged_gateway_token_cookie = GatewayTokenCookie {
+        address: Pubkey::new_unique(),
+    };
+
+    gateway_voter_test.bench.set_account(
+        &forged_gateway_token_cookie.address,
+        &Account {
+            owner: Pubkey::new_unique(),
+            ..gateway_token_account
+        },
+    );
+
+    let voter_token_owner_record_cookie = gateway_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, EXPECTED_VOTES)
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Right(&voter_token_owner_record_cookie),
+            &mut voter_weight_record_cookie,
+            &forged_gateway_token_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::InvalidGatewayTokenAccountOwner);
+
+    Ok(())
+}
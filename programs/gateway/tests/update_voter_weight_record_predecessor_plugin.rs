@@ -1,4 +1,5 @@
 use crate::program_test::governance_test::RealmCookie;
+use gpl_civic_gateway::error::GatewayError;
 use itertools::Either;
 use program_test::{gateway_voter_test::GatewayVoterTest, tools::*};
 use solana_program::instruction::InstructionError;
@@ -196,6 +197,101 @@ async fn test_update_fails_with_predecessor_with_a_different_owner() -> Result<(
     Ok(())
 }
 
+#[tokio::test]
+async fn test_update_voter_weight_record_with_predecessor_expiry_carried_forward(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, _, gateway_token_cookie, voter_cookie) =
+        gateway_voter_test.setup(true).await?;
+
+    let clock = gateway_voter_test.bench.get_clock().await;
+    let predecessor_voter_weight_expiry = clock.slot + 100;
+
+    let predecessor_voter_weight_record_cookie = gateway_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record_using_expiry(
+            &realm_cookie,
+            &voter_cookie,
+            EXPECTED_VOTES,
+            Some(predecessor_voter_weight_expiry),
+        )
+        .await?;
+
+    let mut gateway_voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Left(&predecessor_voter_weight_record_cookie),
+            &mut gateway_voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = gateway_voter_test
+        .get_voter_weight_record(&gateway_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, EXPECTED_VOTES);
+    assert_eq!(
+        voter_weight_record.voter_weight_expiry,
+        Some(predecessor_voter_weight_expiry)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_fails_with_expired_predecessor() -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, _, gateway_token_cookie, voter_cookie) =
+        gateway_voter_test.setup(true).await?;
+
+    // the predecessor voter weight record expired at slot 0 and can't be used any longer
+    let predecessor_voter_weight_record_cookie = gateway_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record_using_expiry(
+            &realm_cookie,
+            &voter_cookie,
+            EXPECTED_VOTES,
+            Some(0),
+        )
+        .await?;
+
+    let mut gateway_voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Left(&predecessor_voter_weight_record_cookie),
+            &mut gateway_voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gateway_err(err, GatewayError::PredecessorVoterWeightRecordExpired);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_cast_vote_with_update_voter_weight_record_and_predecessor(
 ) -> Result<(), TransportError> {
@@ -0,0 +1,167 @@
+use gpl_civic_gateway::state::VoterWeightMode;
+use itertools::Either;
+use program_test::gateway_voter_test::GatewayVoterTest;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_fixed_voter_weight() -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, _, gateway_token_cookie, voter_cookie) =
+        gateway_voter_test.setup(false).await?;
+
+    let mut max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    gateway_voter_test
+        .configure_voter_weight_mode(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            VoterWeightMode::Fixed(1),
+            Some(10),
+        )
+        .await?;
+
+    let voter_token_owner_record_cookie = gateway_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, 1000)
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Right(&voter_token_owner_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = gateway_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // Every verified voter gets the same voter weight regardless of the deposited tokens
+    assert_eq!(voter_weight_record.voter_weight, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_capped_voter_weight() -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, _, gateway_token_cookie, voter_cookie) =
+        gateway_voter_test.setup(false).await?;
+
+    let mut max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    gateway_voter_test
+        .configure_voter_weight_mode(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            VoterWeightMode::Capped(500),
+            Some(10),
+        )
+        .await?;
+
+    let voter_token_owner_record_cookie = gateway_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, 1000)
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Right(&voter_token_owner_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = gateway_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // The deposited tokens exceed the cap
+    assert_eq!(voter_weight_record.voter_weight, 500);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_capped_voter_weight_below_cap(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut gateway_voter_test = GatewayVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, _, gateway_token_cookie, voter_cookie) =
+        gateway_voter_test.setup(false).await?;
+
+    let mut max_voter_weight_record_cookie = gateway_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    gateway_voter_test
+        .configure_voter_weight_mode(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            VoterWeightMode::Capped(500),
+            Some(10),
+        )
+        .await?;
+
+    let voter_token_owner_record_cookie = gateway_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, 100)
+        .await?;
+
+    let mut voter_weight_record_cookie = gateway_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    gateway_voter_test.bench.advance_clock().await;
+
+    // Act
+    gateway_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Right(&voter_token_owner_record_cookie),
+            &mut voter_weight_record_cookie,
+            &gateway_token_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = gateway_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // The deposited tokens are below the cap and passed through
+    assert_eq!(voter_weight_record.voter_weight, 100);
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use gpl_plugin_core::input_voter_weight::InputVoterWeightError;
 
 #[error_code]
 pub enum NftVoterError {
@@ -73,4 +74,148 @@ pub enum NftVoterError {
 
     #[msg("VoterWeightRecord must be expired")]
     VoterWeightRecordMustBeExpired,
+
+    #[msg("Invalid Registrar for NftActionTicket")]
+    InvalidRegistrarForNftActionTicket,
+
+    #[msg("Invalid TokenOwner for NftActionTicket")]
+    InvalidTokenOwnerForNftActionTicket,
+
+    #[msg("Invalid VoterWeightAction for NftActionTicket")]
+    InvalidActionForNftActionTicket,
+
+    #[msg("NftActionTicket expired")]
+    NftActionTicketExpired,
+
+    #[msg("NftActionTicket already issued")]
+    NftActionTicketAlreadyIssued,
+
+    #[msg("NftActionTicket doesn't match the given NFT and action")]
+    NftActionTicketMismatch,
+
+    #[msg("Max collections can't be less than the number of configured collections")]
+    InvalidMaxCollections,
+
+    #[msg("Invalid TokenRecord for Programmable NFT")]
+    InvalidTokenRecord,
+
+    #[msg("Programmable NFT is locked")]
+    ProgrammableNftLocked,
+
+    #[msg("Programmable NFT is delegated")]
+    ProgrammableNftDelegated,
+
+    #[msg("Merkle tree must be created by Bubblegum")]
+    InvalidBubblegumMerkleTree,
+
+    #[msg("Invalid NftWeightOverride")]
+    InvalidNftWeightOverride,
+
+    #[msg("Collection with NFT weight overrides can't be removed")]
+    CollectionHasNftWeightOverrides,
+
+    #[msg("NFT weight bonus must be greater than zero")]
+    InvalidNftWeightBonus,
+
+    #[msg("NFT doesn't belong to the collection")]
+    InvalidNftCollection,
+
+    #[msg("Collection metadata accounts must be provided for all configured collections")]
+    InvalidCollectionMetadataAccounts,
+
+    #[msg("Voter has unrelinquished votes")]
+    VoterHasUnrelinquishedVotes,
+
+    #[msg("Registrar with configured collections can't be closed")]
+    RegistrarHasCollections,
+
+    #[msg("Previous voter weight plugin required but not provided")]
+    MissingPreviousVoterWeightPlugin,
+
+    #[msg("Invalid VoterWeightRecord of the previous voter weight plugin")]
+    InvalidPredecessorVoterWeightRecord,
+
+    #[msg("Invalid VoterWeightRecord realm of the previous voter weight plugin")]
+    InvalidPredecessorVoterWeightRecordRealm,
+
+    #[msg("Invalid VoterWeightRecord governance token mint of the previous voter weight plugin")]
+    InvalidPredecessorVoterWeightRecordGovTokenMint,
+
+    #[msg("Invalid VoterWeightRecord governance token owner of the previous voter weight plugin")]
+    InvalidPredecessorVoterWeightRecordGovTokenOwner,
+
+    #[msg("Voter weight of the previous voter weight plugin must be greater than zero")]
+    PredecessorVoterWeightIsZero,
+
+    #[msg("VoterWeightAction doesn't match the previous voter weight plugin action")]
+    InvalidPredecessorVoterWeightAction,
+
+    #[msg("Weight action target doesn't match the previous voter weight plugin action target")]
+    InvalidPredecessorVoterWeightActionTarget,
+
+    #[msg("VoterWeightRecord of the previous voter weight plugin expired")]
+    PredecessorVoterWeightRecordExpired,
+
+    #[msg("NFT weight overflow")]
+    NftWeightOverflow,
+
+    #[msg("NftActionTicket already consumed")]
+    NftActionTicketAlreadyConsumed,
+
+    #[msg("NftActionTicket must be expired")]
+    NftActionTicketMustBeExpired,
+
+    #[msg("Invalid payer for NftActionTicket")]
+    InvalidPayerForNftActionTicket,
+
+    #[msg("Registrar can't grow by more than 10KB in a single resize")]
+    RegistrarSizeIncreaseTooLarge,
+
+    #[msg("Registrar has NftVoteRecords which were not relinquished")]
+    RegistrarHasNftVoteRecords,
+
+    #[msg("Missing predecessor MaxVoterWeightRecord")]
+    MissingPredecessorMaxVoterWeightRecord,
+
+    #[msg("Invalid predecessor MaxVoterWeightRecord")]
+    InvalidPredecessorMaxVoterWeightRecord,
+
+    #[msg("Invalid predecessor MaxVoterWeightRecord realm")]
+    InvalidPredecessorMaxVoterWeightRecordRealm,
+
+    #[msg("Invalid predecessor MaxVoterWeightRecord governance token mint")]
+    InvalidPredecessorMaxVoterWeightRecordGovTokenMint,
+
+    #[msg("Predecessor MaxVoterWeightRecord expired")]
+    PredecessorMaxVoterWeightRecordExpired,
+}
+
+impl From<InputVoterWeightError> for NftVoterError {
+    fn from(error: InputVoterWeightError) -> Self {
+        match error {
+            // The NFT plugin only takes the predecessor VoterWeightRecord as the input voter weight
+            InputVoterWeightError::InvalidTokenOwnerRecord
+            | InputVoterWeightError::InvalidVoterWeightRecord => {
+                NftVoterError::InvalidPredecessorVoterWeightRecord
+            }
+            InputVoterWeightError::InvalidRealm => {
+                NftVoterError::InvalidPredecessorVoterWeightRecordRealm
+            }
+            InputVoterWeightError::InvalidGoverningTokenMint => {
+                NftVoterError::InvalidPredecessorVoterWeightRecordGovTokenMint
+            }
+            InputVoterWeightError::InvalidGoverningTokenOwner => {
+                NftVoterError::InvalidPredecessorVoterWeightRecordGovTokenOwner
+            }
+            InputVoterWeightError::VoterWeightExpired => {
+                NftVoterError::PredecessorVoterWeightRecordExpired
+            }
+            InputVoterWeightError::InvalidWeightAction => {
+                NftVoterError::InvalidPredecessorVoterWeightAction
+            }
+            InputVoterWeightError::InvalidWeightActionTarget => {
+                NftVoterError::InvalidPredecessorVoterWeightActionTarget
+            }
+        }
+    }
 }
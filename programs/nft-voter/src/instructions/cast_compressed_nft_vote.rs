@@ -0,0 +1,175 @@
+use crate::error::NftVoterError;
+use crate::{id, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::Accounts;
+use solana_program::account_info::next_account_info;
+use spl_account_compression::program::SplAccountCompression;
+use spl_governance_tools::account::create_and_serialize_account_signed;
+
+/// Casts vote with compressed NFTs (Bubblegum assets)
+/// The compressed NFTs used for voting are tracked using NftVoteRecord accounts keyed by the asset id
+/// and hence the same NFT can't vote twice and the votes can be relinquished using RelinquishNftVote
+///
+/// This instruction updates VoterWeightRecord which is valid for the current Slot and the target Proposal only
+/// and hance the instruction has to be executed inside the same transaction as spl-gov.CastVote
+///
+/// CastCompressedNftVote is accumulative and it can be combined with CastNftVote for the same Proposal
+/// In this scenario only the last instruction should be bundled with spl-gov.CastVote in the same transaction
+#[derive(Accounts)]
+#[instruction(proposal: Pubkey)]
+pub struct CastCompressedNftVote<'info> {
+    /// The NFT voting registrar
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        constraint = voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    /// TokenOwnerRecord of the voter who casts the vote
+    #[account(
+        owner = registrar.governance_program_id
+     )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    voter_token_owner_record: UncheckedAccount<'info>,
+
+    /// Authority of the voter who casts the vote
+    /// It can be either governing_token_owner or its delegate and must sign this instruction
+    pub voter_authority: Signer<'info>,
+
+    /// The account which pays for the transaction
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// spl-account-compression program used to verify the Merkle proofs
+    pub compression_program: Program<'info, SplAccountCompression>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Casts vote with the compressed NFTs
+/// The remaining_accounts must be (merkle_tree, proof_accounts..., nft_vote_record) tuple for each compressed_nft_asset
+/// Note: Compressed NFTs don't have weight overrides and they always have the collection weight
+/// If the Registrar has a predecessor plugin then its VoterWeightRecord must be provided as the first remaining account
+pub fn cast_compressed_nft_vote<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, CastCompressedNftVote<'info>>,
+    proposal: Pubkey,
+    compressed_nft_assets: Vec<CompressedNftAsset>,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+
+    let governing_token_owner = resolve_governing_token_owner(
+        registrar,
+        &ctx.accounts.voter_token_owner_record,
+        &ctx.accounts.voter_authority,
+        voter_weight_record,
+    )?;
+
+    let mut voter_weight = 0u64;
+
+    // Ensure all voting nfts in the batch are unique
+    let mut unique_nft_mints = vec![];
+
+    let rent = Rent::get()?;
+    let compression_program_info = ctx.accounts.compression_program.to_account_info();
+
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+
+    // If the Registrar has a predecessor plugin then its VoterWeightRecord is provided as the first account
+    let predecessor_voter_weight_record = resolve_predecessor_voter_weight_record(
+        registrar,
+        voter_weight_record,
+        VoterWeightAction::CastVote,
+        Some(proposal),
+        &mut remaining_accounts,
+    )?;
+
+    for compressed_nft_asset in compressed_nft_assets.iter() {
+        let merkle_tree_info = next_account_info(&mut remaining_accounts)?;
+
+        let (nft_vote_weight, asset_id) = resolve_compressed_nft_vote_weight_and_asset_id(
+            registrar,
+            &governing_token_owner,
+            merkle_tree_info,
+            compressed_nft_asset,
+            &mut remaining_accounts,
+            &compression_program_info,
+            &mut unique_nft_mints,
+        )?;
+
+        let nft_vote_record_info = next_account_info(&mut remaining_accounts)?;
+
+        voter_weight = voter_weight.checked_add(nft_vote_weight).unwrap();
+
+        // Create NFT vote record to ensure the same NFT hasn't been already used for voting
+        // Note: The correct PDA of the NftVoteRecord is validated in create_and_serialize_account_signed
+        // It ensures the NftVoteRecord is for ('nft-vote-record',proposal,asset_id) seeds
+        require!(
+            nft_vote_record_info.data_is_empty(),
+            NftVoterError::NftAlreadyVoted
+        );
+
+        // Compressed NFTs don't have mints and the asset id is used to identify the NFT instead
+        // The asset id is a PDA derived from the Merkle tree and the leaf nonce and it can't collide with any mint
+        let nft_vote_record = NftVoteRecord {
+            account_discriminator: NftVoteRecord::ACCOUNT_DISCRIMINATOR,
+            proposal,
+            nft_mint: asset_id,
+            governing_token_owner,
+            reserved: [0; 8],
+        };
+
+        // Anchor doesn't natively support dynamic account creation using remaining_accounts
+        // and we have to take it on the manual drive
+        create_and_serialize_account_signed(
+            &ctx.accounts.payer.to_account_info(),
+            nft_vote_record_info,
+            &nft_vote_record,
+            &get_nft_vote_record_seeds(&proposal, &asset_id),
+            &id(),
+            &ctx.accounts.system_program.to_account_info(),
+            &rent,
+            0,
+        )?;
+    }
+
+    // Track the NftVoteRecords to prevent closing the Registrar before they are relinquished
+    registrar.increase_nft_vote_record_count(unique_nft_mints.len() as u64);
+
+    if voter_weight_record.weight_action_target == Some(proposal)
+        && voter_weight_record.weight_action == Some(VoterWeightAction::CastVote)
+    {
+        // If the instruction is called for the same proposal then we keep accumulating the weight
+        // this way cast_compressed_nft_vote can be called multiple times in different transactions to allow voting with any number of NFTs
+        voter_weight_record.voter_weight = voter_weight_record
+            .voter_weight
+            .checked_add(voter_weight)
+            .unwrap();
+    } else {
+        // The predecessor voter weight is only included once when the accumulation for the proposal starts
+        voter_weight_record.voter_weight = registrar.get_voter_weight_with_predecessor(
+            voter_weight,
+            predecessor_voter_weight_record.as_ref(),
+        );
+    }
+
+    // The record is only valid as of the current slot
+    voter_weight_record.voter_weight_expiry = Some(Clock::get()?.slot);
+
+    // The record is only valid for casting vote on the given Proposal
+    voter_weight_record.weight_action = Some(VoterWeightAction::CastVote);
+    voter_weight_record.weight_action_target = Some(proposal);
+
+    if let Some(predecessor_voter_weight_record) = predecessor_voter_weight_record {
+        apply_predecessor_voter_weight_scope(voter_weight_record, &predecessor_voter_weight_record);
+    }
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use crate::error::NftVoterError;
 use crate::{id, state::*};
 use anchor_lang::prelude::*;
 use anchor_lang::Accounts;
-use itertools::Itertools;
+use solana_program::account_info::next_account_info;
 use spl_governance_tools::account::create_and_serialize_account_signed;
 
 /// Casts NFT vote. The NFTs used for voting are tracked using NftVoteRecord accounts
@@ -15,10 +15,13 @@ use spl_governance_tools::account::create_and_serialize_account_signed;
 /// CastNftVote instruction and NftVoteRecord are not directional. They don't record vote choice (ex Yes/No)
 /// VoteChoice is recorded by spl-gov in VoteRecord and this CastNftVote only tracks voting NFTs
 ///
+/// If the Registrar has a predecessor plugin then its VoterWeightRecord must be provided as the first remaining account
+/// and the predecessor weight is combined with the NFT weight when the accumulation for the Proposal starts
 #[derive(Accounts)]
 #[instruction(proposal: Pubkey)]
 pub struct CastNftVote<'info> {
     /// The NFT voting registrar
+    #[account(mut)]
     pub registrar: Account<'info, Registrar>,
 
     #[account(
@@ -54,7 +57,7 @@ pub fn cast_nft_vote<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, CastNftVote<'info>>,
     proposal: Pubkey,
 ) -> Result<()> {
-    let registrar = &ctx.accounts.registrar;
+    let registrar = &mut ctx.accounts.registrar;
     let voter_weight_record = &mut ctx.accounts.voter_weight_record;
 
     let governing_token_owner = resolve_governing_token_owner(
@@ -71,17 +74,34 @@ pub fn cast_nft_vote<'a, 'b, 'c, 'info>(
 
     let rent = Rent::get()?;
 
-    for (nft_info, nft_metadata_info, nft_vote_record_info) in
-        ctx.remaining_accounts.iter().tuples()
-    {
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+
+    // If the Registrar has a predecessor plugin then its VoterWeightRecord is provided as the first account
+    let predecessor_voter_weight_record = resolve_predecessor_voter_weight_record(
+        registrar,
+        voter_weight_record,
+        VoterWeightAction::CastVote,
+        Some(proposal),
+        &mut remaining_accounts,
+    )?;
+
+    // The NFTs are provided as (nft, nft_metadata, nft_vote_record) tuples
+    // and for Programmable NFTs TokenRecord must be provided after nft_metadata
+    // optionally followed by NftWeightOverride for NFTs with weight overrides
+    while let Some(nft_info) = remaining_accounts.next() {
+        let nft_metadata_info = next_account_info(&mut remaining_accounts)?;
+
         let (nft_vote_weight, nft_mint) = resolve_nft_vote_weight_and_mint(
             registrar,
             &governing_token_owner,
             nft_info,
             nft_metadata_info,
+            &mut remaining_accounts,
             &mut unique_nft_mints,
         )?;
 
+        let nft_vote_record_info = next_account_info(&mut remaining_accounts)?;
+
         voter_weight = voter_weight.checked_add(nft_vote_weight as u64).unwrap();
 
         // Create NFT vote record to ensure the same NFT hasn't been already used for voting
@@ -120,6 +140,9 @@ pub fn cast_nft_vote<'a, 'b, 'c, 'info>(
         )?;
     }
 
+    // Track the NftVoteRecords to prevent closing the Registrar before they are relinquished
+    registrar.increase_nft_vote_record_count(unique_nft_mints.len() as u64);
+
     if voter_weight_record.weight_action_target == Some(proposal)
         && voter_weight_record.weight_action == Some(VoterWeightAction::CastVote)
     {
@@ -130,7 +153,11 @@ pub fn cast_nft_vote<'a, 'b, 'c, 'info>(
             .checked_add(voter_weight)
             .unwrap();
     } else {
-        voter_weight_record.voter_weight = voter_weight;
+        // The predecessor voter weight is only included once when the accumulation for the proposal starts
+        voter_weight_record.voter_weight = registrar.get_voter_weight_with_predecessor(
+            voter_weight,
+            predecessor_voter_weight_record.as_ref(),
+        );
     }
 
     // The record is only valid as of the current slot
@@ -140,5 +167,9 @@ pub fn cast_nft_vote<'a, 'b, 'c, 'info>(
     voter_weight_record.weight_action = Some(VoterWeightAction::CastVote);
     voter_weight_record.weight_action_target = Some(proposal);
 
+    if let Some(predecessor_voter_weight_record) = predecessor_voter_weight_record {
+        apply_predecessor_voter_weight_scope(voter_weight_record, &predecessor_voter_weight_record);
+    }
+
     Ok(())
 }
@@ -0,0 +1,36 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use spl_governance_tools::account::dispose_account;
+
+/// Closes an expired NftActionTicket and refunds the lamports to the payer of the ticket
+/// The instruction is permissionless and can be used for both consumed and never consumed tickets
+#[derive(Accounts)]
+pub struct CloseNftActionTicket<'info> {
+    /// CHECK: Owned by the NFT voter program and deserialized in the instruction
+    #[account(mut)]
+    pub nft_action_ticket: UncheckedAccount<'info>,
+
+    /// CHECK: The payer of the NftActionTicket who receives lamports from the closed ticket
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+pub fn close_nft_action_ticket(ctx: Context<CloseNftActionTicket>) -> Result<()> {
+    let nft_action_ticket_info = &ctx.accounts.nft_action_ticket;
+    let nft_action_ticket = get_nft_action_ticket_data(nft_action_ticket_info)?;
+
+    require!(
+        nft_action_ticket.payer == ctx.accounts.payer.key(),
+        NftVoterError::InvalidPayerForNftActionTicket
+    );
+
+    require!(
+        nft_action_ticket.is_expired(Clock::get()?.slot),
+        NftVoterError::NftActionTicketMustBeExpired
+    );
+
+    dispose_account(nft_action_ticket_info, &ctx.accounts.payer)?;
+
+    Ok(())
+}
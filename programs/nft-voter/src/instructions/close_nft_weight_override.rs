@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+use crate::error::NftVoterError;
+use crate::state::{max_voter_weight_record::MaxVoterWeightRecord, NftWeightOverride, Registrar};
+
+/// Removes weight override of a single NFT and recovers the rent from the account
+/// The NFT has governance power = CollectionConfig.weight again once the override is closed
+///
+/// The instruction updates MaxVoterWeightRecord which is used by spl-gov to determine max voting power
+/// used to calculate voting quorum
+#[derive(Accounts)]
+pub struct CloseNftWeightOverride<'info> {
+    /// Registrar the override belongs to
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// The override to close
+    #[account(
+        mut,
+        constraint = nft_weight_override.registrar == registrar.key()
+        @ NftVoterError::InvalidNftWeightOverride,
+        close = beneficiary
+    )]
+    pub nft_weight_override: Account<'info, NftWeightOverride>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+
+    /// CHECK: The beneficiary who receives lamports from the closed NftWeightOverride can be any account
+    #[account(mut)]
+    pub beneficiary: UncheckedAccount<'info>,
+}
+
+pub fn close_nft_weight_override(ctx: Context<CloseNftWeightOverride>) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        NftVoterError::InvalidRealmAuthority
+    );
+
+    let nft_weight_override = &ctx.accounts.nft_weight_override;
+
+    // Collections with overrides can't be removed and hence the collection must still be configured
+    let collection_config = registrar
+        .collection_configs
+        .iter_mut()
+        .find(|cc| cc.collection == nft_weight_override.collection)
+        .ok_or(NftVoterError::CollectionNotFound)?;
+
+    collection_config.weight_bonus_total = collection_config
+        .weight_bonus_total
+        .checked_sub(nft_weight_override.weight_bonus)
+        .unwrap();
+
+    // Update MaxVoterWeightRecord based on max voting power of the collections
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    registrar.update_max_voter_weight_record(max_voter_weight_record, None)
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+use crate::error::NftVoterError;
+use crate::state::Registrar;
+
+/// Closes Registrar and recovers the rent from the account
+/// All collections must be removed from the Registrar before it can be closed to ensure the plugin is no longer used for voting
+///
+/// NftVoteRecords are relinquished using the Registrar and all of them must be relinquished before the Registrar can be closed
+///
+/// Note: NftVoteRecords created before Registrar.nft_vote_record_count was introduced are not counted
+/// and they must be relinquished before the Registrar is closed or their rent can't be recovered any longer
+#[derive(Accounts)]
+pub struct CloseRegistrar<'info> {
+    /// The NFT voting Registrar to close
+    #[account(mut, close = beneficiary)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// CHECK: The beneficiary who receives lamports from the closed Registrar can be any account
+    #[account(mut)]
+    pub beneficiary: UncheckedAccount<'info>,
+}
+
+pub fn close_registrar(ctx: Context<CloseRegistrar>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        NftVoterError::InvalidRealmAuthority
+    );
+
+    require!(
+        registrar.collection_configs.is_empty(),
+        NftVoterError::RegistrarHasCollections
+    );
+
+    require!(
+        registrar.nft_vote_record_count == 0,
+        NftVoterError::RegistrarHasNftVoteRecords
+    );
+
+    Ok(())
+}
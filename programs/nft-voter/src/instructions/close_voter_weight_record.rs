@@ -0,0 +1,85 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use spl_governance::state::token_owner_record;
+
+/// Closes VoterWeightRecord and recovers the rent from the account
+/// The account can only be closed by the governing_token_owner when the voter doesn't have any unrelinquished votes in spl-gov
+///
+/// Note: NftVoteRecords are relinquished using the voter VoterWeightRecord and if the voter still has NftVoteRecords
+/// which were not relinquished then the VoterWeightRecord must be created again to relinquish them
+/// The outstanding NftVoteRecords are counted on the Registrar which can't be closed until they are relinquished
+#[derive(Accounts)]
+pub struct CloseVoterWeightRecord<'info> {
+    /// The NFT voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        close = beneficiary,
+        constraint = voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidVoterWeightRecordMint,
+
+        constraint = voter_weight_record.governing_token_owner == governing_token_owner.key()
+        @ NftVoterError::InvalidTokenOwnerForVoterWeightRecord,
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    /// TokenOwnerRecord of the voter
+    /// CHECK: The account is validated in the instruction and it doesn't have to exist
+    /// If the member left the DAO and the TokenOwnerRecord was closed then the voter doesn't have any votes
+    pub voter_token_owner_record: UncheckedAccount<'info>,
+
+    /// The owner of the VoterWeightRecord must sign the instruction
+    pub governing_token_owner: Signer<'info>,
+
+    /// CHECK: The beneficiary who receives lamports from the closed VoterWeightRecord can be any account
+    #[account(mut)]
+    pub beneficiary: UncheckedAccount<'info>,
+}
+
+pub fn close_voter_weight_record(ctx: Context<CloseVoterWeightRecord>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let voter_weight_record = &ctx.accounts.voter_weight_record;
+
+    // Prevent closing VoterWeightRecord within its expiration period when it can still be used by spl-gov
+    // VoterWeightRecord without expiry never expires and it can't be closed
+    match voter_weight_record.voter_weight_expiry {
+        Some(voter_weight_expiry) if voter_weight_expiry < Clock::get()?.slot => {}
+        _ => return err!(NftVoterError::VoterWeightRecordMustBeExpired),
+    }
+
+    let voter_token_owner_record_info = &ctx.accounts.voter_token_owner_record;
+
+    // Ensure the given TokenOwnerRecord address matches the expected PDA
+    let voter_token_owner_record_key = token_owner_record::get_token_owner_record_address(
+        &registrar.governance_program_id,
+        &registrar.realm,
+        &registrar.governing_token_mint,
+        &voter_weight_record.governing_token_owner,
+    );
+
+    require!(
+        voter_token_owner_record_key == voter_token_owner_record_info.key(),
+        NftVoterError::InvalidTokenOwnerForVoterWeightRecord
+    );
+
+    // NFT votes depend on the VoterWeightRecord until they are relinquished
+    // and hence the voter can't have any unrelinquished votes when the account is closed
+    if !voter_token_owner_record_info.data_is_empty() {
+        let voter_token_owner_record = token_owner_record::get_token_owner_record_data(
+            &registrar.governance_program_id,
+            voter_token_owner_record_info,
+        )?;
+
+        require!(
+            voter_token_owner_record.unrelinquished_votes_count == 0,
+            NftVoterError::VoterHasUnrelinquishedVotes
+        );
+    }
+
+    Ok(())
+}
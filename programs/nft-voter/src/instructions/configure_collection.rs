@@ -6,13 +6,14 @@ use anchor_lang::{
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
-use spl_governance::state::realm;
+use gpl_plugin_core::realm::is_realm_authority;
 
 use crate::error::NftVoterError;
-use crate::state::{max_voter_weight_record::MaxVoterWeightRecord, CollectionConfig, Registrar};
+use crate::state::{max_voter_weight_record::MaxVoterWeightRecord, Registrar};
 
 /// Configures NFT voting collection which defines what NFTs can be used for governances
 /// and what weight they have
+/// For Metaplex sized collections ConfigureSizedCollection can be used to derive the size on chain
 /// The instruction updates MaxVoterWeightRecord which is used by spl-gov to determine max voting power
 /// used to calculate voting quorum    
 #[derive(Accounts)]
@@ -54,14 +55,13 @@ pub fn configure_collection(
 
     let registrar = &mut ctx.accounts.registrar;
 
-    let realm = realm::get_realm_data_for_governing_token_mint(
-        &registrar.governance_program_id,
-        &ctx.accounts.realm,
-        &registrar.governing_token_mint,
-    )?;
-
     require!(
-        realm.authority.unwrap() == ctx.accounts.realm_authority.key(),
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
         NftVoterError::InvalidRealmAuthority
     );
 
@@ -73,42 +73,13 @@ pub fn configure_collection(
     //     return err!(NftVoterError::CannotConfigureCollectionWithVotingProposals);
     // }
 
-    let collection = &ctx.accounts.collection;
-
-    let collection_config = CollectionConfig {
-        collection: collection.key(),
-        weight,
-        reserved: [0; 8],
-        size,
-    };
-
-    let collection_idx = registrar
-        .collection_configs
-        .iter()
-        .position(|cc| cc.collection == collection.key());
+    registrar.upsert_collection_config(ctx.accounts.collection.key(), weight, size, false);
 
-    if let Some(collection_idx) = collection_idx {
-        registrar.collection_configs[collection_idx] = collection_config;
-    } else {
-        // Note: In the current runtime version push() would throw an error if we exceed
-        // max_collections specified when the Registrar was created
-        registrar.collection_configs.push(collection_config);
-    }
-
-    // TODO: if weight == 0 then remove the collection from config
-    // Currently if weight is set to 0 then the collection won't be removed but it won't have any governance power
+    // Note: If weight is set to 0 then the collection won't be removed but it won't have any governance power
+    // RemoveCollection should be used to remove the collection and free its slot in the Registrar
 
     // Update MaxVoterWeightRecord based on max voting power of the collections
     let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
 
-    max_voter_weight_record.max_voter_weight = registrar
-        .collection_configs
-        .iter()
-        .try_fold(0u64, |sum, cc| sum.checked_add(cc.get_max_weight()))
-        .unwrap();
-
-    // The weight never expires and only changes when collections are configured
-    max_voter_weight_record.max_voter_weight_expiry = None;
-
-    Ok(())
+    registrar.update_max_voter_weight_record(max_voter_weight_record, None)
 }
@@ -0,0 +1,153 @@
+use anchor_lang::{
+    account,
+    prelude::{Context, Signer},
+    Accounts,
+};
+
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+use crate::error::NftVoterError;
+use crate::state::{max_voter_weight_record::MaxVoterWeightRecord, NftWeightOverride, Registrar};
+use crate::tools::token_metadata::get_token_metadata_for_mint;
+
+/// Configures weight override for a single NFT of the given collection
+/// The NFT has governance power = CollectionConfig.weight + weight_bonus
+/// and the override is removed using close_nft_weight_override
+///
+/// Note: Overrides can only be configured for NFTs with Metadata accounts and not for compressed NFTs
+///
+/// The instruction updates MaxVoterWeightRecord which is used by spl-gov to determine max voting power
+/// used to calculate voting quorum
+#[derive(Accounts)]
+pub struct ConfigureNftWeightOverride<'info> {
+    /// Registrar for which we configure the override
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// Collection of the NFT which must be configured on the Registrar
+    /// CHECK: The collection is only used as the key of the configured collection
+    pub collection: UncheckedAccount<'info>,
+
+    /// The mint of the NFT
+    /// CHECK: The NFT is only used as the key of the override and it's validated by nft_metadata
+    pub nft_mint: UncheckedAccount<'info>,
+
+    /// Metadata of the NFT which must have the given collection verified
+    /// CHECK: Owned by Token Metadata program and validated in the instruction
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        seeds = [ b"nft-weight-override".as_ref(),
+                registrar.key().as_ref(),
+                nft_mint.key().as_ref()],
+        bump,
+        payer = payer,
+        space = NftWeightOverride::get_space()
+    )]
+    pub nft_weight_override: Account<'info, NftWeightOverride>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_nft_weight_override(
+    ctx: Context<ConfigureNftWeightOverride>,
+    weight_bonus: u64,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        NftVoterError::InvalidRealmAuthority
+    );
+
+    // Overrides without bonus would only hold rent and they are removed using close_nft_weight_override
+    require!(weight_bonus > 0, NftVoterError::InvalidNftWeightBonus);
+
+    let collection = ctx.accounts.collection.key();
+
+    // The NFT must belong to the collection otherwise its bonus would be accounted for in max voter weight
+    // of the collection but never applied to any vote
+    let nft_metadata =
+        get_token_metadata_for_mint(&ctx.accounts.nft_metadata, &ctx.accounts.nft_mint.key())?;
+
+    let nft_collection = nft_metadata
+        .collection
+        .ok_or(NftVoterError::MissingMetadataCollection)?;
+
+    require!(
+        nft_collection.verified,
+        NftVoterError::CollectionMustBeVerified
+    );
+    require!(
+        nft_collection.key == collection,
+        NftVoterError::InvalidNftCollection
+    );
+
+    let nft_weight_override = &mut ctx.accounts.nft_weight_override;
+
+    // Remove the previous bonus from the collection it was configured for
+    // Note: If the override is new then its weight_bonus is 0 and there is nothing to remove
+    if nft_weight_override.weight_bonus > 0 {
+        let previous_collection_config = registrar
+            .collection_configs
+            .iter_mut()
+            .find(|cc| cc.collection == nft_weight_override.collection)
+            .ok_or(NftVoterError::CollectionNotFound)?;
+
+        previous_collection_config.weight_bonus_total = previous_collection_config
+            .weight_bonus_total
+            .checked_sub(nft_weight_override.weight_bonus)
+            .unwrap();
+    }
+
+    let collection_config = registrar
+        .collection_configs
+        .iter_mut()
+        .find(|cc| cc.collection == collection)
+        .ok_or(NftVoterError::CollectionNotFound)?;
+
+    collection_config.weight_bonus_total = collection_config
+        .weight_bonus_total
+        .checked_add(weight_bonus)
+        .unwrap();
+
+    nft_weight_override.registrar = registrar.key();
+    nft_weight_override.collection = collection;
+    nft_weight_override.nft_mint = ctx.accounts.nft_mint.key();
+    nft_weight_override.weight_bonus = weight_bonus;
+
+    // Update MaxVoterWeightRecord based on max voting power of the collections
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    registrar.update_max_voter_weight_record(max_voter_weight_record, None)
+}
@@ -0,0 +1,80 @@
+use crate::error::NftVoterError;
+use crate::state::max_voter_weight_record::MaxVoterWeightRecord;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+/// Configures the previous voter weight plugin the NFT plugin is chained with
+/// When the predecessor is configured the voter weight instructions take the predecessor VoterWeightRecord
+/// as the first remaining account and combine its weight with the NFT weight using predecessor_weight_mode
+///
+/// With PredecessorWeightMode::Add the predecessor max voter weight is added to MaxVoterWeightRecord
+/// and the record is set as expired until it's updated using RefreshMaxVoterWeight with the predecessor MaxVoterWeightRecord
+#[derive(Accounts)]
+#[instruction(use_previous_voter_weight_plugin:bool)]
+pub struct ConfigurePreviousVoterWeightPlugin<'info> {
+    /// The NFT voting Registrar
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+/// Configures the previous voter weight plugin and how its weight is combined with the NFT weight
+/// If use_previous_voter_weight_plugin is true then the predecessor program id must be provided as the first remaining account
+pub fn configure_previous_voter_weight_plugin(
+    ctx: Context<ConfigurePreviousVoterWeightPlugin>,
+    use_previous_voter_weight_plugin: bool,
+    predecessor_weight_mode: PredecessorWeightMode,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        NftVoterError::InvalidRealmAuthority
+    );
+
+    // If the plugin has a previous plugin, it "inherits" the vote weight from a voter_weight_record owned
+    // by the previous plugin. This chain is registered here.
+    let remaining_accounts = ctx.remaining_accounts;
+
+    registrar.previous_voter_weight_plugin_program_id = use_previous_voter_weight_plugin
+        .then(|| {
+            remaining_accounts
+                .get(0)
+                .ok_or(NftVoterError::MissingPreviousVoterWeightPlugin)
+                .map(|account| account.key)
+        })
+        .transpose()?
+        .copied();
+
+    registrar.predecessor_weight_mode = predecessor_weight_mode;
+
+    // The max voter weight changes when the predecessor max voter weight is added or removed
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    registrar.update_max_voter_weight_record(max_voter_weight_record, None)
+}
@@ -0,0 +1,87 @@
+use anchor_lang::{
+    account,
+    prelude::{Context, Signer},
+    Accounts,
+};
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use gpl_plugin_core::realm::is_realm_authority;
+
+use crate::error::NftVoterError;
+use crate::state::{max_voter_weight_record::MaxVoterWeightRecord, Registrar};
+use crate::tools::token_metadata::{get_sized_collection_size, get_token_metadata_for_mint};
+
+/// Configures Metaplex sized NFT voting collection which defines what NFTs can be used for governances
+/// and what weight they have
+/// The size of the collection is taken from collection_details of the collection metadata
+/// and can be refreshed using RefreshMaxVoterWeight when the collection grows or shrinks
+/// The instruction updates MaxVoterWeightRecord which is used by spl-gov to determine max voting power
+/// used to calculate voting quorum
+#[derive(Accounts)]
+pub struct ConfigureSizedCollection<'info> {
+    /// Registrar for which we configure this Collection
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    // Collection which is going to be used for voting
+    pub collection: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+
+    /// Metadata account of the collection
+    /// CHECK: Verified against the collection mint when deserialized
+    pub collection_metadata: UncheckedAccount<'info>,
+}
+
+pub fn configure_sized_collection(
+    ctx: Context<ConfigureSizedCollection>,
+    weight: u64,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        NftVoterError::InvalidRealmAuthority
+    );
+
+    let collection = &ctx.accounts.collection;
+
+    let collection_metadata =
+        get_token_metadata_for_mint(&ctx.accounts.collection_metadata, &collection.key())?;
+
+    let size = get_sized_collection_size(&collection_metadata)?
+        .ok_or(NftVoterError::InvalidCollectionSize)?;
+
+    require!(size > 0, NftVoterError::InvalidCollectionSize);
+
+    registrar.upsert_collection_config(collection.key(), weight, size, true);
+
+    // Update MaxVoterWeightRecord based on max voting power of the collections
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    registrar.update_max_voter_weight_record(max_voter_weight_record, None)
+}
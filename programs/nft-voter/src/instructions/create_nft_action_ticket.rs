@@ -0,0 +1,140 @@
+use crate::error::NftVoterError;
+use crate::{id, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::Accounts;
+use solana_program::account_info::next_account_info;
+use spl_governance_tools::account::create_and_serialize_account_signed;
+
+/// Issues NftActionTickets for the given NFTs which are used to evaluate governance power
+/// for non voting use cases: CreateProposal, CreateGovernance etc...
+///
+/// CreateNftActionTicket can be invoked using several transactions if voter owns more than 5 NFTs
+/// and once all the tickets are issued UpdateVoterWeightRecordWithTickets should be bundled with the corresponding
+/// spl-gov instruction in the same transaction to consume them
+///
+/// Each ticket is only valid for NftActionTicket::EXPIRY_SLOTS and for the given voter_weight_action
+#[derive(Accounts)]
+#[instruction(voter_weight_action: VoterWeightAction)]
+pub struct CreateNftActionTicket<'info> {
+    /// The NFT voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        constraint = voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    /// The account which pays for the tickets
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Issues NftActionTicket for each (nft, nft_metadata, nft_action_ticket) tuple provided in remaining_accounts
+/// For Programmable NFTs the NFT TokenRecord must be provided after nft_metadata
+pub fn create_nft_action_ticket<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, CreateNftActionTicket<'info>>,
+    voter_weight_action: VoterWeightAction,
+) -> Result<()> {
+    // voter_weight for CastVote action can't be evaluated using tickets and CastNftVote must be used instead
+    require!(
+        voter_weight_action != VoterWeightAction::CastVote,
+        NftVoterError::CastVoteIsNotAllowed
+    );
+
+    let registrar = &ctx.accounts.registrar;
+    let governing_token_owner = &ctx.accounts.voter_weight_record.governing_token_owner;
+
+    // Ensure all nfts in the batch are unique
+    let mut unique_nft_mints = vec![];
+
+    let rent = Rent::get()?;
+    let current_slot = Clock::get()?.slot;
+
+    let voter_weight_action_seed = [voter_weight_action as u8];
+
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+
+    // The NFTs are provided as (nft, nft_metadata, nft_action_ticket) tuples
+    // and for Programmable NFTs TokenRecord must be provided after nft_metadata
+    // optionally followed by NftWeightOverride for NFTs with weight overrides
+    while let Some(nft_info) = remaining_accounts.next() {
+        let nft_metadata_info = next_account_info(&mut remaining_accounts)?;
+
+        let (nft_vote_weight, nft_mint) = resolve_nft_vote_weight_and_mint(
+            registrar,
+            governing_token_owner,
+            nft_info,
+            nft_metadata_info,
+            &mut remaining_accounts,
+            &mut unique_nft_mints,
+        )?;
+
+        let nft_action_ticket_info = next_account_info(&mut remaining_accounts)?;
+
+        let mut nft_action_ticket = NftActionTicket {
+            account_discriminator: NftActionTicket::ACCOUNT_DISCRIMINATOR,
+            registrar: registrar.key(),
+            governing_token_owner: *governing_token_owner,
+            nft_mint,
+            weight_action: voter_weight_action,
+            weight: nft_vote_weight,
+            expiry: current_slot
+                .checked_add(NftActionTicket::EXPIRY_SLOTS)
+                .unwrap(),
+            payer: ctx.accounts.payer.key(),
+            is_consumed: false,
+            reserved: [0; 8],
+        };
+
+        if nft_action_ticket_info.data_is_empty() {
+            // Anchor doesn't natively support dynamic account creation using remaining_accounts
+            // and we have to take it on the manual drive
+            // Note: The correct PDA of the NftActionTicket is validated in create_and_serialize_account_signed
+            create_and_serialize_account_signed(
+                &ctx.accounts.payer.to_account_info(),
+                nft_action_ticket_info,
+                &nft_action_ticket,
+                &get_nft_action_ticket_seeds(
+                    &registrar.key(),
+                    &voter_weight_action_seed,
+                    &nft_mint,
+                ),
+                &id(),
+                &ctx.accounts.system_program.to_account_info(),
+                &rent,
+                0,
+            )?;
+        } else {
+            // An expired ticket can be reissued in place, also for a new owner of the NFT
+            // The existing ticket must be for the same registrar, action and NFT which asserts it's the expected PDA
+            let existing_ticket = get_nft_action_ticket_data(nft_action_ticket_info)?;
+
+            require!(
+                existing_ticket.registrar == nft_action_ticket.registrar
+                    && existing_ticket.weight_action == nft_action_ticket.weight_action
+                    && existing_ticket.nft_mint == nft_action_ticket.nft_mint,
+                NftVoterError::NftActionTicketMismatch
+            );
+
+            require!(
+                existing_ticket.is_expired(current_slot),
+                NftVoterError::NftActionTicketAlreadyIssued
+            );
+
+            // The rent was paid by the payer of the existing ticket and it's refunded to it when the ticket is closed
+            nft_action_ticket.payer = existing_ticket.payer;
+
+            nft_action_ticket
+                .serialize(&mut *nft_action_ticket_info.try_borrow_mut_data()?)
+                .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        }
+    }
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use crate::error::NftVoterError;
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
-use spl_governance::state::realm;
+use gpl_plugin_core::realm::is_realm_authority;
 
 /// Creates Registrar storing NFT governance configuration for spl-gov Realm
 /// This instruction should only be executed once per realm/governing_token_mint to create the account
@@ -57,7 +57,7 @@ pub struct CreateRegistrar<'info> {
 /// used for governance
 ///
 /// max_collections is used allocate account size for the maximum number of governing NFT collections
-/// Note: Once Solana runtime supports account resizing the max value won't be required
+/// Note: The Registrar can be resized later using ResizeRegistrar if more collections are needed
 pub fn create_registrar(ctx: Context<CreateRegistrar>, _max_collections: u8) -> Result<()> {
     let registrar = &mut ctx.accounts.registrar;
     registrar.governance_program_id = ctx.accounts.governance_program_id.key();
@@ -66,14 +66,13 @@ pub fn create_registrar(ctx: Context<CreateRegistrar>, _max_collections: u8) ->
 
     // Verify that realm_authority is the expected authority of the Realm
     // and that the mint matches one of the realm mints too
-    let realm = realm::get_realm_data_for_governing_token_mint(
-        &registrar.governance_program_id,
-        &ctx.accounts.realm,
-        &registrar.governing_token_mint,
-    )?;
-
     require!(
-        realm.authority.unwrap() == ctx.accounts.realm_authority.key(),
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
         NftVoterError::InvalidRealmAuthority
     );
 
@@ -1,15 +1,45 @@
 pub use configure_collection::*;
 mod configure_collection;
 
+pub use configure_sized_collection::*;
+mod configure_sized_collection;
+
+pub use remove_collection::*;
+mod remove_collection;
+
+pub use refresh_max_voter_weight::*;
+mod refresh_max_voter_weight;
+
+pub use configure_nft_weight_override::*;
+mod configure_nft_weight_override;
+
+pub use close_nft_weight_override::*;
+mod close_nft_weight_override;
+
 pub use create_registrar::*;
 mod create_registrar;
 
+pub use resize_registrar::*;
+mod resize_registrar;
+
+pub use close_registrar::*;
+mod close_registrar;
+
+pub use configure_previous_voter_weight_plugin::*;
+mod configure_previous_voter_weight_plugin;
+
 pub use create_voter_weight_record::*;
 mod create_voter_weight_record;
 
+pub use close_voter_weight_record::*;
+mod close_voter_weight_record;
+
 pub use create_max_voter_weight_record::*;
 mod create_max_voter_weight_record;
 
+pub use close_max_voter_weight_record::*;
+mod close_max_voter_weight_record;
+
 pub use update_voter_weight_record::*;
 mod update_voter_weight_record;
 
@@ -18,3 +48,15 @@ mod relinquish_nft_vote;
 
 pub use cast_nft_vote::*;
 mod cast_nft_vote;
+
+pub use cast_compressed_nft_vote::*;
+mod cast_compressed_nft_vote;
+
+pub use create_nft_action_ticket::*;
+mod create_nft_action_ticket;
+
+pub use update_voter_weight_record_with_tickets::*;
+mod update_voter_weight_record_with_tickets;
+
+pub use close_nft_action_ticket::*;
+mod close_nft_action_ticket;
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::error::NftVoterError;
+use crate::state::{
+    max_voter_weight_record::MaxVoterWeightRecord, resolve_predecessor_max_voter_weight_record,
+    Registrar,
+};
+use crate::tools::token_metadata::{get_sized_collection_size, get_token_metadata_for_mint};
+
+/// Refreshes the sizes of all the collections configured with sizes derived from Metaplex sized collections
+/// and updates MaxVoterWeightRecord
+/// The size of a sized collection changes when NFTs are added to the collection or burnt
+/// and the instruction is permissionless to allow anybody to keep max voter weight accurate
+///
+/// Manually configured sizes are not changed and they have to be updated using ConfigureCollection
+///
+/// If the predecessor voter weight is added using PredecessorWeightMode::Add then the predecessor max voter weight
+/// is added to the max voter weight of the collections and the record expires together with the predecessor MaxVoterWeightRecord
+#[derive(Accounts)]
+pub struct RefreshMaxVoterWeight<'info> {
+    /// Registrar for which we refresh the collection sizes
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+/// If the predecessor max voter weight is added then the predecessor MaxVoterWeightRecord must be the first remaining account
+/// The other remaining_accounts must be the collection metadata accounts for all the collections with derived sizes
+/// in the same order as Registrar.collection_configs
+pub fn refresh_max_voter_weight(ctx: Context<RefreshMaxVoterWeight>) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+
+    let predecessor_max_voter_weight_record =
+        resolve_predecessor_max_voter_weight_record(registrar, &mut remaining_accounts)?;
+
+    let derived_size_collection_idxs = (0..registrar.collection_configs.len())
+        .filter(|idx| registrar.is_collection_size_derived(*idx))
+        .collect::<Vec<_>>();
+
+    require!(
+        remaining_accounts.len() == derived_size_collection_idxs.len(),
+        NftVoterError::InvalidCollectionMetadataAccounts
+    );
+
+    for (collection_idx, collection_metadata_info) in derived_size_collection_idxs
+        .into_iter()
+        .zip(remaining_accounts)
+    {
+        let collection_config = &mut registrar.collection_configs[collection_idx];
+
+        let collection_metadata =
+            get_token_metadata_for_mint(collection_metadata_info, &collection_config.collection)?;
+
+        // Metaplex collections can't be unsized once they are sized
+        collection_config.size = get_sized_collection_size(&collection_metadata)?
+            .ok_or(NftVoterError::InvalidCollectionSize)?;
+    }
+
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    registrar.update_max_voter_weight_record(
+        max_voter_weight_record,
+        predecessor_max_voter_weight_record.as_ref(),
+    )
+}
@@ -19,6 +19,7 @@ use spl_governance_tools::account::dispose_account;
 #[derive(Accounts)]
 pub struct RelinquishNftVote<'info> {
     /// The NFT voting Registrar
+    #[account(mut)]
     pub registrar: Account<'info, Registrar>,
 
     #[account(
@@ -63,7 +64,7 @@ pub struct RelinquishNftVote<'info> {
 }
 
 pub fn relinquish_nft_vote(ctx: Context<RelinquishNftVote>) -> Result<()> {
-    let registrar = &ctx.accounts.registrar;
+    let registrar = &mut ctx.accounts.registrar;
     let voter_weight_record = &mut ctx.accounts.voter_weight_record;
 
     let governing_token_owner = resolve_governing_token_owner(
@@ -139,6 +140,8 @@ pub fn relinquish_nft_vote(ctx: Context<RelinquishNftVote>) -> Result<()> {
         dispose_account(nft_vote_record_info, &ctx.accounts.beneficiary)?;
     }
 
+    registrar.decrease_nft_vote_record_count(ctx.remaining_accounts.len() as u64);
+
     // Reset VoterWeightRecord and set expiry to expired to prevent it from being used
     voter_weight_record.voter_weight = 0;
     voter_weight_record.voter_weight_expiry = Some(0);
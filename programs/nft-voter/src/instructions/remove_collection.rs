@@ -0,0 +1,82 @@
+use anchor_lang::{
+    account,
+    prelude::{Context, Signer},
+    Accounts,
+};
+
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+use crate::error::NftVoterError;
+use crate::state::{max_voter_weight_record::MaxVoterWeightRecord, Registrar};
+
+/// Removes NFT voting collection from the Registrar and frees its slot for other collections
+/// The instruction updates MaxVoterWeightRecord which is used by spl-gov to determine max voting power
+/// used to calculate voting quorum
+#[derive(Accounts)]
+pub struct RemoveCollection<'info> {
+    /// Registrar from which we remove the Collection
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// Collection which is going to be removed
+    /// CHECK: The collection is only used as the key of the configured collection
+    /// and it's not deserialized to allow removing collections whose mint is no longer valid
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+pub fn remove_collection(ctx: Context<RemoveCollection>) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        NftVoterError::InvalidRealmAuthority
+    );
+
+    let collection = ctx.accounts.collection.key();
+
+    let collection_idx = registrar
+        .collection_configs
+        .iter()
+        .position(|cc| cc.collection == collection)
+        .ok_or(NftVoterError::CollectionNotFound)?;
+
+    // NftWeightOverrides of the collection must be closed first otherwise they would be applied
+    // without being accounted for in max voter weight if the collection is configured again
+    require!(
+        registrar.collection_configs[collection_idx].weight_bonus_total == 0,
+        NftVoterError::CollectionHasNftWeightOverrides
+    );
+
+    registrar.remove_collection_config(collection_idx);
+
+    // Update MaxVoterWeightRecord based on max voting power of the remaining collections
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    registrar.update_max_voter_weight_record(max_voter_weight_record, None)
+}
@@ -0,0 +1,64 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+use solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+
+/// Resizes Registrar to allow a different number of NFT collections to be configured
+/// The rent difference is paid by the payer when the account grows
+///
+/// Solana limits the account growth to MAX_PERMITTED_DATA_INCREASE (10KB) per instruction
+/// and larger increases of max_collections must be done using several ResizeRegistrar instructions
+#[derive(Accounts)]
+#[instruction(max_collections: u8)]
+pub struct ResizeRegistrar<'info> {
+    /// The NFT voting Registrar
+    #[account(
+        mut,
+        constraint = Registrar::get_space(max_collections)
+            <= registrar.to_account_info().data_len() + MAX_PERMITTED_DATA_INCREASE
+            @ NftVoterError::RegistrarSizeIncreaseTooLarge,
+        realloc = Registrar::get_space(max_collections),
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Resizes Registrar to the space required for max_collections
+/// max_collections can't be less than the number of currently configured collections
+pub fn resize_registrar(ctx: Context<ResizeRegistrar>, max_collections: u8) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        NftVoterError::InvalidRealmAuthority
+    );
+
+    require!(
+        registrar.collection_configs.len() <= max_collections as usize,
+        NftVoterError::InvalidMaxCollections
+    );
+
+    Ok(())
+}
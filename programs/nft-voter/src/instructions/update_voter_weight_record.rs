@@ -1,7 +1,7 @@
 use crate::error::NftVoterError;
 use crate::state::*;
 use anchor_lang::prelude::*;
-use itertools::Itertools;
+use solana_program::account_info::next_account_info;
 
 /// Updates VoterWeightRecord to evaluate governance power for non voting use cases: CreateProposal, CreateGovernance etc...
 /// This instruction updates VoterWeightRecord which is valid for the current Slot and the given target action only
@@ -9,8 +9,9 @@ use itertools::Itertools;
 ///
 /// Note: UpdateVoterWeight is not cumulative the same way as CastNftVote and hence voter_weight for non voting scenarios
 /// can only be used with max 5 NFTs due to Solana transaction size limit
-/// It could be supported in future version by introducing bookkeeping accounts to track the NFTs
-/// which were already used to calculate the total weight
+/// If the voter owns more NFTs then CreateNftActionTicket and UpdateVoterWeightRecordWithTickets should be used instead
+///
+/// If the Registrar has a predecessor plugin then its VoterWeightRecord must be provided as the first remaining account
 #[derive(Accounts)]
 #[instruction(voter_weight_action:VoterWeightAction)]
 pub struct UpdateVoterWeightRecord<'info> {
@@ -49,12 +50,28 @@ pub fn update_voter_weight_record(
     // Ensure all nfts are unique
     let mut unique_nft_mints = vec![];
 
-    for (nft_info, nft_metadata_info) in ctx.remaining_accounts.iter().tuples() {
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+
+    // If the Registrar has a predecessor plugin then its VoterWeightRecord is provided as the first account
+    let predecessor_voter_weight_record = resolve_predecessor_voter_weight_record(
+        registrar,
+        &ctx.accounts.voter_weight_record,
+        voter_weight_action,
+        None,
+        &mut remaining_accounts,
+    )?;
+
+    // The NFTs are provided as (nft, nft_metadata) pairs followed by TokenRecord for Programmable NFTs
+    // and optionally NftWeightOverride for NFTs with weight overrides
+    while let Some(nft_info) = remaining_accounts.next() {
+        let nft_metadata_info = next_account_info(&mut remaining_accounts)?;
+
         let (nft_vote_weight, _) = resolve_nft_vote_weight_and_mint(
             registrar,
             governing_token_owner,
             nft_info,
             nft_metadata_info,
+            &mut remaining_accounts,
             &mut unique_nft_mints,
         )?;
 
@@ -63,7 +80,8 @@ pub fn update_voter_weight_record(
 
     let voter_weight_record = &mut ctx.accounts.voter_weight_record;
 
-    voter_weight_record.voter_weight = voter_weight;
+    voter_weight_record.voter_weight = registrar
+        .get_voter_weight_with_predecessor(voter_weight, predecessor_voter_weight_record.as_ref());
 
     // Record is only valid as of the current slot
     voter_weight_record.voter_weight_expiry = Some(Clock::get()?.slot);
@@ -72,5 +90,9 @@ pub fn update_voter_weight_record(
     voter_weight_record.weight_action = Some(voter_weight_action);
     voter_weight_record.weight_action_target = None;
 
+    if let Some(predecessor_voter_weight_record) = predecessor_voter_weight_record {
+        apply_predecessor_voter_weight_scope(voter_weight_record, &predecessor_voter_weight_record);
+    }
+
     Ok(())
 }
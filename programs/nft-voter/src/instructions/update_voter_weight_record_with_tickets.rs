@@ -0,0 +1,122 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Updates VoterWeightRecord to evaluate governance power for non voting use cases: CreateProposal, CreateGovernance etc...
+/// using NftActionTickets issued by CreateNftActionTicket
+///
+/// The weight of all the provided tickets is summed up and the tickets are marked as consumed to prevent them from being used again
+/// Consumed tickets are kept until they expire and can be closed using CloseNftActionTicket afterwards
+/// This instruction updates VoterWeightRecord which is valid for the current Slot and the given target action only
+/// and hence the instruction has to be executed inside the same transaction as the corresponding spl-gov instruction
+///
+/// Note: Unlike UpdateVoterWeightRecord which takes (nft, nft_metadata) pairs a ticket takes a single account
+/// and NFTs are validated when the tickets are issued which allows the voter to use a larger number of NFTs
+///
+/// If the Registrar has a predecessor plugin then its VoterWeightRecord must be provided as the first remaining account
+#[derive(Accounts)]
+#[instruction(voter_weight_action:VoterWeightAction)]
+pub struct UpdateVoterWeightRecordWithTickets<'info> {
+    /// The NFT voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        constraint = voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    /// TokenOwnerRecord of the voter who uses the tickets
+    #[account(
+        owner = registrar.governance_program_id
+     )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    voter_token_owner_record: UncheckedAccount<'info>,
+
+    /// Authority of the voter who uses the tickets
+    /// It can be either governing_token_owner or its delegate and must sign this instruction
+    pub voter_authority: Signer<'info>,
+}
+
+pub fn update_voter_weight_record_with_tickets(
+    ctx: Context<UpdateVoterWeightRecordWithTickets>,
+    voter_weight_action: VoterWeightAction,
+) -> Result<()> {
+    // voter_weight for CastVote action can't be evaluated using tickets and CastNftVote must be used instead
+    require!(
+        voter_weight_action != VoterWeightAction::CastVote,
+        NftVoterError::CastVoteIsNotAllowed
+    );
+
+    let registrar = &ctx.accounts.registrar;
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+
+    let governing_token_owner = resolve_governing_token_owner(
+        registrar,
+        &ctx.accounts.voter_token_owner_record,
+        &ctx.accounts.voter_authority,
+        voter_weight_record,
+    )?;
+
+    let current_slot = Clock::get()?.slot;
+
+    let mut voter_weight = 0u64;
+
+    // Ensure all nfts are unique
+    let mut unique_nft_mints = vec![];
+
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+
+    // If the Registrar has a predecessor plugin then its VoterWeightRecord is provided as the first account
+    let predecessor_voter_weight_record = resolve_predecessor_voter_weight_record(
+        registrar,
+        voter_weight_record,
+        voter_weight_action,
+        None,
+        &mut remaining_accounts,
+    )?;
+
+    for nft_action_ticket_info in remaining_accounts {
+        let mut nft_action_ticket = get_nft_action_ticket_data_for_registrar_and_token_owner(
+            nft_action_ticket_info,
+            &registrar.key(),
+            &governing_token_owner,
+            voter_weight_action,
+            current_slot,
+        )?;
+
+        if unique_nft_mints.contains(&nft_action_ticket.nft_mint) {
+            return Err(NftVoterError::DuplicatedNftDetected.into());
+        }
+        unique_nft_mints.push(nft_action_ticket.nft_mint);
+
+        voter_weight = voter_weight.checked_add(nft_action_ticket.weight).unwrap();
+
+        // The ticket is kept until it expires to prevent the same NFT from being ticketed again within the window
+        nft_action_ticket.is_consumed = true;
+
+        nft_action_ticket
+            .serialize(&mut *nft_action_ticket_info.try_borrow_mut_data()?)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+    }
+
+    voter_weight_record.voter_weight = registrar
+        .get_voter_weight_with_predecessor(voter_weight, predecessor_voter_weight_record.as_ref());
+
+    // Record is only valid as of the current slot
+    voter_weight_record.voter_weight_expiry = Some(current_slot);
+
+    // Set the action to make it specific and prevent being used for voting
+    voter_weight_record.weight_action = Some(voter_weight_action);
+    voter_weight_record.weight_action_target = None;
+
+    if let Some(predecessor_voter_weight_record) = predecessor_voter_weight_record {
+        apply_predecessor_voter_weight_scope(voter_weight_record, &predecessor_voter_weight_record);
+    }
+
+    Ok(())
+}
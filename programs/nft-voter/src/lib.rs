@@ -23,6 +23,26 @@ pub mod nft_voter {
         log_version();
         instructions::create_registrar(ctx, max_collections)
     }
+    pub fn resize_registrar(ctx: Context<ResizeRegistrar>, max_collections: u8) -> Result<()> {
+        log_version();
+        instructions::resize_registrar(ctx, max_collections)
+    }
+    pub fn close_registrar(ctx: Context<CloseRegistrar>) -> Result<()> {
+        log_version();
+        instructions::close_registrar(ctx)
+    }
+    pub fn configure_previous_voter_weight_plugin(
+        ctx: Context<ConfigurePreviousVoterWeightPlugin>,
+        use_previous_voter_weight_plugin: bool,
+        predecessor_weight_mode: PredecessorWeightMode,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_previous_voter_weight_plugin(
+            ctx,
+            use_previous_voter_weight_plugin,
+            predecessor_weight_mode,
+        )
+    }
     pub fn create_voter_weight_record(
         ctx: Context<CreateVoterWeightRecord>,
         governing_token_owner: Pubkey,
@@ -30,10 +50,18 @@ pub mod nft_voter {
         log_version();
         instructions::create_voter_weight_record(ctx, governing_token_owner)
     }
+    pub fn close_voter_weight_record(ctx: Context<CloseVoterWeightRecord>) -> Result<()> {
+        log_version();
+        instructions::close_voter_weight_record(ctx)
+    }
     pub fn create_max_voter_weight_record(ctx: Context<CreateMaxVoterWeightRecord>) -> Result<()> {
         log_version();
         instructions::create_max_voter_weight_record(ctx)
     }
+    pub fn close_max_voter_weight_record(ctx: Context<CloseMaxVoterWeightRecord>) -> Result<()> {
+        log_version();
+        instructions::close_max_voter_weight_record(ctx)
+    }
     pub fn update_voter_weight_record(
         ctx: Context<UpdateVoterWeightRecord>,
         voter_weight_action: VoterWeightAction,
@@ -53,6 +81,53 @@ pub mod nft_voter {
         log_version();
         instructions::configure_collection(ctx, weight, size)
     }
+    pub fn configure_sized_collection(
+        ctx: Context<ConfigureSizedCollection>,
+        weight: u64,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_sized_collection(ctx, weight)
+    }
+    pub fn remove_collection(ctx: Context<RemoveCollection>) -> Result<()> {
+        log_version();
+        instructions::remove_collection(ctx)
+    }
+    pub fn refresh_max_voter_weight(ctx: Context<RefreshMaxVoterWeight>) -> Result<()> {
+        log_version();
+        instructions::refresh_max_voter_weight(ctx)
+    }
+    pub fn configure_nft_weight_override(
+        ctx: Context<ConfigureNftWeightOverride>,
+        weight_bonus: u64,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_nft_weight_override(ctx, weight_bonus)
+    }
+    pub fn close_nft_weight_override(ctx: Context<CloseNftWeightOverride>) -> Result<()> {
+        log_version();
+        instructions::close_nft_weight_override(ctx)
+    }
+
+    pub fn create_nft_action_ticket<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, CreateNftActionTicket<'info>>,
+        voter_weight_action: VoterWeightAction,
+    ) -> Result<()> {
+        log_version();
+        instructions::create_nft_action_ticket(ctx, voter_weight_action)
+    }
+
+    pub fn update_voter_weight_record_with_tickets(
+        ctx: Context<UpdateVoterWeightRecordWithTickets>,
+        voter_weight_action: VoterWeightAction,
+    ) -> Result<()> {
+        log_version();
+        instructions::update_voter_weight_record_with_tickets(ctx, voter_weight_action)
+    }
+
+    pub fn close_nft_action_ticket(ctx: Context<CloseNftActionTicket>) -> Result<()> {
+        log_version();
+        instructions::close_nft_action_ticket(ctx)
+    }
 
     pub fn cast_nft_vote<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, CastNftVote<'info>>,
@@ -61,6 +136,15 @@ pub mod nft_voter {
         log_version();
         instructions::cast_nft_vote(ctx, proposal)
     }
+
+    pub fn cast_compressed_nft_vote<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, CastCompressedNftVote<'info>>,
+        proposal: Pubkey,
+        compressed_nft_assets: Vec<CompressedNftAsset>,
+    ) -> Result<()> {
+        log_version();
+        instructions::cast_compressed_nft_vote(ctx, proposal, compressed_nft_assets)
+    }
 }
 
 fn log_version() {
@@ -7,8 +7,10 @@ pub struct CollectionConfig {
     pub collection: Pubkey,
 
     /// The size of the NFT collection used to calculate max voter weight
-    /// Note: At the moment the size is not captured on Metaplex accounts
-    /// and it has to be manually updated on the Registrar
+    /// For Metaplex sized collections the size can be taken from the collection metadata collection_details
+    /// and it's kept up to date using RefreshMaxVoterWeight
+    /// For unsized collections the size is not captured on Metaplex accounts and it has to be manually updated on the Registrar
+    /// Note: Whether the size is derived is tracked on Registrar.size_derived_collections
     pub size: u32,
 
     /// Governance power weight of the collection
@@ -17,12 +19,17 @@ pub struct CollectionConfig {
     /// Ex: if the the mint has 2 decimal places then weight of 1 should be stored as 100
     pub weight: u64,
 
-    /// Reserved for future upgrades
-    pub reserved: [u8; 8],
+    /// The total weight bonus of all NftWeightOverrides configured for the collection
+    /// If the total is greater than 0 then NftWeightOverride PDA must be provided for every NFT of the collection
+    pub weight_bonus_total: u64,
 }
 
 impl CollectionConfig {
     pub fn get_max_weight(&self) -> u64 {
-        (self.size as u64).checked_mul(self.weight).unwrap()
+        (self.size as u64)
+            .checked_mul(self.weight)
+            .unwrap()
+            .checked_add(self.weight_bonus_total)
+            .unwrap()
     }
 }
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use mpl_bubblegum::state::metaplex_adapter::MetadataArgs;
+
+/// Compressed NFT (Bubblegum asset) used for voting
+/// The asset is stored as a leaf of spl-account-compression Merkle tree
+/// and its data has to be provided by the caller to recompute the leaf and verify its Merkle proof
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CompressedNftAsset {
+    /// Root of the Merkle tree the proof was generated for
+    /// The root must be still present in the tree changelog buffer
+    pub root: [u8; 32],
+
+    /// Index of the leaf in the Merkle tree
+    pub index: u32,
+
+    /// Nonce of the leaf used to derive the asset id
+    pub nonce: u64,
+
+    /// The number of proof accounts provided for the asset in remaining_accounts
+    /// Note: It can be less than the tree depth if the tree has a canopy
+    pub proof_len: u8,
+
+    /// Metadata of the asset used to compute the leaf data and creator hashes
+    pub metadata: MetadataArgs,
+}
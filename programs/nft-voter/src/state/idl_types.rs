@@ -15,3 +15,32 @@ pub struct NftVoteRecord {
     /// It's a Realm member pubkey corresponding to TokenOwnerRecord.governing_token_owner
     pub governing_token_owner: Pubkey,
 }
+
+/// NftActionTicket exported to IDL without account_discriminator
+/// TODO: Once we can support these accounts in Anchor via remaining_accounts then it should be possible to remove it
+#[account]
+pub struct NftActionTicket {
+    /// The Registrar the ticket was issued for
+    pub registrar: Pubkey,
+
+    /// The owner of the NFT at the time the ticket was issued
+    pub governing_token_owner: Pubkey,
+
+    /// The mint of the NFT the ticket was issued for
+    pub nft_mint: Pubkey,
+
+    /// The governance action the ticket can be used for
+    pub weight_action: crate::state::VoterWeightAction,
+
+    /// Governance power weight of the NFT at the time the ticket was issued
+    pub weight: u64,
+
+    /// The slot when the ticket expires and can't be used any longer
+    pub expiry: u64,
+
+    /// The account which paid for the ticket and receives the lamports when it's closed
+    pub payer: Pubkey,
+
+    /// Indicates whether the ticket was consumed by UpdateVoterWeightRecordWithTickets
+    pub is_consumed: bool,
+}
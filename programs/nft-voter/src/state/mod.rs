@@ -7,6 +7,18 @@ pub mod collection_config;
 pub use nft_vote_record::*;
 pub mod nft_vote_record;
 
+pub use nft_action_ticket::*;
+pub mod nft_action_ticket;
+
+pub use nft_weight_override::*;
+pub mod nft_weight_override;
+
+pub use compressed_nft_asset::*;
+pub mod compressed_nft_asset;
+
+pub use predecessor_voter_weight::*;
+pub mod predecessor_voter_weight;
+
 pub mod max_voter_weight_record;
 
 pub use voter_weight_record::*;
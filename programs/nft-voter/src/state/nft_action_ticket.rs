@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use solana_program::program_pack::IsInitialized;
+
+use spl_governance_tools::account::{get_account_data, AccountMaxSize};
+
+use crate::{error::NftVoterError, id, state::VoterWeightAction};
+
+/// Ticket recording the given NFT was evaluated for a non voting action (CreateProposal, CreateGovernance etc...)
+/// The PDA of the ticket is ["nft-action-ticket",registrar,voter_weight_action,nft_mint]
+/// It guarantees uniqueness and ensures the same NFT can't be counted twice for the same action
+/// while the ticket is valid
+/// Note: The owner is not part of the seeds to prevent the NFT from being ticketed again by a new owner
+/// after it was transferred within the same slot window
+///
+/// Tickets are created using CreateNftActionTicket in as many transactions as needed
+/// and consumed by UpdateVoterWeightRecordWithTickets which sums their weight and marks them as consumed
+/// Consumed tickets are kept until they expire to prevent the NFT from being ticketed again within the same window
+/// and once expired they can be closed by anyone using CloseNftActionTicket which refunds the payer
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct NftActionTicket {
+    /// NftActionTicket discriminator sha256("account:NftActionTicket")[..8]
+    /// Note: The discriminator is used explicitly because NftActionTickets
+    /// are created and consumed dynamically using remaining_accounts
+    pub account_discriminator: [u8; 8],
+
+    /// The Registrar the ticket was issued for
+    pub registrar: Pubkey,
+
+    /// The owner of the NFT at the time the ticket was issued
+    /// It's a Realm member pubkey corresponding to TokenOwnerRecord.governing_token_owner
+    pub governing_token_owner: Pubkey,
+
+    /// The mint of the NFT the ticket was issued for
+    pub nft_mint: Pubkey,
+
+    /// The governance action the ticket can be used for
+    pub weight_action: VoterWeightAction,
+
+    /// Governance power weight of the NFT at the time the ticket was issued
+    pub weight: u64,
+
+    /// The slot when the ticket expires and can't be used any longer
+    pub expiry: u64,
+
+    /// The account which paid for the ticket and receives the lamports when it's closed
+    pub payer: Pubkey,
+
+    /// Indicates whether the ticket was consumed by UpdateVoterWeightRecordWithTickets
+    pub is_consumed: bool,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 8],
+}
+
+impl NftActionTicket {
+    /// sha256("account:NftActionTicket")[..8]
+    pub const ACCOUNT_DISCRIMINATOR: [u8; 8] = [114, 60, 180, 44, 31, 30, 158, 118];
+
+    /// The number of slots a ticket stays valid for after it was issued
+    /// It's the window within which all tickets for the given action must be created and consumed
+    pub const EXPIRY_SLOTS: u64 = 150;
+
+    pub fn is_expired(&self, current_slot: u64) -> bool {
+        self.expiry < current_slot
+    }
+}
+
+impl AccountMaxSize for NftActionTicket {}
+
+impl IsInitialized for NftActionTicket {
+    fn is_initialized(&self) -> bool {
+        self.account_discriminator == NftActionTicket::ACCOUNT_DISCRIMINATOR
+    }
+}
+
+/// Returns NftActionTicket PDA seeds
+pub fn get_nft_action_ticket_seeds<'a>(
+    registrar: &'a Pubkey,
+    voter_weight_action: &'a [u8; 1],
+    nft_mint: &'a Pubkey,
+) -> [&'a [u8]; 4] {
+    [
+        b"nft-action-ticket",
+        registrar.as_ref(),
+        voter_weight_action,
+        nft_mint.as_ref(),
+    ]
+}
+
+/// Returns NftActionTicket PDA address
+pub fn get_nft_action_ticket_address(
+    registrar: &Pubkey,
+    voter_weight_action: VoterWeightAction,
+    nft_mint: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_nft_action_ticket_seeds(registrar, &[voter_weight_action as u8], nft_mint),
+        &id(),
+    )
+    .0
+}
+
+/// Deserializes account and checks owner program
+pub fn get_nft_action_ticket_data(nft_action_ticket_info: &AccountInfo) -> Result<NftActionTicket> {
+    Ok(get_account_data::<NftActionTicket>(
+        &id(),
+        nft_action_ticket_info,
+    )?)
+}
+
+/// Deserializes NftActionTicket and asserts it was issued for the given Registrar, TokenOwner and action
+/// and that it hasn't expired or been consumed yet
+pub fn get_nft_action_ticket_data_for_registrar_and_token_owner(
+    nft_action_ticket_info: &AccountInfo,
+    registrar: &Pubkey,
+    governing_token_owner: &Pubkey,
+    voter_weight_action: VoterWeightAction,
+    current_slot: u64,
+) -> Result<NftActionTicket> {
+    let nft_action_ticket = get_nft_action_ticket_data(nft_action_ticket_info)?;
+
+    require!(
+        nft_action_ticket.registrar == *registrar,
+        NftVoterError::InvalidRegistrarForNftActionTicket
+    );
+
+    require!(
+        nft_action_ticket.governing_token_owner == *governing_token_owner,
+        NftVoterError::InvalidTokenOwnerForNftActionTicket
+    );
+
+    require!(
+        nft_action_ticket.weight_action == voter_weight_action,
+        NftVoterError::InvalidActionForNftActionTicket
+    );
+
+    require!(
+        !nft_action_ticket.is_expired(current_slot),
+        NftVoterError::NftActionTicketExpired
+    );
+
+    require!(
+        !nft_action_ticket.is_consumed,
+        NftVoterError::NftActionTicketAlreadyConsumed
+    );
+
+    Ok(nft_action_ticket)
+}
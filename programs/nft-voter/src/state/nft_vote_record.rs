@@ -22,6 +22,7 @@ pub struct NftVoteRecord {
     pub proposal: Pubkey,
 
     /// The mint of the NFT which was used for the vote
+    /// For compressed NFTs it's the asset id of the NFT
     pub nft_mint: Pubkey,
 
     /// The voter who casted this vote
@@ -0,0 +1,109 @@
+use crate::error::NftVoterError;
+use crate::id;
+use crate::tools::anchor::DISCRIMINATOR_SIZE;
+use anchor_lang::prelude::*;
+use solana_program::pubkey::PUBKEY_BYTES;
+
+/// Weight override of a single NFT configured by the Realm authority
+/// It allows NFTs of the same collection to have different governance power, for example legendary tier NFTs can count more than commons
+///
+/// The governance power of the NFT is CollectionConfig.weight + weight_bonus
+/// and the bonus is accounted for in CollectionConfig.weight_bonus_total to keep max voter weight consistent
+///
+/// The PDA of the override is ["nft-weight-override",registrar,nft_mint]
+#[account]
+#[derive(Debug, PartialEq, Default)]
+pub struct NftWeightOverride {
+    /// The Registrar the override belongs to
+    pub registrar: Pubkey,
+
+    /// The collection of the NFT
+    /// The override only applies to NFTs of the given collection
+    pub collection: Pubkey,
+
+    /// The mint of the NFT the override is for
+    /// Note: Compressed NFTs don't have overrides and they always have the collection weight
+    pub nft_mint: Pubkey,
+
+    /// Governance power weight added to the collection weight
+    /// Note: The weight is scaled accordingly to the governing_token_mint decimals
+    pub weight_bonus: u64,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 8],
+}
+
+impl NftWeightOverride {
+    pub fn get_space() -> usize {
+        DISCRIMINATOR_SIZE + PUBKEY_BYTES * 3 + 8 + 8
+    }
+}
+
+/// Returns NftWeightOverride PDA seeds
+pub fn get_nft_weight_override_seeds<'a>(
+    registrar: &'a Pubkey,
+    nft_mint: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        b"nft-weight-override",
+        registrar.as_ref(),
+        nft_mint.as_ref(),
+    ]
+}
+
+/// Returns NftWeightOverride PDA address
+pub fn get_nft_weight_override_address(registrar: &Pubkey, nft_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&get_nft_weight_override_seeds(registrar, nft_mint), &id()).0
+}
+
+/// Returns the weight bonus of the given NFT
+/// The NftWeightOverride account must be the PDA for the given Registrar and NFT but it doesn't have to exist
+/// If the account doesn't exist then the NFT doesn't have any bonus
+pub fn get_nft_weight_bonus(
+    nft_weight_override_info: &AccountInfo,
+    registrar: &Pubkey,
+    collection: &Pubkey,
+    nft_mint: &Pubkey,
+) -> Result<u64> {
+    require!(
+        *nft_weight_override_info.key == get_nft_weight_override_address(registrar, nft_mint),
+        NftVoterError::InvalidNftWeightOverride
+    );
+
+    if nft_weight_override_info.data_is_empty() {
+        return Ok(0);
+    }
+
+    if *nft_weight_override_info.owner != id() {
+        return Err(NftVoterError::InvalidAccountOwner.into());
+    }
+
+    let nft_weight_override =
+        NftWeightOverride::try_deserialize(&mut &nft_weight_override_info.try_borrow_data()?[..])?;
+
+    // The bonus is accounted for in the max voter weight of the override collection only
+    if nft_weight_override.collection != *collection {
+        return Ok(0);
+    }
+
+    Ok(nft_weight_override.weight_bonus)
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = NftWeightOverride::get_space();
+
+        // Act
+        let actual_space =
+            DISCRIMINATOR_SIZE + NftWeightOverride::default().try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::error::NftVoterError;
+use crate::state::{
+    max_voter_weight_record::MaxVoterWeightRecord, Registrar, VoterWeightAction, VoterWeightRecord,
+};
+use anchor_lang::prelude::*;
+use gpl_plugin_core::generic_voter_weight::{GenericVoterWeight, GenericVoterWeightEnum};
+use gpl_plugin_core::input_voter_weight::{resolve_input_voter_weight, InputVoterWeightScope};
+use solana_program::account_info::next_account_info;
+use spl_governance_addin_api::max_voter_weight;
+use spl_governance_tools::account::get_account_data;
+use std::slice::Iter;
+
+/// Defines how the voter weight of the predecessor plugin is combined with the NFT voter weight
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum PredecessorWeightMode {
+    /// The predecessor voter weight is added to the NFT voter weight
+    /// and the predecessor max voter weight is added to the NFT max voter weight
+    Add,
+
+    /// The NFT voter weight replaces the predecessor voter weight
+    /// The predecessor plugin still gates the action through its weight_action, weight_action_target and expiry
+    Replace,
+
+    /// The NFT voter weight is used but the predecessor voter weight must be greater than zero
+    /// It allows to use the predecessor plugin as a gate, for example to require a Civic Pass
+    RequireNonZero,
+}
+
+impl Default for PredecessorWeightMode {
+    fn default() -> Self {
+        PredecessorWeightMode::Add
+    }
+}
+
+/// Resolves the VoterWeightRecord of the predecessor plugin configured for the Registrar
+/// If the Registrar has a predecessor plugin then its VoterWeightRecord is taken from the given accounts
+/// and it must be provided as the first account by the caller
+///
+/// The predecessor VoterWeightRecord can't be expired and it must be evaluated for the given weight_action
+/// and weight_action_target if it's specific to any action or target
+///
+/// Returns None if the Registrar doesn't have a predecessor plugin
+pub fn resolve_predecessor_voter_weight_record(
+    registrar: &Registrar,
+    voter_weight_record: &VoterWeightRecord,
+    weight_action: VoterWeightAction,
+    weight_action_target: Option<Pubkey>,
+    accounts: &mut Iter<AccountInfo>,
+) -> Result<Option<GenericVoterWeightEnum>> {
+    if registrar.previous_voter_weight_plugin_program_id.is_none() {
+        return Ok(None);
+    }
+
+    let predecessor_voter_weight_record_info = next_account_info(accounts)?;
+
+    let predecessor_voter_weight_record = resolve_input_voter_weight(
+        predecessor_voter_weight_record_info,
+        &InputVoterWeightScope {
+            governance_program_id: registrar.governance_program_id,
+            previous_voter_weight_plugin_program_id: registrar
+                .previous_voter_weight_plugin_program_id,
+            realm: registrar.realm,
+            governing_token_mint: registrar.governing_token_mint,
+            governing_token_owner: voter_weight_record.governing_token_owner,
+            weight_action: Some(weight_action),
+            weight_action_target,
+            current_slot: Clock::get()?.slot,
+        },
+    )
+    .map_err(NftVoterError::from)?;
+
+    if registrar.predecessor_weight_mode == PredecessorWeightMode::RequireNonZero {
+        require!(
+            predecessor_voter_weight_record.get_voter_weight() > 0,
+            NftVoterError::PredecessorVoterWeightIsZero
+        );
+    }
+
+    Ok(Some(predecessor_voter_weight_record))
+}
+
+impl Registrar {
+    /// Returns the NFT voter weight combined with the predecessor voter weight according to predecessor_weight_mode
+    pub fn get_voter_weight_with_predecessor(
+        &self,
+        nft_voter_weight: u64,
+        predecessor_voter_weight_record: Option<&GenericVoterWeightEnum>,
+    ) -> u64 {
+        match predecessor_voter_weight_record {
+            Some(predecessor_voter_weight_record)
+                if self.predecessor_weight_mode == PredecessorWeightMode::Add =>
+            {
+                nft_voter_weight
+                    .checked_add(predecessor_voter_weight_record.get_voter_weight())
+                    .unwrap()
+            }
+            _ => nft_voter_weight,
+        }
+    }
+}
+
+/// Resolves the MaxVoterWeightRecord of the predecessor plugin configured for the Registrar
+/// The predecessor max voter weight is only included with PredecessorWeightMode::Add
+/// and then the predecessor MaxVoterWeightRecord must be provided as the first account by the caller
+///
+/// Returns None if the predecessor max voter weight is not included
+pub fn resolve_predecessor_max_voter_weight_record(
+    registrar: &Registrar,
+    accounts: &mut Iter<AccountInfo>,
+) -> Result<Option<max_voter_weight::MaxVoterWeightRecord>> {
+    let previous_voter_weight_plugin_program_id =
+        match registrar.previous_voter_weight_plugin_program_id {
+            Some(previous_voter_weight_plugin_program_id)
+                if registrar.predecessor_weight_mode == PredecessorWeightMode::Add =>
+            {
+                previous_voter_weight_plugin_program_id
+            }
+            _ => return Ok(None),
+        };
+
+    let predecessor_max_voter_weight_record_info = accounts
+        .next()
+        .ok_or(NftVoterError::MissingPredecessorMaxVoterWeightRecord)?;
+
+    let predecessor_max_voter_weight_record: max_voter_weight::MaxVoterWeightRecord =
+        get_account_data(
+            &previous_voter_weight_plugin_program_id,
+            predecessor_max_voter_weight_record_info,
+        )
+        .map_err(|_| error!(NftVoterError::InvalidPredecessorMaxVoterWeightRecord))?;
+
+    require_keys_eq!(
+        predecessor_max_voter_weight_record.realm,
+        registrar.realm,
+        NftVoterError::InvalidPredecessorMaxVoterWeightRecordRealm
+    );
+
+    require_keys_eq!(
+        predecessor_max_voter_weight_record.governing_token_mint,
+        registrar.governing_token_mint,
+        NftVoterError::InvalidPredecessorMaxVoterWeightRecordGovTokenMint
+    );
+
+    if let Some(max_voter_weight_expiry) =
+        predecessor_max_voter_weight_record.max_voter_weight_expiry
+    {
+        require_gte!(
+            max_voter_weight_expiry,
+            Clock::get()?.slot,
+            NftVoterError::PredecessorMaxVoterWeightRecordExpired
+        );
+    }
+
+    Ok(Some(predecessor_max_voter_weight_record))
+}
+
+impl Registrar {
+    /// Updates MaxVoterWeightRecord with the max voter weight of the collections
+    /// combined with the predecessor max voter weight according to predecessor_weight_mode
+    ///
+    /// With PredecessorWeightMode::Add the record is only valid for as long as the predecessor MaxVoterWeightRecord is
+    /// If the predecessor MaxVoterWeightRecord isn't provided then the record is set as expired
+    /// and it must be updated using RefreshMaxVoterWeight before it can be used by spl-gov
+    pub fn update_max_voter_weight_record(
+        &self,
+        max_voter_weight_record: &mut MaxVoterWeightRecord,
+        predecessor_max_voter_weight_record: Option<&max_voter_weight::MaxVoterWeightRecord>,
+    ) -> Result<()> {
+        let max_voter_weight = self.get_max_voter_weight();
+
+        let is_predecessor_max_voter_weight_included =
+            self.previous_voter_weight_plugin_program_id.is_some()
+                && self.predecessor_weight_mode == PredecessorWeightMode::Add;
+
+        if !is_predecessor_max_voter_weight_included {
+            max_voter_weight_record.max_voter_weight = max_voter_weight;
+
+            // The weight never expires and only changes when collections are configured or refreshed
+            max_voter_weight_record.max_voter_weight_expiry = None;
+
+            return Ok(());
+        }
+
+        match predecessor_max_voter_weight_record {
+            Some(predecessor_max_voter_weight_record) => {
+                max_voter_weight_record.max_voter_weight = max_voter_weight
+                    .checked_add(predecessor_max_voter_weight_record.max_voter_weight)
+                    .ok_or(NftVoterError::NftWeightOverflow)?;
+                max_voter_weight_record.max_voter_weight_expiry =
+                    predecessor_max_voter_weight_record.max_voter_weight_expiry;
+            }
+            None => {
+                max_voter_weight_record.max_voter_weight = max_voter_weight;
+                max_voter_weight_record.max_voter_weight_expiry = Some(0);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Restricts the updated VoterWeightRecord to the weight_action_target of the predecessor VoterWeightRecord
+///
+/// The predecessor weight action, target and expiry are validated when the predecessor VoterWeightRecord is resolved
+/// and because it can't be expired the combined weight, valid as of the current slot only, can't outlive it
+pub fn apply_predecessor_voter_weight_scope(
+    voter_weight_record: &mut VoterWeightRecord,
+    predecessor_voter_weight_record: &GenericVoterWeightEnum,
+) {
+    // The NFT weight is not specific to any target and we carry forward the predecessor target
+    if voter_weight_record.weight_action_target.is_none() {
+        voter_weight_record.weight_action_target =
+            predecessor_voter_weight_record.get_weight_action_target();
+    }
+}
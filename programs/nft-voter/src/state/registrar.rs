@@ -1,16 +1,30 @@
 use crate::{
     error::NftVoterError,
     id,
-    state::{CollectionConfig, VoterWeightRecord},
+    state::{
+        get_nft_weight_bonus, get_nft_weight_override_address, CollectionConfig,
+        CompressedNftAsset, PredecessorWeightMode, VoterWeightRecord,
+    },
     tools::{
-        anchor::DISCRIMINATOR_SIZE, spl_token::get_spl_token_amount,
-        token_metadata::get_token_metadata_for_mint,
+        anchor::DISCRIMINATOR_SIZE,
+        compressed_nft::{
+            assert_is_bubblegum_merkle_tree, get_compressed_nft_leaf_hash,
+            verify_compressed_nft_leaf,
+        },
+        spl_token::get_spl_token_amount,
+        token_metadata::{
+            assert_token_record_can_vote, get_token_metadata_for_mint, get_token_record_for_nft,
+        },
     },
 };
 use anchor_lang::prelude::*;
+use mpl_bubblegum::utils::get_asset_id;
+use mpl_token_metadata::state::TokenStandard;
+use solana_program::account_info::next_account_info;
 use solana_program::pubkey::PUBKEY_BYTES;
 use spl_governance::state::token_owner_record;
 use spl_governance::tools::spl_token::{get_spl_token_mint, get_spl_token_owner};
+use std::slice::Iter;
 
 /// Registrar which stores NFT voting configuration for the given Realm
 #[account]
@@ -31,8 +45,30 @@ pub struct Registrar {
     /// MPL Collection used for voting
     pub collection_configs: Vec<CollectionConfig>,
 
+    /// If the plugin is one in a sequence, this is the previous plugin program ID
+    /// If set, then the voter weight instructions expect a VoterWeightRecord owned by this program
+    pub previous_voter_weight_plugin_program_id: Option<Pubkey>,
+
+    /// Defines how the predecessor plugin voter weight is combined with the NFT voter weight
+    pub predecessor_weight_mode: PredecessorWeightMode,
+
+    /// Bitmask of the collection_configs whose sizes are derived from collection_details of Metaplex sized collections
+    /// The bit at position i is set when the size of collection_configs[i] is derived
+    /// Only derived sizes are updated by RefreshMaxVoterWeight and manually configured sizes are never overwritten
+    /// Note: The bitmask is stored in the space previously reserved to keep the Registrar layout unchanged
+    pub size_derived_collections: [u8; 32],
+
+    /// The number of NftVoteRecords created for the Registrar which haven't been relinquished yet
+    /// NftVoteRecords are relinquished using the Registrar and hence it can't be closed until the count is 0
+    /// Note: The count is stored in the space previously reserved and NftVoteRecords created before it was introduced are not counted
+    pub nft_vote_record_count: u64,
+
+    /// Reserved for future upgrades
+    /// Note: The reserved space is split because Borsh doesn't support arrays of arbitrary length
+    pub reserved: [u8; 24],
+
     /// Reserved for future upgrades
-    pub reserved: [u8; 128],
+    pub reserved2: [u8; 30],
 }
 
 impl Registrar {
@@ -41,21 +77,20 @@ impl Registrar {
             + PUBKEY_BYTES * 3
             + 4
             + max_collections as usize * (PUBKEY_BYTES + 4 + 8 + 8)
-            + 128
+            + (PUBKEY_BYTES + 1)
+            + 1
+            + 32
+            + 8
+            + 24
+            + 30
     }
 }
 
-/// Returns Registrar PDA seeds
-pub fn get_registrar_seeds<'a>(
-    realm: &'a Pubkey,
-    governing_token_mint: &'a Pubkey,
-) -> [&'a [u8]; 3] {
-    [b"registrar", realm.as_ref(), governing_token_mint.as_ref()]
-}
+pub use gpl_plugin_core::registrar::get_registrar_seeds;
 
 /// Returns Registrar PDA address
 pub fn get_registrar_address(realm: &Pubkey, governing_token_mint: &Pubkey) -> Pubkey {
-    Pubkey::find_program_address(&get_registrar_seeds(realm, governing_token_mint), &id()).0
+    gpl_plugin_core::registrar::get_registrar_address(&id(), realm, governing_token_mint)
 }
 
 impl Registrar {
@@ -66,6 +101,86 @@ impl Registrar {
             .find(|cc| cc.collection == collection)
             .ok_or_else(|| NftVoterError::CollectionNotFound.into());
     }
+
+    /// Returns true if the size of the collection at the given collection_configs index is derived
+    pub fn is_collection_size_derived(&self, collection_idx: usize) -> bool {
+        self.size_derived_collections[collection_idx / 8] & (1 << (collection_idx % 8)) != 0
+    }
+
+    pub fn set_collection_size_derived(&mut self, collection_idx: usize, is_size_derived: bool) {
+        if is_size_derived {
+            self.size_derived_collections[collection_idx / 8] |= 1 << (collection_idx % 8);
+        } else {
+            self.size_derived_collections[collection_idx / 8] &= !(1 << (collection_idx % 8));
+        }
+    }
+
+    /// Adds the collection config or updates it if the collection is already configured
+    /// The bonus of the existing NftWeightOverrides doesn't change when the collection is reconfigured
+    pub fn upsert_collection_config(
+        &mut self,
+        collection: Pubkey,
+        weight: u64,
+        size: u32,
+        is_size_derived: bool,
+    ) {
+        let mut collection_config = CollectionConfig {
+            collection,
+            weight,
+            weight_bonus_total: 0,
+            size,
+        };
+
+        let collection_idx = self
+            .collection_configs
+            .iter()
+            .position(|cc| cc.collection == collection);
+
+        let collection_idx = if let Some(collection_idx) = collection_idx {
+            collection_config.weight_bonus_total =
+                self.collection_configs[collection_idx].weight_bonus_total;
+
+            self.collection_configs[collection_idx] = collection_config;
+            collection_idx
+        } else {
+            // Note: In the current runtime version push() would throw an error if we exceed
+            // max_collections specified when the Registrar was created or last resized
+            self.collection_configs.push(collection_config);
+            self.collection_configs.len() - 1
+        };
+
+        self.set_collection_size_derived(collection_idx, is_size_derived);
+    }
+
+    /// Removes the collection at the given collection_configs index
+    /// and shifts the size_derived_collections bits of the subsequent collections accordingly
+    pub fn remove_collection_config(&mut self, collection_idx: usize) {
+        for idx in collection_idx..self.collection_configs.len() - 1 {
+            let is_size_derived = self.is_collection_size_derived(idx + 1);
+            self.set_collection_size_derived(idx, is_size_derived);
+        }
+
+        self.set_collection_size_derived(self.collection_configs.len() - 1, false);
+        self.collection_configs.remove(collection_idx);
+    }
+
+    pub fn increase_nft_vote_record_count(&mut self, count: u64) {
+        self.nft_vote_record_count = self.nft_vote_record_count.checked_add(count).unwrap();
+    }
+
+    /// Note: The count saturates at 0 for NftVoteRecords created before the count was tracked
+    pub fn decrease_nft_vote_record_count(&mut self, count: u64) {
+        self.nft_vote_record_count = self.nft_vote_record_count.saturating_sub(count);
+    }
+
+    /// Returns max voter weight of all the configured collections
+    /// Note: The predecessor max voter weight is combined with it in update_max_voter_weight_record
+    pub fn get_max_voter_weight(&self) -> u64 {
+        self.collection_configs
+            .iter()
+            .try_fold(0u64, |sum, cc| sum.checked_add(cc.get_max_weight()))
+            .unwrap()
+    }
 }
 
 // Resolves governing_token_owner from voter TokenOwnerRecord and
@@ -98,11 +213,16 @@ pub fn resolve_governing_token_owner(
 }
 
 /// Resolves vote weight and voting mint for the given NFT
-pub fn resolve_nft_vote_weight_and_mint(
-    registrar: &Registrar,
+///
+/// For Programmable NFTs the TokenRecord of the NFT token account is taken from nft_accounts
+/// and hence the TokenRecord must follow nft_metadata in the accounts provided by the caller
+/// If the NFT has NftWeightOverride then its PDA can follow as the last account to apply the weight bonus
+pub fn resolve_nft_vote_weight_and_mint<'a, 'info>(
+    registrar: &Account<Registrar>,
     governing_token_owner: &Pubkey,
     nft_info: &AccountInfo,
     nft_metadata_info: &AccountInfo,
+    nft_accounts: &mut Iter<'a, AccountInfo<'info>>,
     unique_nft_mints: &mut Vec<Pubkey>,
 ) -> Result<(u64, Pubkey)> {
     let nft_owner = get_spl_token_owner(nft_info)?;
@@ -128,6 +248,16 @@ pub fn resolve_nft_vote_weight_and_mint(
 
     let nft_metadata = get_token_metadata_for_mint(nft_metadata_info, &nft_mint)?;
 
+    // Programmable NFT token accounts are always frozen and their lock and delegation state is tracked on TokenRecord
+    if nft_metadata.token_standard == Some(TokenStandard::ProgrammableNonFungible) {
+        let nft_token_record_info = next_account_info(nft_accounts)?;
+
+        let nft_token_record =
+            get_token_record_for_nft(nft_token_record_info, &nft_mint, nft_info.key)?;
+
+        assert_token_record_can_vote(&nft_token_record)?;
+    }
+
     // The NFT must have a collection and the collection must be verified
     let collection = nft_metadata
         .collection
@@ -137,13 +267,117 @@ pub fn resolve_nft_vote_weight_and_mint(
 
     let collection_config = registrar.get_collection_config(collection.key)?;
 
-    Ok((collection_config.weight, nft_mint))
+    let nft_vote_weight =
+        resolve_collection_nft_weight(registrar, collection_config, &nft_mint, nft_accounts)?;
+
+    Ok((nft_vote_weight, nft_mint))
+}
+
+/// Resolves vote weight and asset id for the given compressed NFT
+///
+/// The Merkle proof accounts of the asset are taken from nft_accounts
+/// and hence compressed_nft_asset.proof_len proof accounts must follow merkle_tree in the accounts provided by the caller
+/// Note: Compressed NFTs don't have NftWeightOverrides and they always have the collection weight
+pub fn resolve_compressed_nft_vote_weight_and_asset_id<'a, 'info>(
+    registrar: &Account<Registrar>,
+    governing_token_owner: &Pubkey,
+    merkle_tree_info: &AccountInfo<'info>,
+    compressed_nft_asset: &CompressedNftAsset,
+    nft_accounts: &mut Iter<'a, AccountInfo<'info>>,
+    compression_program_info: &AccountInfo<'info>,
+    unique_nft_mints: &mut Vec<Pubkey>,
+) -> Result<(u64, Pubkey)> {
+    assert_is_bubblegum_merkle_tree(merkle_tree_info)?;
+
+    let asset_id = get_asset_id(merkle_tree_info.key, compressed_nft_asset.nonce);
+
+    // Ensure the same NFT was not provided more than once
+    // Note: The asset id is used in place of the NFT mint for compressed NFTs
+    if unique_nft_mints.contains(&asset_id) {
+        return Err(NftVoterError::DuplicatedNftDetected.into());
+    }
+    unique_nft_mints.push(asset_id);
+
+    // The NFT must have a collection and the collection must be verified
+    // The verified flag is part of the leaf data hash and can't be forged without invalidating the proof
+    let collection = compressed_nft_asset
+        .metadata
+        .collection
+        .as_ref()
+        .ok_or(NftVoterError::MissingMetadataCollection)?;
+
+    require!(collection.verified, NftVoterError::CollectionMustBeVerified);
+
+    let collection_config = registrar.get_collection_config(collection.key)?;
+
+    // voter_weight_record.governing_token_owner must be the owner of the NFT
+    // It's asserted by computing the leaf with governing_token_owner as the leaf owner
+    let leaf = get_compressed_nft_leaf_hash(
+        &asset_id,
+        governing_token_owner,
+        compressed_nft_asset.nonce,
+        &compressed_nft_asset.metadata,
+    )?;
+
+    let proof_accounts = (0..compressed_nft_asset.proof_len)
+        .map(|_| next_account_info(nft_accounts).map(|info| info.clone()))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    verify_compressed_nft_leaf(
+        compression_program_info,
+        merkle_tree_info,
+        proof_accounts,
+        compressed_nft_asset.root,
+        leaf,
+        compressed_nft_asset.index,
+    )?;
+
+    Ok((collection_config.weight, asset_id))
+}
+
+/// Resolves vote weight of the NFT from the given collection including its NftWeightOverride bonus
+/// If the next account in nft_accounts is the NftWeightOverride PDA of the NFT then it's taken and its bonus is applied
+/// Otherwise the NFT has the collection weight
+///
+/// Note: The bonus only increases the NFT weight and voters have no incentive to skip the overrides
+fn resolve_collection_nft_weight<'a, 'info>(
+    registrar: &Account<Registrar>,
+    collection_config: &CollectionConfig,
+    nft_mint: &Pubkey,
+    nft_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<u64> {
+    if collection_config.weight_bonus_total == 0 {
+        return Ok(collection_config.weight);
+    }
+
+    let nft_weight_override_address = get_nft_weight_override_address(&registrar.key(), nft_mint);
+
+    if nft_accounts.as_slice().first().map(|info| info.key) != Some(&nft_weight_override_address) {
+        return Ok(collection_config.weight);
+    }
+
+    let nft_weight_override_info = next_account_info(nft_accounts)?;
+
+    let weight_bonus = get_nft_weight_bonus(
+        nft_weight_override_info,
+        &registrar.key(),
+        &collection_config.collection,
+        nft_mint,
+    )?;
+
+    let nft_vote_weight = collection_config
+        .weight
+        .checked_add(weight_bonus)
+        .ok_or(NftVoterError::NftWeightOverflow)?;
+
+    Ok(nft_vote_weight)
 }
 
 #[cfg(test)]
 mod test {
 
     use super::*;
+    use anchor_lang::Discriminator;
 
     #[test]
     fn test_get_space() {
@@ -159,7 +393,12 @@ mod test {
                 CollectionConfig::default(),
                 CollectionConfig::default(),
             ],
-            reserved: [0; 128],
+            previous_voter_weight_plugin_program_id: Some(Pubkey::default()),
+            predecessor_weight_mode: PredecessorWeightMode::Add,
+            size_derived_collections: [0; 32],
+            nft_vote_record_count: 0,
+            reserved: [0; 24],
+            reserved2: [0; 30],
         };
 
         // Act
@@ -168,4 +407,86 @@ mod test {
         // Assert
         assert_eq!(expected_space, actual_space);
     }
+
+    #[test]
+    fn test_deserialize_registrar_with_baseline_layout() {
+        // Arrange
+        let governance_program_id = Pubkey::new_unique();
+        let realm = Pubkey::new_unique();
+        let governing_token_mint = Pubkey::new_unique();
+        let collection = Pubkey::new_unique();
+
+        // Registrar layout before predecessor plugins, weight overrides and derived sizes were introduced
+        // CollectionConfig { collection, size: u32, weight: u64, reserved: [u8; 8] } and Registrar.reserved: [u8; 128]
+        let mut data = Registrar::discriminator().to_vec();
+        data.extend_from_slice(governance_program_id.as_ref());
+        data.extend_from_slice(realm.as_ref());
+        data.extend_from_slice(governing_token_mint.as_ref());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(collection.as_ref());
+        data.extend_from_slice(&10u32.to_le_bytes());
+        data.extend_from_slice(&3u64.to_le_bytes());
+        data.extend_from_slice(&[0; 8]);
+        data.extend_from_slice(&[0; 128]);
+
+        // The baseline space allocated for a single collection
+        assert_eq!(data.len(), Registrar::get_space(1));
+
+        // Act
+        let registrar = Registrar::try_deserialize(&mut data.as_slice()).unwrap();
+
+        // Assert
+        assert_eq!(
+            registrar,
+            Registrar {
+                governance_program_id,
+                realm,
+                governing_token_mint,
+                collection_configs: vec![CollectionConfig {
+                    collection,
+                    size: 10,
+                    weight: 3,
+                    weight_bonus_total: 0,
+                }],
+                previous_voter_weight_plugin_program_id: None,
+                predecessor_weight_mode: PredecessorWeightMode::default(),
+                size_derived_collections: [0; 32],
+                nft_vote_record_count: 0,
+                reserved: [0; 24],
+                reserved2: [0; 30],
+            }
+        );
+        assert!(!registrar.is_collection_size_derived(0));
+    }
+
+    #[test]
+    fn test_remove_collection_config_shifts_size_derived_collections() {
+        // Arrange
+        let mut registrar = Registrar {
+            governance_program_id: Pubkey::default(),
+            realm: Pubkey::default(),
+            governing_token_mint: Pubkey::default(),
+            collection_configs: vec![CollectionConfig::default(); 10],
+            previous_voter_weight_plugin_program_id: None,
+            predecessor_weight_mode: PredecessorWeightMode::default(),
+            size_derived_collections: [0; 32],
+            nft_vote_record_count: 0,
+            reserved: [0; 24],
+            reserved2: [0; 30],
+        };
+
+        registrar.set_collection_size_derived(1, true);
+        registrar.set_collection_size_derived(8, true);
+        registrar.set_collection_size_derived(9, true);
+
+        // Act
+        registrar.remove_collection_config(1);
+
+        // Assert
+        assert_eq!(registrar.collection_configs.len(), 9);
+        assert!((0..7).all(|idx| !registrar.is_collection_size_derived(idx)));
+        assert!(registrar.is_collection_size_derived(7));
+        assert!(registrar.is_collection_size_derived(8));
+        assert!(!registrar.is_collection_size_derived(9));
+    }
 }
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use mpl_bubblegum::state::leaf_schema::LeafSchema;
+use mpl_bubblegum::state::metaplex_adapter::MetadataArgs;
+use mpl_bubblegum::{hash_creators, hash_metadata};
+use spl_account_compression::cpi::accounts::VerifyLeaf;
+use spl_account_compression::state::{
+    ConcurrentMerkleTreeHeader, CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1,
+};
+
+use crate::error::NftVoterError;
+
+/// Returns Bubblegum TreeConfig PDA which is the authority of the Merkle tree created by Bubblegum
+pub fn get_bubblegum_tree_authority(merkle_tree: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[merkle_tree.as_ref()], &mpl_bubblegum::id()).0
+}
+
+/// Asserts the given account is spl-account-compression Merkle tree created by Bubblegum
+///
+/// Anybody can create a Merkle tree and append arbitrary leaves to it
+/// and hence only trees controlled by Bubblegum can be trusted to hold valid compressed NFTs
+pub fn assert_is_bubblegum_merkle_tree(merkle_tree_info: &AccountInfo) -> Result<()> {
+    if *merkle_tree_info.owner != spl_account_compression::id() {
+        return Err(NftVoterError::InvalidAccountOwner.into());
+    }
+
+    let merkle_tree_data = merkle_tree_info.try_borrow_data()?;
+
+    require!(
+        merkle_tree_data.len() > CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1,
+        NftVoterError::InvalidBubblegumMerkleTree
+    );
+
+    let header = ConcurrentMerkleTreeHeader::try_from_slice(
+        &merkle_tree_data[..CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1],
+    )?;
+
+    header
+        .assert_valid_authority(&get_bubblegum_tree_authority(merkle_tree_info.key))
+        .map_err(|_| NftVoterError::InvalidBubblegumMerkleTree)?;
+
+    Ok(())
+}
+
+/// Computes Bubblegum leaf hash of the given compressed NFT
+///
+/// The leaf delegate is set to the owner which means only compressed NFTs without delegates can be used
+/// Delegates can transfer compressed NFTs and delegated NFTs (ex. listed for sale) are not controlled by the owner
+pub fn get_compressed_nft_leaf_hash(
+    asset_id: &Pubkey,
+    owner: &Pubkey,
+    nonce: u64,
+    metadata: &MetadataArgs,
+) -> Result<[u8; 32]> {
+    let data_hash = hash_metadata(metadata)?;
+    let creator_hash = hash_creators(&metadata.creators)?;
+
+    let leaf = LeafSchema::new_v0(*asset_id, *owner, *owner, nonce, data_hash, creator_hash);
+
+    Ok(leaf.to_node())
+}
+
+/// Verifies the leaf is present in the Merkle tree using spl-account-compression
+/// The proof accounts are the Merkle proof nodes required to recompute the given root
+pub fn verify_compressed_nft_leaf<'info>(
+    compression_program_info: &AccountInfo<'info>,
+    merkle_tree_info: &AccountInfo<'info>,
+    proof_accounts: Vec<AccountInfo<'info>>,
+    root: [u8; 32],
+    leaf: [u8; 32],
+    index: u32,
+) -> Result<()> {
+    let cpi_ctx = CpiContext::new(
+        compression_program_info.clone(),
+        VerifyLeaf {
+            merkle_tree: merkle_tree_info.clone(),
+        },
+    )
+    .with_remaining_accounts(proof_accounts);
+
+    spl_account_compression::cpi::verify_leaf(cpi_ctx, root, leaf, index)
+}
@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
+use std::convert::TryFrom;
 
-use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+use mpl_token_metadata::pda::find_token_record_account;
+use mpl_token_metadata::state::{
+    CollectionDetails, Metadata, TokenDelegateRole, TokenMetadataAccount, TokenRecord, TokenState,
+};
 
 use crate::error::NftVoterError;
 
@@ -29,3 +33,59 @@ pub fn get_token_metadata_for_mint(account_info: &AccountInfo, mint: &Pubkey) ->
 
     Ok(token_metadata)
 }
+
+/// Deserializes TokenRecord of a Programmable NFT and asserts it's the record for the given mint and token account
+pub fn get_token_record_for_nft(
+    account_info: &AccountInfo,
+    mint: &Pubkey,
+    token_account: &Pubkey,
+) -> Result<TokenRecord> {
+    if *account_info.owner != mpl_token_metadata::ID {
+        return Err(NftVoterError::InvalidAccountOwner.into());
+    }
+
+    // TokenRecord doesn't store the mint and token account and we have to validate its PDA instead
+    let (token_record_key, _) = find_token_record_account(mint, token_account);
+
+    if token_record_key != *account_info.key {
+        return Err(NftVoterError::InvalidTokenRecord.into());
+    }
+
+    let token_record = TokenRecord::from_account_info(account_info)?;
+
+    if token_record.key != mpl_token_metadata::state::Key::TokenRecord {
+        return Err(NftVoterError::InvalidTokenRecord.into());
+    }
+
+    Ok(token_record)
+}
+
+/// Asserts the Programmable NFT can be used for governance given its TokenRecord
+///
+/// Locked NFTs (ex. staked or lent) and NFTs with delegates which can transfer them (ex. listed for sale)
+/// are not controlled by the owner and hence they can't be used
+/// Utility and Staking delegates don't disqualify the NFT as long as they haven't locked it
+pub fn assert_token_record_can_vote(token_record: &TokenRecord) -> Result<()> {
+    if token_record.state != TokenState::Unlocked {
+        return Err(NftVoterError::ProgrammableNftLocked.into());
+    }
+
+    match token_record.delegate_role {
+        None | Some(TokenDelegateRole::Utility) | Some(TokenDelegateRole::Staking) => Ok(()),
+        Some(TokenDelegateRole::Sale)
+        | Some(TokenDelegateRole::Transfer)
+        | Some(TokenDelegateRole::LockedTransfer)
+        | Some(TokenDelegateRole::Standard)
+        | Some(TokenDelegateRole::Migration) => Err(NftVoterError::ProgrammableNftDelegated.into()),
+    }
+}
+
+/// Returns the size of Metaplex sized collection or None if the collection is not sized
+pub fn get_sized_collection_size(collection_metadata: &Metadata) -> Result<Option<u32>> {
+    match collection_metadata.collection_details {
+        Some(CollectionDetails::V1 { size }) => Ok(Some(
+            u32::try_from(size).map_err(|_| NftVoterError::InvalidCollectionSize)?,
+        )),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,545 @@
+use crate::program_test::nft_voter_test::ConfigureCollectionArgs;
+use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::*;
+use program_test::compressed_nft_test::CreateCompressedNftArgs;
+use program_test::{nft_voter_test::*, tools::assert_nft_voter_err};
+
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_cast_compressed_nft_vote() -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new_with_compressed_nft().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    let mut merkle_tree_cookie = nft_voter_test.compressed_nft.with_merkle_tree().await?;
+
+    let compressed_nft_cookie1 = nft_voter_test
+        .compressed_nft
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+            None,
+        )
+        .await?;
+    let compressed_nft_cookie2 = nft_voter_test
+        .compressed_nft
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+            None,
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+    let clock = nft_voter_test.bench.get_clock().await;
+
+    // Act
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1, &compressed_nft_cookie2],
+            None,
+        )
+        .await?;
+
+    // Assert
+    let nft_vote_record = nft_voter_test
+        .get_nft_vote_record_account(&nft_vote_record_cookies[0].address)
+        .await;
+
+    assert_eq!(nft_vote_record_cookies[0].account, nft_vote_record);
+    assert_eq!(nft_vote_record.nft_mint, compressed_nft_cookie1.asset_id);
+
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 20);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+    assert_eq!(
+        voter_weight_record.weight_action,
+        Some(VoterWeightAction::CastVote.into())
+    );
+    assert_eq!(
+        voter_weight_record.weight_action_target,
+        Some(proposal_cookie.address)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_compressed_nft_vote_with_nft_already_voted_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new_with_compressed_nft().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    let mut merkle_tree_cookie = nft_voter_test.compressed_nft.with_merkle_tree().await?;
+
+    let compressed_nft_cookie1 = nft_voter_test
+        .compressed_nft
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+            None,
+        )
+        .await?;
+
+    let args = CastNftVoteArgs {
+        cast_spl_gov_vote: false,
+    };
+
+    nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+            Some(args),
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftAlreadyVoted);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_compressed_nft_vote_with_unverified_collection_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new_with_compressed_nft().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    let mut merkle_tree_cookie = nft_voter_test.compressed_nft.with_merkle_tree().await?;
+
+    let compressed_nft_cookie1 = nft_voter_test
+        .compressed_nft
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+            Some(CreateCompressedNftArgs {
+                verify_collection: false,
+            }),
+        )
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::CollectionMustBeVerified);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_compressed_nft_vote_with_forged_collection_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new_with_compressed_nft().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    let mut merkle_tree_cookie = nft_voter_test.compressed_nft.with_merkle_tree().await?;
+
+    let mut compressed_nft_cookie1 = nft_voter_test
+        .compressed_nft
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+            Some(CreateCompressedNftArgs {
+                verify_collection: false,
+            }),
+        )
+        .await?;
+
+    // Try to claim the collection is verified
+    compressed_nft_cookie1
+        .metadata
+        .collection
+        .as_mut()
+        .unwrap()
+        .verified = true;
+
+    // Act
+    let err = nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+            None,
+        )
+        .await
+        .err();
+
+    // Assert
+    // The leaf with the forged metadata is not in the tree and spl-account-compression rejects the proof
+    assert!(err.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_compressed_nft_vote_with_invalid_owner_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new_with_compressed_nft().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    let mut merkle_tree_cookie = nft_voter_test.compressed_nft.with_merkle_tree().await?;
+
+    let voter_cookie2 = nft_voter_test.bench.with_wallet().await;
+
+    let compressed_nft_cookie1 = nft_voter_test
+        .compressed_nft
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie2,
+            None,
+        )
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+            None,
+        )
+        .await
+        .err();
+
+    // Assert
+    // The leaf owned by voter_cookie2 doesn't match the leaf computed for the voter and the proof is rejected
+    assert!(err.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_compressed_nft_vote_with_same_nft_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new_with_compressed_nft().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    let mut merkle_tree_cookie = nft_voter_test.compressed_nft.with_merkle_tree().await?;
+
+    let compressed_nft_cookie1 = nft_voter_test
+        .compressed_nft
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+            None,
+        )
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1, &compressed_nft_cookie1],
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::DuplicatedNftDetected);
+
+    Ok(())
+}
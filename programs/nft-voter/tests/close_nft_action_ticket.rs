@@ -0,0 +1,226 @@
+use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::*;
+use program_test::nft_voter_test::NftVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_close_nft_action_ticket() -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_action_ticket_cookies = nft_voter_test
+        .create_nft_action_ticket(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft_cookie1],
+        )
+        .await?;
+
+    nft_voter_test
+        .update_voter_weight_record_with_tickets(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &nft_action_ticket_cookies,
+        )
+        .await?;
+
+    nft_voter_test
+        .bench
+        .advance_clock_by_slots(NftActionTicket::EXPIRY_SLOTS + 1)
+        .await;
+
+    let payer_lamports = nft_voter_test
+        .bench
+        .get_account(&nft_action_ticket_cookies[0].account.payer)
+        .await
+        .unwrap()
+        .lamports;
+
+    // Act
+    nft_voter_test
+        .close_nft_action_ticket(&nft_action_ticket_cookies[0])
+        .await?;
+
+    // Assert
+    let nft_action_ticket_account = nft_voter_test
+        .bench
+        .get_account(&nft_action_ticket_cookies[0].address)
+        .await;
+
+    assert_eq!(None, nft_action_ticket_account);
+
+    let payer_account = nft_voter_test
+        .bench
+        .get_account(&nft_action_ticket_cookies[0].account.payer)
+        .await
+        .unwrap();
+
+    assert!(payer_account.lamports > payer_lamports);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_nft_action_ticket_with_not_expired_ticket_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_action_ticket_cookies = nft_voter_test
+        .create_nft_action_ticket(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft_cookie1],
+        )
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .close_nft_action_ticket(&nft_action_ticket_cookies[0])
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftActionTicketMustBeExpired);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_nft_action_ticket_with_invalid_payer_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_action_ticket_cookies = nft_voter_test
+        .create_nft_action_ticket(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft_cookie1],
+        )
+        .await?;
+
+    nft_voter_test
+        .bench
+        .advance_clock_by_slots(NftActionTicket::EXPIRY_SLOTS + 1)
+        .await;
+
+    // Act
+    let err = nft_voter_test
+        .close_nft_action_ticket_using_ix(&nft_action_ticket_cookies[0], |i| {
+            i.accounts[1].pubkey = voter_cookie.address // payer
+        })
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidPayerForNftActionTicket);
+
+    Ok(())
+}
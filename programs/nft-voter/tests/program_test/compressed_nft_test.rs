@@ -0,0 +1,271 @@
+use std::{str::FromStr, sync::Arc};
+
+use anchor_lang::prelude::Pubkey;
+use gpl_nft_voter::tools::compressed_nft::{
+    get_bubblegum_tree_authority, get_compressed_nft_leaf_hash,
+};
+use mpl_bubblegum::state::metaplex_adapter::{
+    Collection, MetadataArgs, TokenProgramVersion, TokenStandard,
+};
+use mpl_bubblegum::utils::get_asset_id;
+use solana_program::keccak::hashv;
+use solana_program_test::ProgramTest;
+use solana_sdk::{
+    instruction::Instruction, signature::Keypair, signer::Signer, system_instruction,
+    transport::TransportError,
+};
+use spl_account_compression::state::CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1;
+use spl_concurrent_merkle_tree::concurrent_merkle_tree::ConcurrentMerkleTree;
+
+use crate::program_test::program_test_bench::{ProgramTestBench, WalletCookie};
+use crate::program_test::token_metadata_test::{NftCollectionCookie, TokenMetadataTest};
+
+pub const MAX_DEPTH: usize = 14;
+pub const MAX_BUFFER_SIZE: usize = 64;
+
+pub struct MerkleTreeCookie {
+    pub address: Pubkey,
+    pub tree_authority: Pubkey,
+
+    /// Leaves appended to the tree used to compute Merkle proofs off chain
+    pub leaves: Vec<[u8; 32]>,
+}
+
+impl MerkleTreeCookie {
+    /// Returns the current root of the tree and the Merkle proof for the leaf at the given index
+    pub fn get_root_and_proof(&self, index: u32) -> ([u8; 32], Vec<Pubkey>) {
+        let mut nodes = self.leaves.clone();
+        let mut empty_node = [0u8; 32];
+        let mut node_index = index as usize;
+        let mut proof = vec![];
+
+        for _ in 0..MAX_DEPTH {
+            let sibling = nodes.get(node_index ^ 1).copied().unwrap_or(empty_node);
+            proof.push(Pubkey::new_from_array(sibling));
+
+            nodes = nodes
+                .chunks(2)
+                .map(|pair| hashv(&[&pair[0], pair.get(1).unwrap_or(&empty_node)]).to_bytes())
+                .collect();
+
+            empty_node = hashv(&[&empty_node, &empty_node]).to_bytes();
+            node_index /= 2;
+        }
+
+        (nodes[0], proof)
+    }
+}
+
+pub struct CompressedNftCookie {
+    pub asset_id: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub collection: Pubkey,
+    pub nonce: u64,
+    pub index: u32,
+    pub metadata: MetadataArgs,
+}
+
+pub struct CreateCompressedNftArgs {
+    pub verify_collection: bool,
+}
+
+impl Default for CreateCompressedNftArgs {
+    fn default() -> Self {
+        Self {
+            verify_collection: true,
+        }
+    }
+}
+
+pub struct CompressedNftTest {
+    pub bench: Arc<ProgramTestBench>,
+}
+
+impl CompressedNftTest {
+    pub fn noop_program_id() -> Pubkey {
+        Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap()
+    }
+
+    #[allow(dead_code)]
+    pub fn add_program(program_test: &mut ProgramTest) {
+        program_test.add_program("mpl_bubblegum", mpl_bubblegum::id(), None);
+        program_test.add_program(
+            "spl_account_compression",
+            spl_account_compression::id(),
+            None,
+        );
+        program_test.add_program("spl_noop", Self::noop_program_id(), None);
+    }
+
+    #[allow(dead_code)]
+    pub fn new(bench: Arc<ProgramTestBench>) -> Self {
+        CompressedNftTest { bench }
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_merkle_tree(&self) -> Result<MerkleTreeCookie, TransportError> {
+        let merkle_tree_keypair = Keypair::new();
+        let payer = self.bench.payer.pubkey();
+
+        let merkle_tree_size = CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1
+            + std::mem::size_of::<ConcurrentMerkleTree<MAX_DEPTH, MAX_BUFFER_SIZE>>();
+
+        let tree_authority = get_bubblegum_tree_authority(&merkle_tree_keypair.pubkey());
+
+        let create_account_ix = system_instruction::create_account(
+            &payer,
+            &merkle_tree_keypair.pubkey(),
+            self.bench.rent.minimum_balance(merkle_tree_size),
+            merkle_tree_size as u64,
+            &spl_account_compression::id(),
+        );
+
+        let data = anchor_lang::InstructionData::data(&mpl_bubblegum::instruction::CreateTree {
+            max_depth: MAX_DEPTH as u32,
+            max_buffer_size: MAX_BUFFER_SIZE as u32,
+            public: None,
+        });
+
+        let accounts = mpl_bubblegum::accounts::CreateTree {
+            tree_authority,
+            merkle_tree: merkle_tree_keypair.pubkey(),
+            payer,
+            tree_creator: payer,
+            log_wrapper: Self::noop_program_id(),
+            compression_program: spl_account_compression::id(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let create_tree_ix = Instruction {
+            program_id: mpl_bubblegum::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        self.bench
+            .process_transaction(
+                &[create_account_ix, create_tree_ix],
+                Some(&[&merkle_tree_keypair]),
+            )
+            .await?;
+
+        Ok(MerkleTreeCookie {
+            address: merkle_tree_keypair.pubkey(),
+            tree_authority,
+            leaves: vec![],
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_compressed_nft(
+        &self,
+        merkle_tree_cookie: &mut MerkleTreeCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        nft_owner_cookie: &WalletCookie,
+        args: Option<CreateCompressedNftArgs>,
+    ) -> Result<CompressedNftCookie, TransportError> {
+        let CreateCompressedNftArgs { verify_collection } = args.unwrap_or_default();
+
+        let payer = self.bench.payer.pubkey();
+
+        let mut metadata = MetadataArgs {
+            name: "TestCNFT".to_string(),
+            symbol: "CNFT".to_string(),
+            uri: "URI".to_string(),
+            seller_fee_basis_points: 10,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: Some(TokenStandard::NonFungible),
+            collection: Some(Collection {
+                verified: false,
+                key: nft_collection_cookie.mint,
+            }),
+            uses: None,
+            token_program_version: TokenProgramVersion::Original,
+            creators: vec![],
+        };
+
+        let mint_ix = if verify_collection {
+            let (bubblegum_signer, _) =
+                Pubkey::find_program_address(&[b"collection_cpi"], &mpl_bubblegum::id());
+
+            let data = anchor_lang::InstructionData::data(
+                &mpl_bubblegum::instruction::MintToCollectionV1 {
+                    metadata_args: metadata.clone(),
+                },
+            );
+
+            let accounts = mpl_bubblegum::accounts::MintToCollectionV1 {
+                tree_authority: merkle_tree_cookie.tree_authority,
+                leaf_owner: nft_owner_cookie.address,
+                leaf_delegate: nft_owner_cookie.address,
+                merkle_tree: merkle_tree_cookie.address,
+                payer,
+                tree_delegate: payer,
+                collection_authority: payer,
+                collection_authority_record_pda: mpl_bubblegum::id(),
+                collection_mint: nft_collection_cookie.mint,
+                collection_metadata: nft_collection_cookie.metadata,
+                edition_account: nft_collection_cookie.master_edition,
+                bubblegum_signer,
+                log_wrapper: Self::noop_program_id(),
+                compression_program: spl_account_compression::id(),
+                token_metadata_program: TokenMetadataTest::program_id(),
+                system_program: solana_sdk::system_program::id(),
+            };
+
+            // Bubblegum sets the collection as verified in the minted leaf
+            metadata.collection.as_mut().unwrap().verified = true;
+
+            Instruction {
+                program_id: mpl_bubblegum::id(),
+                accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+                data,
+            }
+        } else {
+            let data = anchor_lang::InstructionData::data(&mpl_bubblegum::instruction::MintV1 {
+                message: metadata.clone(),
+            });
+
+            let accounts = mpl_bubblegum::accounts::MintV1 {
+                tree_authority: merkle_tree_cookie.tree_authority,
+                leaf_owner: nft_owner_cookie.address,
+                leaf_delegate: nft_owner_cookie.address,
+                merkle_tree: merkle_tree_cookie.address,
+                payer,
+                tree_delegate: payer,
+                log_wrapper: Self::noop_program_id(),
+                compression_program: spl_account_compression::id(),
+                system_program: solana_sdk::system_program::id(),
+            };
+
+            Instruction {
+                program_id: mpl_bubblegum::id(),
+                accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+                data,
+            }
+        };
+
+        self.bench.process_transaction(&[mint_ix], None).await?;
+
+        // Bubblegum uses the number of minted assets as the nonce and the leaf index
+        let nonce = merkle_tree_cookie.leaves.len() as u64;
+        let asset_id = get_asset_id(&merkle_tree_cookie.address, nonce);
+
+        let leaf =
+            get_compressed_nft_leaf_hash(&asset_id, &nft_owner_cookie.address, nonce, &metadata)
+                .unwrap();
+
+        merkle_tree_cookie.leaves.push(leaf);
+
+        Ok(CompressedNftCookie {
+            asset_id,
+            merkle_tree: merkle_tree_cookie.address,
+            collection: nft_collection_cookie.mint,
+            nonce,
+            index: nonce as u32,
+            metadata,
+        })
+    }
+}
@@ -1,5 +1,7 @@
+pub mod compressed_nft_test;
 pub mod governance_test;
 pub mod nft_voter_test;
+pub mod predecessor_plugin_test;
 pub mod program_test_bench;
 pub mod token_metadata_test;
 pub mod tools;
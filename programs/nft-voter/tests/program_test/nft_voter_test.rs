@@ -8,10 +8,13 @@ use gpl_nft_voter::state::max_voter_weight_record::{
 use gpl_nft_voter::state::*;
 
 use spl_governance::instruction::cast_vote;
+use spl_governance::state::token_owner_record::get_token_owner_record_address;
 use spl_governance::state::vote_record::{self, Vote, VoteChoice};
 
 use gpl_nft_voter::state::{
-    get_nft_vote_record_address, get_registrar_address, CollectionConfig, NftVoteRecord, Registrar,
+    get_nft_action_ticket_address, get_nft_vote_record_address, get_nft_weight_override_address,
+    get_registrar_address, CollectionConfig, NftActionTicket, NftVoteRecord, NftWeightOverride,
+    Registrar,
 };
 
 use solana_program_test::{BanksClientError, ProgramTest};
@@ -19,7 +22,11 @@ use solana_sdk::instruction::Instruction;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 
+use crate::program_test::compressed_nft_test::{
+    CompressedNftCookie, CompressedNftTest, MerkleTreeCookie,
+};
 use crate::program_test::governance_test::GovernanceTest;
+use crate::program_test::predecessor_plugin_test::PredecessorPluginTest;
 use crate::program_test::program_test_bench::ProgramTestBench;
 
 use crate::program_test::governance_test::{ProposalCookie, RealmCookie, TokenOwnerRecordCookie};
@@ -67,6 +74,18 @@ pub struct NftVoteRecordCookie {
     pub account: NftVoteRecord,
 }
 
+#[derive(Debug, PartialEq)]
+pub struct NftActionTicketCookie {
+    pub address: Pubkey,
+    pub account: NftActionTicket,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct NftWeightOverrideCookie {
+    pub address: Pubkey,
+    pub account: NftWeightOverride,
+}
+
 pub struct CastNftVoteArgs {
     pub cast_spl_gov_vote: bool,
 }
@@ -84,6 +103,8 @@ pub struct NftVoterTest {
     pub bench: Arc<ProgramTestBench>,
     pub governance: GovernanceTest,
     pub token_metadata: TokenMetadataTest,
+    pub compressed_nft: CompressedNftTest,
+    pub predecessor_plugin: PredecessorPluginTest,
 }
 
 impl NftVoterTest {
@@ -94,11 +115,27 @@ impl NftVoterTest {
 
     #[allow(dead_code)]
     pub async fn start_new() -> Self {
+        Self::start_new_using_programs(false).await
+    }
+
+    /// Starts NftVoterTest with Bubblegum, Account Compression and Noop programs loaded
+    /// Note: The programs are only loaded for compressed NFT tests
+    #[allow(dead_code)]
+    pub async fn start_new_with_compressed_nft() -> Self {
+        Self::start_new_using_programs(true).await
+    }
+
+    async fn start_new_using_programs(with_compressed_nft: bool) -> Self {
         let mut program_test = ProgramTest::default();
 
         NftVoterTest::add_program(&mut program_test);
         GovernanceTest::add_program(&mut program_test);
         TokenMetadataTest::add_program(&mut program_test);
+        PredecessorPluginTest::add_program(&mut program_test);
+
+        if with_compressed_nft {
+            CompressedNftTest::add_program(&mut program_test);
+        }
 
         let program_id = gpl_nft_voter::id();
 
@@ -108,12 +145,16 @@ impl NftVoterTest {
         let governance_bench =
             GovernanceTest::new(bench_rc.clone(), Some(program_id), Some(program_id));
         let token_metadata_bench = TokenMetadataTest::new(bench_rc.clone());
+        let compressed_nft_bench = CompressedNftTest::new(bench_rc.clone());
+        let predecessor_plugin_bench = PredecessorPluginTest::new(bench_rc.clone());
 
         Self {
             program_id,
             bench: bench_rc,
             governance: governance_bench,
             token_metadata: token_metadata_bench,
+            compressed_nft: compressed_nft_bench,
+            predecessor_plugin: predecessor_plugin_bench,
         }
     }
 
@@ -176,7 +217,12 @@ impl NftVoterTest {
             realm: realm_cookie.address,
             governing_token_mint: realm_cookie.account.community_mint,
             collection_configs: vec![],
-            reserved: [0; 128],
+            previous_voter_weight_plugin_program_id: None,
+            predecessor_weight_mode: PredecessorWeightMode::Add,
+            size_derived_collections: [0; 32],
+            nft_vote_record_count: 0,
+            reserved: [0; 24],
+            reserved2: [0; 30],
         };
 
         Ok(RegistrarCookie {
@@ -187,6 +233,182 @@ impl NftVoterTest {
         })
     }
 
+    #[allow(dead_code)]
+    pub async fn resize_registrar(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        max_collections: u8,
+    ) -> Result<(), BanksClientError> {
+        self.resize_registrar_using_ix(registrar_cookie, max_collections, NopOverride, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn resize_registrar_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        max_collections: u8,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::ResizeRegistrar {
+                max_collections,
+            });
+
+        let accounts = anchor_lang::ToAccountMetas::to_account_metas(
+            &gpl_nft_voter::accounts::ResizeRegistrar {
+                registrar: registrar_cookie.address,
+                realm: registrar_cookie.account.realm,
+                realm_authority: registrar_cookie.realm_authority.pubkey(),
+                payer: self.bench.payer.pubkey(),
+                system_program: solana_sdk::system_program::id(),
+            },
+            None,
+        );
+
+        let mut resize_registrar_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts,
+            data,
+        };
+
+        instruction_override(&mut resize_registrar_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[resize_registrar_ix], Some(signers))
+            .await?;
+
+        registrar_cookie.max_collections = max_collections;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_registrar(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+    ) -> Result<(), BanksClientError> {
+        self.close_registrar_using_ix(registrar_cookie, NopOverride, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_registrar_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::CloseRegistrar {});
+
+        let accounts = gpl_nft_voter::accounts::CloseRegistrar {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            beneficiary: self.bench.payer.pubkey(),
+        };
+
+        let mut close_registrar_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut close_registrar_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[close_registrar_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_previous_voter_weight_plugin(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        previous_voter_weight_plugin_program_id: Option<Pubkey>,
+        predecessor_weight_mode: PredecessorWeightMode,
+    ) -> Result<(), BanksClientError> {
+        self.configure_previous_voter_weight_plugin_using_ix(
+            registrar_cookie,
+            max_voter_weight_record_cookie,
+            previous_voter_weight_plugin_program_id,
+            previous_voter_weight_plugin_program_id.is_some(),
+            predecessor_weight_mode,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_previous_voter_weight_plugin_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        previous_voter_weight_plugin_program_id: Option<Pubkey>,
+        use_previous_voter_weight_plugin: bool,
+        predecessor_weight_mode: PredecessorWeightMode,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::ConfigurePreviousVoterWeightPlugin {
+                use_previous_voter_weight_plugin,
+                predecessor_weight_mode,
+            },
+        );
+
+        let mut accounts = anchor_lang::ToAccountMetas::to_account_metas(
+            &gpl_nft_voter::accounts::ConfigurePreviousVoterWeightPlugin {
+                registrar: registrar_cookie.address,
+                realm: registrar_cookie.account.realm,
+                realm_authority: registrar_cookie.realm_authority.pubkey(),
+                max_voter_weight_record: max_voter_weight_record_cookie.address,
+            },
+            None,
+        );
+
+        if let Some(previous_voter_weight_plugin_program_id) =
+            previous_voter_weight_plugin_program_id
+        {
+            accounts.push(AccountMeta::new_readonly(
+                previous_voter_weight_plugin_program_id,
+                false,
+            ));
+        }
+
+        let mut configure_previous_voter_weight_plugin_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts,
+            data,
+        };
+
+        instruction_override(&mut configure_previous_voter_weight_plugin_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_previous_voter_weight_plugin_ix], Some(signers))
+            .await?;
+
+        registrar_cookie
+            .account
+            .previous_voter_weight_plugin_program_id = previous_voter_weight_plugin_program_id;
+        registrar_cookie.account.predecessor_weight_mode = predecessor_weight_mode;
+
+        Ok(())
+    }
+
     #[allow(dead_code)]
     pub async fn with_voter_weight_record(
         &self,
@@ -260,6 +482,67 @@ impl NftVoterTest {
         })
     }
 
+    #[allow(dead_code)]
+    pub async fn close_voter_weight_record(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        voter_cookie: &WalletCookie,
+    ) -> Result<(), BanksClientError> {
+        self.close_voter_weight_record_using_ix(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            voter_cookie,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        voter_cookie: &WalletCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::CloseVoterWeightRecord {},
+        );
+
+        let voter_token_owner_record = get_token_owner_record_address(
+            &self.governance.program_id,
+            &registrar_cookie.account.realm,
+            &registrar_cookie.account.governing_token_mint,
+            &voter_cookie.address,
+        );
+
+        let accounts = gpl_nft_voter::accounts::CloseVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            voter_token_owner_record,
+            governing_token_owner: voter_cookie.address,
+            beneficiary: self.bench.payer.pubkey(),
+        };
+
+        let mut close_voter_weight_record_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut close_voter_weight_record_ix);
+
+        let default_signers = &[&voter_cookie.signer];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[close_voter_weight_record_ix], Some(signers))
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn with_max_voter_weight_record(
         &mut self,
@@ -319,6 +602,57 @@ impl NftVoterTest {
         })
     }
 
+    #[allow(dead_code)]
+    pub async fn close_max_voter_weight_record(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+    ) -> Result<(), BanksClientError> {
+        self.close_max_voter_weight_record_using_ix(
+            registrar_cookie,
+            max_voter_weight_record_cookie,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_max_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::CloseMaxVoterWeightRecord {},
+        );
+
+        let accounts = gpl_nft_voter::accounts::CloseMaxVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            beneficiary: self.bench.payer.pubkey(),
+        };
+
+        let mut close_max_voter_weight_record_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut close_max_voter_weight_record_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[close_max_voter_weight_record_ix], Some(signers))
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn update_voter_weight_record(
         &self,
@@ -326,6 +660,25 @@ impl NftVoterTest {
         voter_weight_record_cookie: &mut VoterWeightRecordCookie,
         voter_weight_action: VoterWeightAction,
         nft_cookies: &[&NftCookie],
+    ) -> Result<(), BanksClientError> {
+        self.update_voter_weight_record_with_predecessor(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            None,
+            voter_weight_action,
+            nft_cookies,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record_with_predecessor(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &mut VoterWeightRecordCookie,
+        predecessor_voter_weight_record_cookie: Option<&VoterWeightRecordCookie>,
+        voter_weight_action: VoterWeightAction,
+        nft_cookies: &[&NftCookie],
     ) -> Result<(), BanksClientError> {
         let data = anchor_lang::InstructionData::data(
             &gpl_nft_voter::instruction::UpdateVoterWeightRecord {
@@ -340,9 +693,32 @@ impl NftVoterTest {
 
         let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
 
+        if let Some(predecessor_voter_weight_record_cookie) = predecessor_voter_weight_record_cookie
+        {
+            account_metas.push(AccountMeta::new_readonly(
+                predecessor_voter_weight_record_cookie.address,
+                false,
+            ));
+        }
+
         for nft_cookie in nft_cookies {
             account_metas.push(AccountMeta::new_readonly(nft_cookie.address, false));
             account_metas.push(AccountMeta::new_readonly(nft_cookie.metadata, false));
+
+            if let Some(token_record) = nft_cookie.token_record {
+                account_metas.push(AccountMeta::new_readonly(token_record, false));
+            }
+
+            if let Some(nft_weight_override) = self
+                .get_nft_weight_override(
+                    registrar_cookie,
+                    &nft_cookie.collection,
+                    &nft_cookie.mint_cookie.address,
+                )
+                .await
+            {
+                account_metas.push(AccountMeta::new_readonly(nft_weight_override, false));
+            }
         }
 
         let instructions = vec![Instruction {
@@ -355,20 +731,203 @@ impl NftVoterTest {
     }
 
     #[allow(dead_code)]
-    pub async fn relinquish_nft_vote(
-        &mut self,
+    pub async fn create_nft_action_ticket(
+        &self,
         registrar_cookie: &RegistrarCookie,
         voter_weight_record_cookie: &VoterWeightRecordCookie,
-        proposal_cookie: &ProposalCookie,
-        voter_cookie: &WalletCookie,
-        voter_token_owner_record_cookie: &TokenOwnerRecordCookie,
-        nft_vote_record_cookies: &Vec<NftVoteRecordCookie>,
-    ) -> Result<(), BanksClientError> {
-        let data =
-            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::RelinquishNftVote {});
+        voter_weight_action: VoterWeightAction,
+        nft_cookies: &[&NftCookie],
+    ) -> Result<Vec<NftActionTicketCookie>, BanksClientError> {
+        self.create_nft_action_ticket_using_ix(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            voter_weight_action,
+            nft_cookies,
+            NopOverride,
+        )
+        .await
+    }
 
-        let vote_record_key = vote_record::get_vote_record_address(
-            &self.governance.program_id,
+    #[allow(dead_code)]
+    pub async fn create_nft_action_ticket_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        voter_weight_action: VoterWeightAction,
+        nft_cookies: &[&NftCookie],
+        instruction_override: F,
+    ) -> Result<Vec<NftActionTicketCookie>, BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::CreateNftActionTicket {
+                voter_weight_action,
+            },
+        );
+
+        let accounts = gpl_nft_voter::accounts::CreateNftActionTicket {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+        let mut nft_action_ticket_cookies = vec![];
+
+        for nft_cookie in nft_cookies {
+            account_metas.push(AccountMeta::new_readonly(nft_cookie.address, false));
+            account_metas.push(AccountMeta::new_readonly(nft_cookie.metadata, false));
+
+            if let Some(token_record) = nft_cookie.token_record {
+                account_metas.push(AccountMeta::new_readonly(token_record, false));
+            }
+
+            if let Some(nft_weight_override) = self
+                .get_nft_weight_override(
+                    registrar_cookie,
+                    &nft_cookie.collection,
+                    &nft_cookie.mint_cookie.address,
+                )
+                .await
+            {
+                account_metas.push(AccountMeta::new_readonly(nft_weight_override, false));
+            }
+
+            let nft_action_ticket_key = get_nft_action_ticket_address(
+                &registrar_cookie.address,
+                voter_weight_action,
+                &nft_cookie.mint_cookie.address,
+            );
+            account_metas.push(AccountMeta::new(nft_action_ticket_key, false));
+
+            let account = NftActionTicket {
+                account_discriminator: NftActionTicket::ACCOUNT_DISCRIMINATOR,
+                registrar: registrar_cookie.address,
+                governing_token_owner: voter_weight_record_cookie.account.governing_token_owner,
+                nft_mint: nft_cookie.mint_cookie.address,
+                weight_action: voter_weight_action,
+                weight: 0,
+                expiry: 0,
+                payer: self.bench.payer.pubkey(),
+                is_consumed: false,
+                reserved: [0; 8],
+            };
+
+            nft_action_ticket_cookies.push(NftActionTicketCookie {
+                address: nft_action_ticket_key,
+                account,
+            })
+        }
+
+        let mut create_nft_action_ticket_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        instruction_override(&mut create_nft_action_ticket_ix);
+
+        self.bench
+            .process_transaction(&[create_nft_action_ticket_ix], None)
+            .await?;
+
+        Ok(nft_action_ticket_cookies)
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record_with_tickets(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        voter_cookie: &WalletCookie,
+        voter_token_owner_record_cookie: &TokenOwnerRecordCookie,
+        voter_weight_action: VoterWeightAction,
+        nft_action_ticket_cookies: &[NftActionTicketCookie],
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::UpdateVoterWeightRecordWithTickets {
+                voter_weight_action,
+            },
+        );
+
+        let accounts = gpl_nft_voter::accounts::UpdateVoterWeightRecordWithTickets {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            voter_token_owner_record: voter_token_owner_record_cookie.address,
+            voter_authority: voter_cookie.address,
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        for nft_action_ticket_cookie in nft_action_ticket_cookies {
+            account_metas.push(AccountMeta::new(nft_action_ticket_cookie.address, false));
+        }
+
+        let update_voter_weight_record_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        self.bench
+            .process_transaction(
+                &[update_voter_weight_record_ix],
+                Some(&[&voter_cookie.signer]),
+            )
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_nft_action_ticket(
+        &self,
+        nft_action_ticket_cookie: &NftActionTicketCookie,
+    ) -> Result<(), BanksClientError> {
+        self.close_nft_action_ticket_using_ix(nft_action_ticket_cookie, NopOverride)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_nft_action_ticket_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        nft_action_ticket_cookie: &NftActionTicketCookie,
+        instruction_override: F,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::CloseNftActionTicket {},
+        );
+
+        let accounts = gpl_nft_voter::accounts::CloseNftActionTicket {
+            nft_action_ticket: nft_action_ticket_cookie.address,
+            payer: nft_action_ticket_cookie.account.payer,
+        };
+
+        let mut close_nft_action_ticket_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut close_nft_action_ticket_ix);
+
+        self.bench
+            .process_transaction(&[close_nft_action_ticket_ix], None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn relinquish_nft_vote(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        proposal_cookie: &ProposalCookie,
+        voter_cookie: &WalletCookie,
+        voter_token_owner_record_cookie: &TokenOwnerRecordCookie,
+        nft_vote_record_cookies: &Vec<NftVoteRecordCookie>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::RelinquishNftVote {});
+
+        let vote_record_key = vote_record::get_vote_record_address(
+            &self.governance.program_id,
             &proposal_cookie.address,
             &voter_token_owner_record_cookie.address,
         );
@@ -467,12 +1026,207 @@ impl NftVoterTest {
             collection: nft_collection_cookie.mint,
             size: args.size,
             weight: args.weight,
-            reserved: [0; 8],
+            weight_bonus_total: 0,
+        };
+
+        Ok(CollectionConfigCookie { collection_config })
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_sized_collection(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        weight: u64,
+    ) -> Result<CollectionConfigCookie, BanksClientError> {
+        self.with_sized_collection_using_ix(
+            registrar_cookie,
+            nft_collection_cookie,
+            max_voter_weight_record_cookie,
+            weight,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_sized_collection_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        weight: u64,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<CollectionConfigCookie, BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::ConfigureSizedCollection { weight },
+        );
+
+        let accounts = gpl_nft_voter::accounts::ConfigureSizedCollection {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            collection: nft_collection_cookie.mint,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            collection_metadata: nft_collection_cookie.metadata,
+        };
+
+        let mut configure_sized_collection_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_sized_collection_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_sized_collection_ix], Some(signers))
+            .await?;
+
+        // The size of sized collections is derived on chain from the collection metadata
+        let size = self
+            .get_registrar_account(&registrar_cookie.address)
+            .await
+            .collection_configs
+            .iter()
+            .find(|cc| cc.collection == nft_collection_cookie.mint)
+            .map_or(0, |cc| cc.size);
+
+        let collection_config = CollectionConfig {
+            collection: nft_collection_cookie.mint,
+            size,
+            weight,
+            weight_bonus_total: 0,
         };
 
         Ok(CollectionConfigCookie { collection_config })
     }
 
+    #[allow(dead_code)]
+    pub async fn refresh_max_voter_weight(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        nft_collection_cookies: &[&NftCollectionCookie],
+    ) -> Result<(), BanksClientError> {
+        self.refresh_max_voter_weight_using_ix(
+            registrar_cookie,
+            max_voter_weight_record_cookie,
+            None,
+            nft_collection_cookies,
+            NopOverride,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn refresh_max_voter_weight_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        predecessor_max_voter_weight_record_cookie: Option<&MaxVoterWeightRecordCookie>,
+        nft_collection_cookies: &[&NftCollectionCookie],
+        instruction_override: F,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::RefreshMaxVoterWeight {},
+        );
+
+        let accounts = gpl_nft_voter::accounts::RefreshMaxVoterWeight {
+            registrar: registrar_cookie.address,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        if let Some(predecessor_max_voter_weight_record_cookie) =
+            predecessor_max_voter_weight_record_cookie
+        {
+            account_metas.push(AccountMeta::new_readonly(
+                predecessor_max_voter_weight_record_cookie.address,
+                false,
+            ));
+        }
+
+        for nft_collection_cookie in nft_collection_cookies {
+            account_metas.push(AccountMeta::new_readonly(
+                nft_collection_cookie.metadata,
+                false,
+            ));
+        }
+
+        let mut refresh_max_voter_weight_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        instruction_override(&mut refresh_max_voter_weight_ix);
+
+        self.bench
+            .process_transaction(&[refresh_max_voter_weight_ix], None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_collection(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+    ) -> Result<(), BanksClientError> {
+        self.remove_collection_using_ix(
+            registrar_cookie,
+            nft_collection_cookie,
+            max_voter_weight_record_cookie,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_collection_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::RemoveCollection {});
+
+        let accounts = gpl_nft_voter::accounts::RemoveCollection {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            collection: nft_collection_cookie.mint,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+        };
+
+        let mut remove_collection_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut remove_collection_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[remove_collection_ix], Some(signers))
+            .await
+    }
+
     /// Casts NFT Vote and spl-gov Vote
     #[allow(dead_code)]
     pub async fn cast_nft_vote(
@@ -508,6 +1262,21 @@ impl NftVoterTest {
             account_metas.push(AccountMeta::new_readonly(nft_cookie.address, false));
             account_metas.push(AccountMeta::new_readonly(nft_cookie.metadata, false));
 
+            if let Some(token_record) = nft_cookie.token_record {
+                account_metas.push(AccountMeta::new_readonly(token_record, false));
+            }
+
+            if let Some(nft_weight_override) = self
+                .get_nft_weight_override(
+                    registrar_cookie,
+                    &nft_cookie.collection,
+                    &nft_cookie.mint_cookie.address,
+                )
+                .await
+            {
+                account_metas.push(AccountMeta::new_readonly(nft_weight_override, false));
+            }
+
             let nft_vote_record_key = get_nft_vote_record_address(
                 &proposal_cookie.address,
                 &nft_cookie.mint_cookie.address,
@@ -568,6 +1337,300 @@ impl NftVoterTest {
         Ok(nft_vote_record_cookies)
     }
 
+    #[allow(dead_code)]
+    pub async fn cast_compressed_nft_vote(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        proposal_cookie: &ProposalCookie,
+        nft_voter_cookie: &WalletCookie,
+        voter_token_owner_record_cookie: &TokenOwnerRecordCookie,
+        merkle_tree_cookie: &MerkleTreeCookie,
+        compressed_nft_cookies: &[&CompressedNftCookie],
+        args: Option<CastNftVoteArgs>,
+    ) -> Result<Vec<NftVoteRecordCookie>, BanksClientError> {
+        let args = args.unwrap_or_default();
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(
+            &gpl_nft_voter::accounts::CastCompressedNftVote {
+                registrar: registrar_cookie.address,
+                voter_weight_record: voter_weight_record_cookie.address,
+                voter_token_owner_record: voter_token_owner_record_cookie.address,
+                voter_authority: nft_voter_cookie.address,
+                payer: self.bench.payer.pubkey(),
+                compression_program: spl_account_compression::id(),
+                system_program: solana_sdk::system_program::id(),
+            },
+            None,
+        );
+
+        let mut compressed_nft_assets = vec![];
+        let mut nft_vote_record_cookies = vec![];
+
+        for compressed_nft_cookie in compressed_nft_cookies {
+            let (root, proof) = merkle_tree_cookie.get_root_and_proof(compressed_nft_cookie.index);
+
+            account_metas.push(AccountMeta::new_readonly(
+                compressed_nft_cookie.merkle_tree,
+                false,
+            ));
+
+            for proof_node in proof.iter() {
+                account_metas.push(AccountMeta::new_readonly(*proof_node, false));
+            }
+
+            let nft_vote_record_key = get_nft_vote_record_address(
+                &proposal_cookie.address,
+                &compressed_nft_cookie.asset_id,
+            );
+            account_metas.push(AccountMeta::new(nft_vote_record_key, false));
+
+            compressed_nft_assets.push(CompressedNftAsset {
+                root,
+                index: compressed_nft_cookie.index,
+                nonce: compressed_nft_cookie.nonce,
+                proof_len: proof.len() as u8,
+                metadata: compressed_nft_cookie.metadata.clone(),
+            });
+
+            let account = NftVoteRecord {
+                proposal: proposal_cookie.address,
+                nft_mint: compressed_nft_cookie.asset_id,
+                governing_token_owner: voter_weight_record_cookie.account.governing_token_owner,
+                account_discriminator: NftVoteRecord::ACCOUNT_DISCRIMINATOR,
+                reserved: [0; 8],
+            };
+
+            nft_vote_record_cookies.push(NftVoteRecordCookie {
+                address: nft_vote_record_key,
+                account,
+            })
+        }
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::CastCompressedNftVote {
+                proposal: proposal_cookie.address,
+                compressed_nft_assets,
+            },
+        );
+
+        let cast_compressed_nft_vote_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        let mut instruction = vec![cast_compressed_nft_vote_ix];
+
+        if args.cast_spl_gov_vote {
+            // spl-gov cast vote
+            let vote = Vote::Approve(vec![VoteChoice {
+                rank: 0,
+                weight_percentage: 100,
+            }]);
+
+            let cast_vote_ix = cast_vote(
+                &self.governance.program_id,
+                &registrar_cookie.account.realm,
+                &proposal_cookie.account.governance,
+                &proposal_cookie.address,
+                &proposal_cookie.account.token_owner_record,
+                &voter_token_owner_record_cookie.address,
+                &nft_voter_cookie.address,
+                &proposal_cookie.account.governing_token_mint,
+                &self.bench.payer.pubkey(),
+                Some(voter_weight_record_cookie.address),
+                Some(max_voter_weight_record_cookie.address),
+                vote,
+            );
+
+            instruction.push(cast_vote_ix);
+        }
+
+        self.bench
+            .process_transaction(&instruction, Some(&[&nft_voter_cookie.signer]))
+            .await?;
+
+        Ok(nft_vote_record_cookies)
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_nft_weight_override(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        nft_cookie: &NftCookie,
+        weight_bonus: u64,
+    ) -> Result<NftWeightOverrideCookie, BanksClientError> {
+        self.with_nft_weight_override_using_ix(
+            registrar_cookie,
+            nft_collection_cookie,
+            max_voter_weight_record_cookie,
+            nft_cookie,
+            weight_bonus,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_nft_weight_override_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        nft_cookie: &NftCookie,
+        weight_bonus: u64,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<NftWeightOverrideCookie, BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::ConfigureNftWeightOverride { weight_bonus },
+        );
+
+        let nft_mint = &nft_cookie.mint_cookie.address;
+
+        let nft_weight_override_key =
+            get_nft_weight_override_address(&registrar_cookie.address, nft_mint);
+
+        let accounts = gpl_nft_voter::accounts::ConfigureNftWeightOverride {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            collection: nft_collection_cookie.mint,
+            nft_mint: *nft_mint,
+            nft_metadata: nft_cookie.metadata,
+            nft_weight_override: nft_weight_override_key,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut configure_nft_weight_override_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_nft_weight_override_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_nft_weight_override_ix], Some(signers))
+            .await?;
+
+        let account = NftWeightOverride {
+            registrar: registrar_cookie.address,
+            collection: nft_collection_cookie.mint,
+            nft_mint: *nft_mint,
+            weight_bonus,
+            reserved: [0; 8],
+        };
+
+        Ok(NftWeightOverrideCookie {
+            address: nft_weight_override_key,
+            account,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_nft_weight_override(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_weight_override_cookie: &NftWeightOverrideCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+    ) -> Result<(), BanksClientError> {
+        self.close_nft_weight_override_using_ix(
+            registrar_cookie,
+            nft_weight_override_cookie,
+            max_voter_weight_record_cookie,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_nft_weight_override_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_weight_override_cookie: &NftWeightOverrideCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::CloseNftWeightOverride {},
+        );
+
+        let accounts = gpl_nft_voter::accounts::CloseNftWeightOverride {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            nft_weight_override: nft_weight_override_cookie.address,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            beneficiary: self.bench.payer.pubkey(),
+        };
+
+        let mut close_nft_weight_override_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut close_nft_weight_override_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[close_nft_weight_override_ix], Some(signers))
+            .await
+    }
+
+    /// Returns NftWeightOverride PDA of the NFT if its collection has weight overrides configured
+    // Returns the NftWeightOverride of the NFT if it exists
+    // The override is optional and NFTs without it are given the collection weight
+    async fn get_nft_weight_override(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        collection: &Pubkey,
+        nft_mint: &Pubkey,
+    ) -> Option<Pubkey> {
+        let registrar = self
+            .bench
+            .get_anchor_account::<Registrar>(registrar_cookie.address)
+            .await;
+
+        registrar
+            .collection_configs
+            .iter()
+            .find(|cc| cc.collection == *collection && cc.weight_bonus_total > 0)?;
+
+        let nft_weight_override =
+            get_nft_weight_override_address(&registrar_cookie.address, nft_mint);
+
+        self.bench
+            .get_account(&nft_weight_override)
+            .await
+            .map(|_| nft_weight_override)
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_nft_weight_override_account(
+        &mut self,
+        nft_weight_override: &Pubkey,
+    ) -> NftWeightOverride {
+        self.bench
+            .get_anchor_account::<NftWeightOverride>(*nft_weight_override)
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn get_registrar_account(&mut self, registrar: &Pubkey) -> Registrar {
         self.bench.get_anchor_account::<Registrar>(*registrar).await
@@ -580,6 +1643,16 @@ impl NftVoterTest {
             .await
     }
 
+    #[allow(dead_code)]
+    pub async fn get_nft_action_ticket_account(
+        &mut self,
+        nft_action_ticket: &Pubkey,
+    ) -> NftActionTicket {
+        self.bench
+            .get_borsh_account::<NftActionTicket>(nft_action_ticket)
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn get_max_voter_weight_record(
         &self,
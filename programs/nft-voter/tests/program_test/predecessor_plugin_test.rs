@@ -0,0 +1,152 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anchor_lang::prelude::Pubkey;
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use gpl_nft_voter::state::max_voter_weight_record::MaxVoterWeightRecord;
+use gpl_nft_voter::state::*;
+use solana_program_test::ProgramTest;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+use spl_governance_addin_mock::instruction::*;
+
+use crate::program_test::{
+    governance_test::RealmCookie,
+    nft_voter_test::{MaxVoterWeightRecordCookie, VoterWeightRecordCookie},
+    program_test_bench::{ProgramTestBench, WalletCookie},
+};
+
+pub struct PredecessorVoterWeightRecordArgs {
+    pub voter_weight: u64,
+    pub voter_weight_expiry: Option<u64>,
+    pub weight_action: Option<VoterWeightAction>,
+    pub weight_action_target: Option<Pubkey>,
+}
+
+impl Default for PredecessorVoterWeightRecordArgs {
+    fn default() -> Self {
+        Self {
+            voter_weight: 10,
+            voter_weight_expiry: None,
+            weight_action: None,
+            weight_action_target: None,
+        }
+    }
+}
+
+pub struct PredecessorPluginTest {
+    pub bench: Arc<ProgramTestBench>,
+}
+
+impl PredecessorPluginTest {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("GovAddinMock1111111111111111111111111111111").unwrap()
+    }
+
+    #[allow(dead_code)]
+    pub fn add_program(program_test: &mut ProgramTest) {
+        program_test.add_program("spl_governance_addin_mock", Self::program_id(), None);
+    }
+
+    #[allow(dead_code)]
+    pub fn new(bench: Arc<ProgramTestBench>) -> Self {
+        PredecessorPluginTest { bench }
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_voter_weight_record(
+        &self,
+        realm_cookie: &RealmCookie,
+        voter_cookie: &WalletCookie,
+        args: Option<PredecessorVoterWeightRecordArgs>,
+    ) -> Result<VoterWeightRecordCookie, TransportError> {
+        let args = args.unwrap_or_default();
+
+        let governing_token_owner = voter_cookie.address;
+        let voter_weight_record_account = Keypair::new();
+
+        // VoterWeightAction is redefined by the plugin and it has the same layout as the spl-governance-addin-api enum
+        let weight_action = args.weight_action.map(|weight_action| {
+            spl_governance_addin_api::voter_weight::VoterWeightAction::try_from_slice(
+                &weight_action.try_to_vec().unwrap(),
+            )
+            .unwrap()
+        });
+
+        let setup_voter_weight_record_ix = setup_voter_weight_record(
+            &Self::program_id(),
+            &realm_cookie.address,
+            &realm_cookie.account.community_mint,
+            &governing_token_owner,
+            &voter_weight_record_account.pubkey(),
+            &self.bench.payer.pubkey(),
+            args.voter_weight,
+            args.voter_weight_expiry,
+            weight_action,
+            args.weight_action_target,
+        );
+
+        self.bench
+            .process_transaction(
+                &[setup_voter_weight_record_ix],
+                Some(&[&voter_weight_record_account]),
+            )
+            .await?;
+
+        let account = VoterWeightRecord {
+            realm: realm_cookie.address,
+            governing_token_mint: realm_cookie.account.community_mint,
+            governing_token_owner,
+            voter_weight: args.voter_weight,
+            voter_weight_expiry: args.voter_weight_expiry,
+            weight_action: args.weight_action,
+            weight_action_target: args.weight_action_target,
+            reserved: [0; 8],
+        };
+
+        Ok(VoterWeightRecordCookie {
+            address: voter_weight_record_account.pubkey(),
+            account,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_max_voter_weight_record(
+        &self,
+        realm_cookie: &RealmCookie,
+        max_voter_weight: u64,
+        max_voter_weight_expiry: Option<u64>,
+    ) -> Result<MaxVoterWeightRecordCookie, TransportError> {
+        let max_voter_weight_record_account = Keypair::new();
+
+        let setup_max_voter_weight_record_ix = setup_max_voter_weight_record(
+            &Self::program_id(),
+            &realm_cookie.address,
+            &realm_cookie.account.community_mint,
+            &max_voter_weight_record_account.pubkey(),
+            &self.bench.payer.pubkey(),
+            max_voter_weight,
+            max_voter_weight_expiry,
+        );
+
+        self.bench
+            .process_transaction(
+                &[setup_max_voter_weight_record_ix],
+                Some(&[&max_voter_weight_record_account]),
+            )
+            .await?;
+
+        let account = MaxVoterWeightRecord {
+            realm: realm_cookie.address,
+            governing_token_mint: realm_cookie.account.community_mint,
+            max_voter_weight,
+            max_voter_weight_expiry,
+            reserved: [0; 8],
+        };
+
+        Ok(MaxVoterWeightRecordCookie {
+            address: max_voter_weight_record_account.pubkey(),
+            account,
+        })
+    }
+}
@@ -101,10 +101,15 @@ impl ProgramTestBench {
 
     #[allow(dead_code)]
     pub async fn advance_clock(&self) {
+        self.advance_clock_by_slots(2).await;
+    }
+
+    #[allow(dead_code)]
+    pub async fn advance_clock_by_slots(&self, slots: u64) {
         let clock = self.get_clock().await;
         self.context
             .borrow_mut()
-            .warp_to_slot(clock.slot + 2)
+            .warp_to_slot(clock.slot + slots)
             .unwrap();
     }
 
@@ -1,9 +1,16 @@
 use std::{str::FromStr, sync::Arc};
 
 use anchor_lang::prelude::Pubkey;
-use mpl_token_metadata::state::Collection;
+use mpl_token_metadata::instruction::builders::{CreateBuilder, DelegateBuilder, MintBuilder};
+use mpl_token_metadata::instruction::{CreateArgs, DelegateArgs, InstructionBuilder, MintArgs};
+use mpl_token_metadata::pda::find_token_record_account;
+use mpl_token_metadata::state::{
+    AssetData, Collection, CollectionDetails, PrintSupply, TokenStandard,
+};
 use solana_program_test::ProgramTest;
-use solana_sdk::{signer::Signer, transport::TransportError};
+use solana_sdk::{
+    instruction::Instruction, signature::Keypair, signer::Signer, transport::TransportError,
+};
 
 use crate::program_test::program_test_bench::{MintCookie, ProgramTestBench, WalletCookie};
 
@@ -11,12 +18,17 @@ pub struct NftCookie {
     pub address: Pubkey,
     pub metadata: Pubkey,
     pub mint_cookie: MintCookie,
+    pub collection: Pubkey,
+    /// TokenRecord of the NFT token account for Programmable NFTs
+    pub token_record: Option<Pubkey>,
 }
 
 pub struct NftCollectionCookie {
     pub mint: Pubkey,
     pub metadata: Pubkey,
     pub master_edition: Pubkey,
+    /// Indicates whether the collection is Metaplex sized collection
+    pub sized: bool,
 }
 
 pub struct CreateNftArgs {
@@ -58,6 +70,18 @@ impl TokenMetadataTest {
 
     #[allow(dead_code)]
     pub async fn with_nft_collection(&self) -> Result<NftCollectionCookie, TransportError> {
+        self.create_nft_collection(false).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_sized_nft_collection(&self) -> Result<NftCollectionCookie, TransportError> {
+        self.create_nft_collection(true).await
+    }
+
+    async fn create_nft_collection(
+        &self,
+        sized: bool,
+    ) -> Result<NftCollectionCookie, TransportError> {
         let update_authority = self.bench.context.borrow().payer.pubkey();
         let payer = self.bench.context.borrow().payer.pubkey();
 
@@ -95,7 +119,8 @@ impl TokenMetadataTest {
             false,
             None,
             None,
-            None,
+            // The size of sized collections is always initialised to 0 and it's tracked by mpl-token-metadata
+            sized.then(|| CollectionDetails::V1 { size: 0 }),
         );
 
         self.bench
@@ -136,6 +161,7 @@ impl TokenMetadataTest {
             mint: coll_mint_cookie.address,
             metadata: coll_metadata_key,
             master_edition: master_edition_key,
+            sized,
         })
     }
 
@@ -198,16 +224,7 @@ impl TokenMetadataTest {
             .await?;
 
         if verify_collection {
-            let verify_collection = mpl_token_metadata::instruction::verify_collection(
-                self.program_id,
-                metadata_key,
-                self.bench.payer.pubkey(),
-                self.bench.payer.pubkey(),
-                nft_collection_cookie.mint,
-                nft_collection_cookie.metadata,
-                nft_collection_cookie.master_edition,
-                None,
-            );
+            let verify_collection = self.verify_collection_ix(nft_collection_cookie, &metadata_key);
 
             self.bench
                 .process_transaction(&[verify_collection], None)
@@ -218,6 +235,238 @@ impl TokenMetadataTest {
             address: nft_account_cookie.address,
             metadata: metadata_key,
             mint_cookie,
+            collection: nft_collection_cookie.mint,
+            token_record: None,
         })
     }
+
+    #[allow(dead_code)]
+    pub async fn with_programmable_nft(
+        &self,
+        nft_collection_cookie: &NftCollectionCookie,
+        nft_owner_cookie: &WalletCookie,
+        args: Option<CreateNftArgs>,
+    ) -> Result<NftCookie, TransportError> {
+        let CreateNftArgs {
+            verify_collection,
+            amount,
+        } = args.unwrap_or_default();
+
+        let payer = self.bench.payer.pubkey();
+
+        // Create Programmable NFT
+        let mint_cookie = self.bench.with_mint().await?;
+
+        let metadata_seeds = &[
+            b"metadata".as_ref(),
+            self.program_id.as_ref(),
+            &mint_cookie.address.as_ref(),
+        ];
+        let (metadata_key, _) = Pubkey::find_program_address(metadata_seeds, &self.program_id);
+
+        let master_edition_seeds = &[
+            b"metadata".as_ref(),
+            self.program_id.as_ref(),
+            mint_cookie.address.as_ref(),
+            b"edition".as_ref(),
+        ];
+        let (master_edition_key, _) =
+            Pubkey::find_program_address(master_edition_seeds, &self.program_id);
+
+        let mut asset_data = AssetData::new(
+            TokenStandard::ProgrammableNonFungible,
+            "TestPNFT".to_string(),
+            "PNFT".to_string(),
+            "URI".to_string(),
+        );
+        asset_data.seller_fee_basis_points = 10;
+        asset_data.collection = Some(Collection {
+            verified: false,
+            key: nft_collection_cookie.mint,
+        });
+
+        let create_ix = CreateBuilder::new()
+            .metadata(metadata_key)
+            .master_edition(master_edition_key)
+            .mint(mint_cookie.address)
+            .authority(mint_cookie.mint_authority.pubkey())
+            .payer(payer)
+            .update_authority(payer)
+            .initialize_mint(false)
+            .update_authority_as_signer(true)
+            .build(CreateArgs::V1 {
+                asset_data,
+                decimals: Some(0),
+                print_supply: Some(PrintSupply::Zero),
+            })
+            .unwrap()
+            .instruction();
+
+        self.bench
+            .process_transaction(&[create_ix], Some(&[&mint_cookie.mint_authority]))
+            .await?;
+
+        let token_account_key =
+            get_associated_token_address(&nft_owner_cookie.address, &mint_cookie.address);
+
+        let (token_record_key, _) =
+            find_token_record_account(&mint_cookie.address, &token_account_key);
+
+        let mint_ix = MintBuilder::new()
+            .token(token_account_key)
+            .token_owner(nft_owner_cookie.address)
+            .metadata(metadata_key)
+            .master_edition(master_edition_key)
+            .token_record(token_record_key)
+            .mint(mint_cookie.address)
+            .authority(payer)
+            .payer(payer)
+            .build(MintArgs::V1 {
+                amount,
+                authorization_data: None,
+            })
+            .unwrap()
+            .instruction();
+
+        self.bench.process_transaction(&[mint_ix], None).await?;
+
+        if verify_collection {
+            let verify_collection = self.verify_collection_ix(nft_collection_cookie, &metadata_key);
+
+            self.bench
+                .process_transaction(&[verify_collection], None)
+                .await?;
+        }
+
+        Ok(NftCookie {
+            address: token_account_key,
+            metadata: metadata_key,
+            mint_cookie,
+            collection: nft_collection_cookie.mint,
+            token_record: Some(token_record_key),
+        })
+    }
+
+    /// Approves Transfer delegate for the given Programmable NFT
+    #[allow(dead_code)]
+    pub async fn with_programmable_nft_transfer_delegate(
+        &self,
+        nft_cookie: &NftCookie,
+        nft_owner_cookie: &WalletCookie,
+        delegate: &Pubkey,
+    ) -> Result<(), TransportError> {
+        let master_edition_seeds = &[
+            b"metadata".as_ref(),
+            self.program_id.as_ref(),
+            nft_cookie.mint_cookie.address.as_ref(),
+            b"edition".as_ref(),
+        ];
+        let (master_edition_key, _) =
+            Pubkey::find_program_address(master_edition_seeds, &self.program_id);
+
+        let delegate_ix = DelegateBuilder::new()
+            .delegate(*delegate)
+            .metadata(nft_cookie.metadata)
+            .master_edition(master_edition_key)
+            .token_record(nft_cookie.token_record.unwrap())
+            .mint(nft_cookie.mint_cookie.address)
+            .token(nft_cookie.address)
+            .authority(nft_owner_cookie.address)
+            .payer(self.bench.payer.pubkey())
+            .build(DelegateArgs::TransferV1 {
+                amount: 1,
+                authorization_data: None,
+            })
+            .unwrap()
+            .instruction();
+
+        self.bench
+            .process_transaction(&[delegate_ix], Some(&[&nft_owner_cookie.signer]))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Transfers the NFT to a new token account of the given new owner
+    #[allow(dead_code)]
+    pub async fn transfer_nft(
+        &self,
+        nft_cookie: &mut NftCookie,
+        nft_owner_cookie: &WalletCookie,
+        new_owner_cookie: &WalletCookie,
+    ) -> Result<(), TransportError> {
+        let token_account_keypair = Keypair::new();
+
+        self.bench
+            .create_token_account(
+                &token_account_keypair,
+                &nft_cookie.mint_cookie.address,
+                &new_owner_cookie.address,
+            )
+            .await?;
+
+        let transfer_ix = spl_token::instruction::transfer(
+            &spl_token::id(),
+            &nft_cookie.address,
+            &token_account_keypair.pubkey(),
+            &nft_owner_cookie.address,
+            &[],
+            1,
+        )
+        .unwrap();
+
+        self.bench
+            .process_transaction(&[transfer_ix], Some(&[&nft_owner_cookie.signer]))
+            .await?;
+
+        nft_cookie.address = token_account_keypair.pubkey();
+
+        Ok(())
+    }
+
+    /// Returns verify collection instruction for the given NFT metadata
+    /// Sized collections must be verified using verify_sized_collection_item
+    fn verify_collection_ix(
+        &self,
+        nft_collection_cookie: &NftCollectionCookie,
+        metadata: &Pubkey,
+    ) -> Instruction {
+        let payer = self.bench.payer.pubkey();
+
+        if nft_collection_cookie.sized {
+            mpl_token_metadata::instruction::verify_sized_collection_item(
+                self.program_id,
+                *metadata,
+                payer,
+                payer,
+                nft_collection_cookie.mint,
+                nft_collection_cookie.metadata,
+                nft_collection_cookie.master_edition,
+                None,
+            )
+        } else {
+            mpl_token_metadata::instruction::verify_collection(
+                self.program_id,
+                *metadata,
+                payer,
+                payer,
+                nft_collection_cookie.mint,
+                nft_collection_cookie.metadata,
+                nft_collection_cookie.master_edition,
+                None,
+            )
+        }
+    }
+}
+
+/// Returns the associated token account address of the given wallet and mint
+fn get_associated_token_address(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    let associated_token_program_id =
+        Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL").unwrap();
+
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), spl_token::id().as_ref(), mint.as_ref()],
+        &associated_token_program_id,
+    )
+    .0
 }
@@ -0,0 +1,633 @@
+use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::PredecessorWeightMode;
+use program_test::predecessor_plugin_test::PredecessorPluginTest;
+use program_test::{nft_voter_test::NftVoterTest, tools::assert_nft_voter_err};
+
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+use crate::program_test::nft_voter_test::ConfigureCollectionArgs;
+use crate::program_test::tools::NopOverride;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_refresh_max_voter_weight() -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test
+        .token_metadata
+        .with_sized_nft_collection()
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_sized_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            5,
+        )
+        .await?;
+
+    // Add more NFTs to the collection after it was configured
+    nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    nft_voter_test
+        .refresh_max_voter_weight(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            &[&nft_collection_cookie],
+        )
+        .await?;
+
+    // Assert
+    let registrar = nft_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.collection_configs[0].size, 3);
+
+    let max_voter_weight_record = nft_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+    assert_eq!(max_voter_weight_record.max_voter_weight, 15);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_refresh_max_voter_weight_with_manual_collection_sizes() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie1 = nft_voter_test.token_metadata.with_nft_collection().await?;
+    let nft_collection_cookie2 = nft_voter_test
+        .token_metadata
+        .with_sized_nft_collection()
+        .await?;
+    let nft_collection_cookie3 = nft_voter_test
+        .token_metadata
+        .with_sized_nft_collection()
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie2, &voter_cookie, None)
+        .await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie1,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    nft_voter_test
+        .with_sized_collection(
+            &registrar_cookie,
+            &nft_collection_cookie2,
+            &max_voter_weight_record_cookie,
+            1,
+        )
+        .await?;
+
+    // Sized collection with manually configured size
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie3,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 1,
+                size: 50,
+            }),
+        )
+        .await?;
+
+    nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie2, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie3, &voter_cookie, None)
+        .await?;
+
+    // Act
+    nft_voter_test
+        .refresh_max_voter_weight(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            &[&nft_collection_cookie2],
+        )
+        .await?;
+
+    // Assert
+    let registrar = nft_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    // Manually configured sizes stay as configured
+    assert_eq!(registrar.collection_configs[0].size, 20);
+    assert_eq!(registrar.collection_configs[1].size, 2);
+    assert_eq!(registrar.collection_configs[2].size, 50);
+
+    let max_voter_weight_record = nft_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 252);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_refresh_max_voter_weight_after_preceding_collection_removed(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie1 = nft_voter_test.token_metadata.with_nft_collection().await?;
+    let nft_collection_cookie2 = nft_voter_test
+        .token_metadata
+        .with_sized_nft_collection()
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie2, &voter_cookie, None)
+        .await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie1,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    nft_voter_test
+        .with_sized_collection(
+            &registrar_cookie,
+            &nft_collection_cookie2,
+            &max_voter_weight_record_cookie,
+            2,
+        )
+        .await?;
+
+    nft_voter_test
+        .remove_collection(
+            &registrar_cookie,
+            &nft_collection_cookie1,
+            &max_voter_weight_record_cookie,
+        )
+        .await?;
+
+    nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie2, &voter_cookie, None)
+        .await?;
+
+    // Act
+    nft_voter_test
+        .refresh_max_voter_weight(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            &[&nft_collection_cookie2],
+        )
+        .await?;
+
+    // Assert
+    let registrar = nft_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert!(registrar.is_collection_size_derived(0));
+    assert!(!registrar.is_collection_size_derived(1));
+    assert_eq!(registrar.collection_configs[0].size, 2);
+
+    let max_voter_weight_record = nft_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_refresh_max_voter_weight_with_missing_collection_metadata_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie1 = nft_voter_test.token_metadata.with_nft_collection().await?;
+    let nft_collection_cookie2 = nft_voter_test
+        .token_metadata
+        .with_sized_nft_collection()
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie2, &voter_cookie, None)
+        .await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie1,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    nft_voter_test
+        .with_sized_collection(
+            &registrar_cookie,
+            &nft_collection_cookie2,
+            &max_voter_weight_record_cookie,
+            1,
+        )
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .refresh_max_voter_weight(&registrar_cookie, &max_voter_weight_record_cookie, &[])
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidCollectionMetadataAccounts);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_refresh_max_voter_weight_with_invalid_collection_metadata_order_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie1 = nft_voter_test
+        .token_metadata
+        .with_sized_nft_collection()
+        .await?;
+    let nft_collection_cookie2 = nft_voter_test
+        .token_metadata
+        .with_sized_nft_collection()
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie1, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie2, &voter_cookie, None)
+        .await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_sized_collection(
+            &registrar_cookie,
+            &nft_collection_cookie1,
+            &max_voter_weight_record_cookie,
+            1,
+        )
+        .await?;
+
+    nft_voter_test
+        .with_sized_collection(
+            &registrar_cookie,
+            &nft_collection_cookie2,
+            &max_voter_weight_record_cookie,
+            1,
+        )
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .refresh_max_voter_weight(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            &[&nft_collection_cookie2, &nft_collection_cookie1],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::TokenMetadataDoesNotMatch);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_refresh_max_voter_weight_with_predecessor_add_mode() -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            PredecessorWeightMode::Add,
+        )
+        .await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let max_voter_weight_expiry = nft_voter_test.bench.get_clock().await.slot + 10;
+
+    let predecessor_max_voter_weight_record_cookie = nft_voter_test
+        .predecessor_plugin
+        .with_max_voter_weight_record(&realm_cookie, 100, Some(max_voter_weight_expiry))
+        .await?;
+
+    // Act
+    nft_voter_test
+        .refresh_max_voter_weight_using_ix(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(&predecessor_max_voter_weight_record_cookie),
+            &[],
+            NopOverride,
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = nft_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 300);
+    assert_eq!(
+        max_voter_weight_record.max_voter_weight_expiry,
+        Some(max_voter_weight_expiry)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_collection_with_predecessor_add_mode_expires_max_voter_weight_record(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            PredecessorWeightMode::Add,
+        )
+        .await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = nft_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    // The predecessor max voter weight is only included by RefreshMaxVoterWeight
+    assert_eq!(max_voter_weight_record.max_voter_weight, 200);
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, Some(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_refresh_max_voter_weight_with_missing_predecessor_max_voter_weight_record_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            PredecessorWeightMode::Add,
+        )
+        .await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .refresh_max_voter_weight(&registrar_cookie, &max_voter_weight_record_cookie, &[])
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::MissingPredecessorMaxVoterWeightRecord);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_refresh_max_voter_weight_with_expired_predecessor_max_voter_weight_record_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            PredecessorWeightMode::Add,
+        )
+        .await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let max_voter_weight_expiry = nft_voter_test.bench.get_clock().await.slot;
+
+    let predecessor_max_voter_weight_record_cookie = nft_voter_test
+        .predecessor_plugin
+        .with_max_voter_weight_record(&realm_cookie, 100, Some(max_voter_weight_expiry))
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .refresh_max_voter_weight_using_ix(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(&predecessor_max_voter_weight_record_cookie),
+            &[],
+            NopOverride,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::PredecessorMaxVoterWeightRecordExpired);
+
+    Ok(())
+}
@@ -0,0 +1,688 @@
+use crate::program_test::nft_voter_test::ConfigureCollectionArgs;
+use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::*;
+use program_test::nft_voter_test::NftVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_tickets() -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Issue tickets in separate transactions
+    let mut nft_action_ticket_cookies = nft_voter_test
+        .create_nft_action_ticket(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft_cookie1],
+        )
+        .await?;
+
+    nft_action_ticket_cookies.extend(
+        nft_voter_test
+            .create_nft_action_ticket(
+                &registrar_cookie,
+                &voter_weight_record_cookie,
+                VoterWeightAction::CreateProposal,
+                &[&nft_cookie2],
+            )
+            .await?,
+    );
+
+    nft_voter_test.bench.advance_clock().await;
+    let clock = nft_voter_test.bench.get_clock().await;
+
+    // Act
+    nft_voter_test
+        .update_voter_weight_record_with_tickets(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &nft_action_ticket_cookies,
+        )
+        .await?;
+
+    // Assert
+
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 20);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+    assert_eq!(
+        voter_weight_record.weight_action,
+        Some(VoterWeightAction::CreateProposal.into())
+    );
+    assert_eq!(voter_weight_record.weight_action_target, None);
+
+    // Ensure all tickets were consumed and kept until they expire
+    for nft_action_ticket_cookie in nft_action_ticket_cookies {
+        let nft_action_ticket = nft_voter_test
+            .get_nft_action_ticket_account(&nft_action_ticket_cookie.address)
+            .await;
+
+        assert!(nft_action_ticket.is_consumed);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_nft_action_ticket() -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let clock = nft_voter_test.bench.get_clock().await;
+
+    // Act
+    let nft_action_ticket_cookies = nft_voter_test
+        .create_nft_action_ticket(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            VoterWeightAction::CreateGovernance,
+            &[&nft_cookie1],
+        )
+        .await?;
+
+    // Assert
+    let nft_action_ticket = nft_voter_test
+        .get_nft_action_ticket_account(&nft_action_ticket_cookies[0].address)
+        .await;
+
+    assert_eq!(nft_action_ticket.weight, 10);
+    assert_eq!(
+        nft_action_ticket.expiry,
+        clock.slot + NftActionTicket::EXPIRY_SLOTS
+    );
+    assert_eq!(
+        nft_action_ticket.weight_action,
+        VoterWeightAction::CreateGovernance
+    );
+    assert_eq!(nft_action_ticket.nft_mint, nft_cookie1.mint_cookie.address);
+    assert_eq!(
+        nft_action_ticket.governing_token_owner,
+        voter_cookie.address
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_nft_action_ticket_with_already_issued_ticket_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .create_nft_action_ticket(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft_cookie1],
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .create_nft_action_ticket(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft_cookie1],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftActionTicketAlreadyIssued);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_nft_action_ticket_for_transferred_nft_with_already_issued_ticket_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let new_owner_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let new_owner_voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &new_owner_cookie)
+        .await?;
+
+    let mut nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .create_nft_action_ticket(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft_cookie1],
+        )
+        .await?;
+
+    nft_voter_test
+        .token_metadata
+        .transfer_nft(&mut nft_cookie1, &voter_cookie, &new_owner_cookie)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .create_nft_action_ticket(
+            &registrar_cookie,
+            &new_owner_voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft_cookie1],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftActionTicketAlreadyIssued);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_nft_action_ticket_with_ticket_for_other_nft_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_action_ticket_cookies = nft_voter_test
+        .create_nft_action_ticket(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft_cookie1],
+        )
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .create_nft_action_ticket_using_ix(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft_cookie2],
+            // Try to use the ticket of nft1 for nft2
+            |i| i.accounts.last_mut().unwrap().pubkey = nft_action_ticket_cookies[0].address,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftActionTicketMismatch);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_nft_action_ticket_with_cast_vote_not_allowed_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .create_nft_action_ticket(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            VoterWeightAction::CastVote,
+            &[&nft_cookie1],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::CastVoteIsNotAllowed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_tickets_for_other_action_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_action_ticket_cookies = nft_voter_test
+        .create_nft_action_ticket(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft_cookie1],
+        )
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .update_voter_weight_record_with_tickets(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            VoterWeightAction::CommentProposal,
+            &nft_action_ticket_cookies,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidActionForNftActionTicket);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_consumed_tickets_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_action_ticket_cookies = nft_voter_test
+        .create_nft_action_ticket(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft_cookie1],
+        )
+        .await?;
+
+    nft_voter_test
+        .update_voter_weight_record_with_tickets(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &nft_action_ticket_cookies,
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .update_voter_weight_record_with_tickets(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &nft_action_ticket_cookies,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftActionTicketAlreadyConsumed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_nft_action_ticket_with_consumed_ticket_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_action_ticket_cookies = nft_voter_test
+        .create_nft_action_ticket(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft_cookie1],
+        )
+        .await?;
+
+    nft_voter_test
+        .update_voter_weight_record_with_tickets(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &nft_action_ticket_cookies,
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .create_nft_action_ticket(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft_cookie1],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftActionTicketAlreadyIssued);
+
+    Ok(())
+}
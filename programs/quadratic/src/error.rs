@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use gpl_plugin_core::input_voter_weight::InputVoterWeightError;
+
+#[error_code]
+pub enum QuadraticError {
+    #[msg("Invalid realm authority")]
+    InvalidRealmAuthority,
+
+    #[msg("Invalid realm for the provided registrar")]
+    InvalidRealmForRegistrar,
+
+    #[msg("Invalid TokenOwnerRecord as input voter weight (expecting TokenOwnerRecord V1 or V2)")]
+    InvalidPredecessorTokenOwnerRecord,
+
+    #[msg("Invalid VoterWeightRecord as input voter weight (expecting VoterWeightRecord)")]
+    InvalidPredecessorVoterWeightRecord,
+
+    #[msg("Invalid VoterWeightRecord realm for input voter weight")]
+    InvalidPredecessorVoterWeightRecordRealm,
+
+    #[msg("Invalid VoterWeightRecord governance token mint for input voter weight")]
+    InvalidPredecessorVoterWeightRecordGovTokenMint,
+
+    #[msg("Invalid VoterWeightRecord governance token owner for input voter weight")]
+    InvalidPredecessorVoterWeightRecordGovTokenOwner,
+
+    #[msg("Invalid VoterWeightRecord realm")]
+    InvalidVoterWeightRecordRealm,
+
+    #[msg("Invalid VoterWeightRecord mint")]
+    InvalidVoterWeightRecordMint,
+
+    #[msg("Previous voter weight plugin required but not provided")]
+    MissingPreviousVoterWeightPlugin,
+
+    #[msg("Input VoterWeightRecord expired")]
+    PredecessorVoterWeightRecordExpired,
+
+    #[msg("Invalid MaxVoterWeightRecord realm")]
+    InvalidMaxVoterWeightRecordRealm,
+
+    #[msg("Invalid MaxVoterWeightRecord mint")]
+    InvalidMaxVoterWeightRecordMint,
+
+    #[msg("Invalid governing token mint for the provided registrar")]
+    InvalidGoverningTokenMintForRegistrar,
+
+    #[msg("Previous voter weight plugin MaxVoterWeightRecord required but not provided")]
+    MissingPredecessorMaxVoterWeightRecord,
+
+    #[msg(
+        "Invalid MaxVoterWeightRecord as input max voter weight (expecting MaxVoterWeightRecord)"
+    )]
+    InvalidPredecessorMaxVoterWeightRecord,
+
+    #[msg("Invalid MaxVoterWeightRecord realm for input max voter weight")]
+    InvalidPredecessorMaxVoterWeightRecordRealm,
+
+    #[msg("Invalid MaxVoterWeightRecord governance token mint for input max voter weight")]
+    InvalidPredecessorMaxVoterWeightRecordGovTokenMint,
+
+    #[msg("Input MaxVoterWeightRecord expired")]
+    PredecessorMaxVoterWeightRecordExpired,
+
+    #[msg("Voter weight overflow")]
+    VoterWeightOverflow,
+
+    #[msg("Max voter weight mode not configured")]
+    MaxVoterWeightModeNotConfigured,
+}
+
+impl From<InputVoterWeightError> for QuadraticError {
+    fn from(error: InputVoterWeightError) -> Self {
+        match error {
+            InputVoterWeightError::InvalidTokenOwnerRecord => {
+                QuadraticError::InvalidPredecessorTokenOwnerRecord
+            }
+            // The plugin takes the action and target from the input voter weight and never restricts them
+            InputVoterWeightError::InvalidVoterWeightRecord
+            | InputVoterWeightError::InvalidWeightAction
+            | InputVoterWeightError::InvalidWeightActionTarget => {
+                QuadraticError::InvalidPredecessorVoterWeightRecord
+            }
+            InputVoterWeightError::InvalidRealm => {
+                QuadraticError::InvalidPredecessorVoterWeightRecordRealm
+            }
+            InputVoterWeightError::InvalidGoverningTokenMint => {
+                QuadraticError::InvalidPredecessorVoterWeightRecordGovTokenMint
+            }
+            InputVoterWeightError::InvalidGoverningTokenOwner => {
+                QuadraticError::InvalidPredecessorVoterWeightRecordGovTokenOwner
+            }
+            InputVoterWeightError::VoterWeightExpired => {
+                QuadraticError::PredecessorVoterWeightRecordExpired
+            }
+        }
+    }
+}
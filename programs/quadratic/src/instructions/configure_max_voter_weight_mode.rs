@@ -0,0 +1,81 @@
+use crate::error::QuadraticError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+/// Configures how the max voter weight of the voting population is determined
+/// For MaxVoterWeightMode::Fixed it also sets MaxVoterWeightRecord.max_voter_weight to the configured value
+#[derive(Accounts)]
+pub struct ConfigureMaxVoterWeightMode<'info> {
+    /// The Quadratic Plugin Registrar to be updated
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    /// An spl-governance Realm
+    ///
+    /// Realm is validated in the instruction:
+    /// - Realm is owned by the governance_program_id
+    /// - realm_authority is realm.authority
+    /// CHECK: Owned by spl-governance instance specified in governance_program_id
+    #[account(
+        address = registrar.realm @ QuadraticError::InvalidRealmForRegistrar,
+        owner = registrar.governance_program_id.key()
+    )]
+    pub realm: UncheckedAccount<'info>,
+
+    /// realm_authority must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// MaxVoterWeightRecord for the given registrar.realm and registrar.governing_token_mint
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ QuadraticError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ QuadraticError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+/// Configures the max voter weight mode of the Registrar
+///
+/// In MaxVoterWeightMode::Fixed the max voter weight is static and it never expires
+/// In MaxVoterWeightMode::InputMaxVoterWeight MaxVoterWeightRecord must be refreshed using update_max_voter_weight_record
+///
+/// Warning: Only MaxVoterWeightMode::Fixed gives a correct quorum when there is more than one holder (see MaxVoterWeightMode)
+pub fn configure_max_voter_weight_mode(
+    ctx: Context<ConfigureMaxVoterWeightMode>,
+    max_voter_weight_mode: MaxVoterWeightMode,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        QuadraticError::InvalidRealmAuthority
+    );
+
+    registrar.max_voter_weight_mode = Some(max_voter_weight_mode);
+
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    match max_voter_weight_mode {
+        MaxVoterWeightMode::Fixed(max_voter_weight) => {
+            max_voter_weight_record.max_voter_weight = max_voter_weight;
+
+            // max_voter_weight can only be changed by the realm authority and it never expires
+            max_voter_weight_record.max_voter_weight_expiry = None;
+        }
+        MaxVoterWeightMode::InputMaxVoterWeight => {
+            // Expire the record to prevent the previous max voter weight from being used until it's updated
+            max_voter_weight_record.max_voter_weight_expiry = Some(0);
+        }
+    }
+
+    Ok(())
+}
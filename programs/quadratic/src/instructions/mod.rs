@@ -0,0 +1,20 @@
+pub use create_registrar::*;
+mod create_registrar;
+
+pub use configure_registrar::*;
+mod configure_registrar;
+
+pub use create_voter_weight_record::*;
+mod create_voter_weight_record;
+
+pub use update_voter_weight_record::*;
+mod update_voter_weight_record;
+
+pub use create_max_voter_weight_record::*;
+mod create_max_voter_weight_record;
+
+pub use update_max_voter_weight_record::*;
+mod update_max_voter_weight_record;
+
+pub use configure_max_voter_weight_mode::*;
+mod configure_max_voter_weight_mode;
@@ -0,0 +1,113 @@
+use crate::error::QuadraticError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use spl_governance_addin_api::max_voter_weight;
+use spl_governance_tools::account::get_account_data;
+
+/// Updates MaxVoterWeightRecord to reflect the max voter weight of the voting population
+///
+/// In MaxVoterWeightMode::Fixed the max voter weight is the configured value and it never expires
+///
+/// In MaxVoterWeightMode::InputMaxVoterWeight the max voter weight is the quadratic transform of the input max voter weight
+/// If the Registrar has a predecessor plugin then the input max voter weight is taken from the predecessor MaxVoterWeightRecord
+/// which must be provided as the first remaining account
+/// Otherwise the input max voter weight is the supply of the governing_token_mint
+/// Warning: Only MaxVoterWeightMode::Fixed gives a correct quorum when there is more than one holder
+/// The transform is the voter weight of a single voter holding the entire input max voter weight
+/// and the sum of the transformed voter weights of multiple voters is higher than that (see MaxVoterWeightMode)
+///
+/// The instruction is permissionless to allow anybody to keep the max voter weight accurate
+#[derive(Accounts)]
+pub struct UpdateMaxVoterWeightRecord<'info> {
+    /// The Quadratic Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    /// The governing token mint of the Registrar
+    #[account(
+        address = registrar.governing_token_mint @ QuadraticError::InvalidGoverningTokenMintForRegistrar
+    )]
+    pub governing_token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ QuadraticError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ QuadraticError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+pub fn update_max_voter_weight_record(ctx: Context<UpdateMaxVoterWeightRecord>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+    let current_slot = Clock::get()?.slot;
+
+    match registrar
+        .max_voter_weight_mode
+        .ok_or(QuadraticError::MaxVoterWeightModeNotConfigured)?
+    {
+        MaxVoterWeightMode::Fixed(max_voter_weight) => {
+            max_voter_weight_record.max_voter_weight = max_voter_weight;
+            max_voter_weight_record.max_voter_weight_expiry = None;
+
+            return Ok(());
+        }
+        MaxVoterWeightMode::InputMaxVoterWeight => {}
+    }
+
+    match registrar.previous_voter_weight_plugin_program_id {
+        Some(previous_voter_weight_plugin_program_id) => {
+            let predecessor_max_voter_weight_record_info = ctx
+                .remaining_accounts
+                .get(0)
+                .ok_or(QuadraticError::MissingPredecessorMaxVoterWeightRecord)?;
+
+            let predecessor_max_voter_weight_record: max_voter_weight::MaxVoterWeightRecord =
+                get_account_data(
+                    &previous_voter_weight_plugin_program_id,
+                    predecessor_max_voter_weight_record_info,
+                )
+                .map_err(|_| error!(QuadraticError::InvalidPredecessorMaxVoterWeightRecord))?;
+
+            require_keys_eq!(
+                predecessor_max_voter_weight_record.realm,
+                registrar.realm,
+                QuadraticError::InvalidPredecessorMaxVoterWeightRecordRealm
+            );
+
+            require_keys_eq!(
+                predecessor_max_voter_weight_record.governing_token_mint,
+                registrar.governing_token_mint,
+                QuadraticError::InvalidPredecessorMaxVoterWeightRecordGovTokenMint
+            );
+
+            if let Some(max_voter_weight_expiry) =
+                predecessor_max_voter_weight_record.max_voter_weight_expiry
+            {
+                require_gte!(
+                    max_voter_weight_expiry,
+                    current_slot,
+                    QuadraticError::PredecessorMaxVoterWeightRecordExpired
+                );
+            }
+
+            // The record is valid for as long as the predecessor record is
+            max_voter_weight_record.max_voter_weight =
+                registrar.get_voter_weight(predecessor_max_voter_weight_record.max_voter_weight)?;
+            max_voter_weight_record.max_voter_weight_expiry =
+                predecessor_max_voter_weight_record.max_voter_weight_expiry;
+        }
+        None => {
+            max_voter_weight_record.max_voter_weight =
+                registrar.get_voter_weight(ctx.accounts.governing_token_mint.supply)?;
+
+            // The mint supply can change at any time and the record is only valid as of the current slot
+            max_voter_weight_record.max_voter_weight_expiry = Some(current_slot);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use crate::error::QuadraticError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_plugin_core::input_voter_weight::{resolve_input_voter_weight, InputVoterWeightScope};
+
+/// Updates VoterWeightRecord to evaluate governance power for non voting use cases: CreateProposal, CreateGovernance etc...
+/// This instruction updates VoterWeightRecord which is valid for the current Slot and the given target action only
+/// and hence the instruction has to be executed inside the same transaction as the corresponding spl-gov instruction
+#[derive(Accounts)]
+pub struct UpdateVoterWeightRecord<'info> {
+    /// The Quadratic Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    /// An account that is either of type TokenOwnerRecordV2 or VoterWeightRecord
+    /// depending on whether the registrar includes a predecessor or not
+    /// CHECK: Checked in the code depending on the registrar
+    #[account()]
+    pub input_voter_weight: UncheckedAccount<'info>,
+
+    #[account(
+    mut,
+    constraint = voter_weight_record.realm == registrar.realm
+    @ QuadraticError::InvalidVoterWeightRecordRealm,
+
+    constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+    @ QuadraticError::InvalidVoterWeightRecordMint,
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+}
+
+/// Sets the voter weight record value to the quadratic transform of the input voter weight
+pub fn update_voter_weight_record(ctx: Context<UpdateVoterWeightRecord>) -> Result<()> {
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+
+    let input_voter_weight_account = ctx.accounts.input_voter_weight.to_account_info();
+
+    let registrar = &ctx.accounts.registrar;
+    let current_slot = Clock::get()?.slot;
+
+    // Quadratic plugin doesn't evaluate any specific action and takes the action and target from the input voter weight
+    let input_voter_weight_record = resolve_input_voter_weight(
+        &input_voter_weight_account,
+        &InputVoterWeightScope {
+            governance_program_id: registrar.governance_program_id,
+            previous_voter_weight_plugin_program_id: registrar
+                .previous_voter_weight_plugin_program_id,
+            realm: registrar.realm,
+            governing_token_mint: registrar.governing_token_mint,
+            governing_token_owner: voter_weight_record.governing_token_owner,
+            weight_action: None,
+            weight_action_target: None,
+            current_slot,
+        },
+    )
+    .map_err(QuadraticError::from)?;
+
+    voter_weight_record.voter_weight =
+        registrar.get_voter_weight(input_voter_weight_record.get_voter_weight())?;
+    voter_weight_record.weight_action = input_voter_weight_record.get_weight_action();
+    voter_weight_record.weight_action_target = input_voter_weight_record.get_weight_action_target();
+
+    // If the input voter weight record has an expiry, carry it forward (stale input is rejected by the resolver)
+    // Otherwise use the current slot
+    voter_weight_record.voter_weight_expiry = Some(
+        input_voter_weight_record
+            .get_voter_weight_expiry()
+            .unwrap_or(current_slot),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+pub use registrar::*;
+pub mod registrar;
+
+pub use quadratic_coefficients::*;
+pub mod quadratic_coefficients;
+
+pub use voter_weight_record::*;
+pub mod voter_weight_record;
+
+pub use max_voter_weight_record::*;
+pub mod max_voter_weight_record;
+
+pub use gpl_plugin_core::generic_voter_weight::*;
@@ -0,0 +1,174 @@
+use crate::error::QuadraticError;
+use anchor_lang::prelude::*;
+use std::convert::TryFrom;
+
+/// Coefficients of the quadratic transform applied to the input voter weight
+///
+/// voter_weight = a * sqrt(input_voter_weight) + b * input_voter_weight + c
+///
+/// The square root is taken from the whole token amount and scaled back to the units of the governing_token_mint
+/// sqrt(input_voter_weight / 10^decimals) * 10^decimals = sqrt(input_voter_weight * 10^decimals)
+/// and hence the voter weight is expressed in the same units as the input voter weight
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct QuadraticCoefficients {
+    /// The coefficient of the square root term
+    pub a: u64,
+
+    /// The coefficient of the linear term
+    pub b: u64,
+
+    /// The constant term given to every voter with a non zero input voter weight
+    /// It's expressed in the units of the governing_token_mint
+    pub c: u64,
+}
+
+impl Default for QuadraticCoefficients {
+    fn default() -> Self {
+        // Pure quadratic voting
+        QuadraticCoefficients { a: 1, b: 0, c: 0 }
+    }
+}
+
+impl QuadraticCoefficients {
+    /// Returns the voter weight for the given input voter weight expressed in the units of a mint with the given decimals
+    /// Voters without any input voter weight get no voter weight and the constant term isn't applied to them
+    pub fn apply(&self, input_voter_weight: u64, decimals: u8) -> Result<u64> {
+        if input_voter_weight == 0 {
+            return Ok(0);
+        }
+
+        let input_voter_weight = input_voter_weight as u128;
+
+        let scaled_input_voter_weight = 10_u128
+            .checked_pow(decimals as u32)
+            .and_then(|scale| scale.checked_mul(input_voter_weight))
+            .ok_or(QuadraticError::VoterWeightOverflow)?;
+
+        let voter_weight = (self.a as u128)
+            .checked_mul(integer_sqrt(scaled_input_voter_weight))
+            .and_then(|sqrt_term| {
+                (self.b as u128)
+                    .checked_mul(input_voter_weight)
+                    .and_then(|linear_term| sqrt_term.checked_add(linear_term))
+            })
+            .and_then(|voter_weight| voter_weight.checked_add(self.c as u128))
+            .ok_or(QuadraticError::VoterWeightOverflow)?;
+
+        u64::try_from(voter_weight).map_err(|_| QuadraticError::VoterWeightOverflow.into())
+    }
+}
+
+/// Returns the integer square root of the given value rounded down
+/// It uses Newton's method to avoid floating point arithmetic which isn't deterministic across validators
+pub fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut x = value / 2 + 1;
+    let mut y = (x + value / x) / 2;
+
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+
+    x
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_integer_sqrt() {
+        // Arrange
+        let values = [
+            0,
+            1,
+            2,
+            3,
+            4,
+            15,
+            16,
+            17,
+            1_000_000,
+            u64::MAX as u128,
+            u128::MAX,
+        ];
+
+        // Act
+        let actual: Vec<u128> = values.iter().map(|value| integer_sqrt(*value)).collect();
+
+        // Assert
+        assert_eq!(
+            actual,
+            vec![
+                0,
+                1,
+                1,
+                1,
+                2,
+                3,
+                4,
+                4,
+                1_000,
+                u32::MAX as u128,
+                u64::MAX as u128
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply_with_decimals() {
+        // Arrange
+        let coefficients = QuadraticCoefficients::default();
+
+        // Act
+
+        // 100 tokens with 6 decimals
+        let voter_weight = coefficients.apply(100_000_000, 6).unwrap();
+
+        // Assert
+
+        // 10 tokens with 6 decimals
+        assert_eq!(voter_weight, 10_000_000);
+    }
+
+    #[test]
+    fn test_apply_with_linear_and_constant_terms() {
+        // Arrange
+        let coefficients = QuadraticCoefficients { a: 2, b: 1, c: 5 };
+
+        // Act
+        let voter_weight = coefficients.apply(100, 0).unwrap();
+
+        // Assert
+        assert_eq!(voter_weight, 2 * 10 + 100 + 5);
+    }
+
+    #[test]
+    fn test_apply_with_zero_input_voter_weight() {
+        // Arrange
+        let coefficients = QuadraticCoefficients { a: 2, b: 1, c: 5 };
+
+        // Act
+        let voter_weight = coefficients.apply(0, 6).unwrap();
+
+        // Assert
+        assert_eq!(voter_weight, 0);
+    }
+
+    #[test]
+    fn test_apply_with_overflow_error() {
+        // Arrange
+        let coefficients = QuadraticCoefficients { a: 0, b: 2, c: 0 };
+
+        // Act
+        let err = coefficients.apply(u64::MAX, 0).err().unwrap();
+
+        // Assert
+        assert_eq!(err, QuadraticError::VoterWeightOverflow.into());
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    id,
+    state::QuadraticCoefficients,
+    tools::anchor::{DISCRIMINATOR_SIZE, PUBKEY_SIZE},
+};
+use anchor_lang::prelude::*;
+
+/// Defines how the max voter weight of the voting population is determined
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum MaxVoterWeightMode {
+    /// The max voter weight is configured by the realm authority and it's static
+    /// It should be the total voter weight of all the voters, for example the sum of the quadratic voter weights of the current holders
+    Fixed(u64),
+
+    /// The max voter weight is the quadratic transform of the input max voter weight (mint supply or the predecessor max voter weight)
+    /// Warning: Only MaxVoterWeightMode::Fixed gives a correct quorum when there is more than one holder
+    /// The transform is the voter weight of a single voter holding the entire input max voter weight
+    /// and the voter weights of multiple voters add up to more than that (two equal holders reach about 1.41x)
+    /// spl-governance then coerces the max voter weight to the total votes cast and the quorum is effectively ignored
+    /// The mode is only correct when a single voter holds the entire input max voter weight
+    InputMaxVoterWeight,
+}
+
+/// Registrar which stores the quadratic voting configuration for the given Realm
+#[account]
+#[derive(Debug, PartialEq)]
+pub struct Registrar {
+    /// spl-governance program the Realm belongs to
+    pub governance_program_id: Pubkey,
+
+    /// Realm of the Registrar
+    pub realm: Pubkey,
+
+    /// Governing token mint the Registrar is for
+    /// It can either be the Community or the Council mint of the Realm
+    pub governing_token_mint: Pubkey,
+
+    /// Decimals of the governing_token_mint
+    /// The square root of the input voter weight is taken from the whole token amount using the decimals
+    pub governing_token_mint_decimals: u8,
+
+    /// The coefficients of the quadratic transform applied to the input voter weight
+    pub quadratic_coefficients: QuadraticCoefficients,
+
+    /// If the plugin is one in a sequence, this is the previous plugin program ID
+    /// If set, then update_voter_weight_record will expect a voter_weight_record owned by this program
+    pub previous_voter_weight_plugin_program_id: Option<Pubkey>,
+
+    /// Defines how the max voter weight is determined
+    /// There is no default mode and MaxVoterWeightRecord can't be updated until the mode is configured
+    pub max_voter_weight_mode: Option<MaxVoterWeightMode>,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 128],
+}
+
+impl Registrar {
+    pub fn get_space() -> usize {
+        DISCRIMINATOR_SIZE + PUBKEY_SIZE * 3 + 1 + 8 * 3 + (PUBKEY_SIZE + 1) + (1 + 1 + 8) + 128
+    }
+
+    /// Returns the voter weight for the given input voter weight
+    pub fn get_voter_weight(&self, input_voter_weight: u64) -> Result<u64> {
+        self.quadratic_coefficients
+            .apply(input_voter_weight, self.governing_token_mint_decimals)
+    }
+}
+
+pub use gpl_plugin_core::registrar::get_registrar_seeds;
+
+/// Returns Registrar PDA address
+pub fn get_registrar_address(realm: &Pubkey, governing_token_mint: &Pubkey) -> Pubkey {
+    gpl_plugin_core::registrar::get_registrar_address(&id(), realm, governing_token_mint)
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = Registrar::get_space();
+
+        let registrar = Registrar {
+            governance_program_id: Pubkey::default(),
+            realm: Pubkey::default(),
+            governing_token_mint: Pubkey::default(),
+            governing_token_mint_decimals: 0,
+            quadratic_coefficients: QuadraticCoefficients::default(),
+            previous_voter_weight_plugin_program_id: Pubkey::default().into(),
+            max_voter_weight_mode: Some(MaxVoterWeightMode::Fixed(0)),
+            reserved: [0; 128],
+        };
+
+        // Act
+        let actual_space = DISCRIMINATOR_SIZE + registrar.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+}
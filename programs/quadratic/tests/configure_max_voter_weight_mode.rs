@@ -0,0 +1,127 @@
+mod program_test;
+
+use program_test::quadratic_voter_test::QuadraticVoterTest;
+
+use gpl_quadratic::error::QuadraticError;
+use gpl_quadratic::state::MaxVoterWeightMode;
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+use crate::program_test::tools::assert_quadratic_err;
+
+#[tokio::test]
+async fn test_configure_max_voter_weight_mode_with_fixed_mode() -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _) = quadratic_voter_test.setup(false).await?;
+
+    let mut max_voter_weight_record_cookie = quadratic_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    quadratic_voter_test
+        .configure_max_voter_weight_mode(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::Fixed(1_000),
+        )
+        .await?;
+
+    // Assert
+    let registrar = quadratic_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar, registrar_cookie.account);
+
+    let max_voter_weight_record = quadratic_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 1_000);
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_max_voter_weight_mode_with_input_max_voter_weight_mode(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _) = quadratic_voter_test.setup(false).await?;
+
+    let mut max_voter_weight_record_cookie = quadratic_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    quadratic_voter_test
+        .configure_max_voter_weight_mode(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::Fixed(1_000),
+        )
+        .await?;
+
+    // Act
+    quadratic_voter_test
+        .configure_max_voter_weight_mode(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::InputMaxVoterWeight,
+        )
+        .await?;
+
+    // Assert
+    let registrar = quadratic_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar, registrar_cookie.account);
+
+    // The fixed max voter weight must be refreshed using update_max_voter_weight_record before it can be used
+    let max_voter_weight_record = quadratic_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, Some(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_max_voter_weight_mode_with_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _) = quadratic_voter_test.setup(false).await?;
+
+    let mut max_voter_weight_record_cookie = quadratic_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = quadratic_voter_test
+        .configure_max_voter_weight_mode_using_ix(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::Fixed(1_000),
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_quadratic_err(err, QuadraticError::InvalidRealmAuthority);
+
+    Ok(())
+}
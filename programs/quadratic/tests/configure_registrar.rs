@@ -0,0 +1,180 @@
+mod program_test;
+
+use anchor_lang::prelude::ErrorCode;
+use program_test::quadratic_voter_test::QuadraticVoterTest;
+
+use gpl_quadratic::error::QuadraticError;
+use gpl_quadratic::state::QuadraticCoefficients;
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+use crate::program_test::predecessor_plugin_test::PredecessorPluginTest;
+use crate::program_test::tools::{assert_anchor_err, assert_quadratic_err, NopOverride};
+
+#[tokio::test]
+async fn test_configure_registrar_new_quadratic_coefficients() -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _) = quadratic_voter_test.setup(false).await?;
+
+    let quadratic_coefficients = QuadraticCoefficients { a: 3, b: 2, c: 1 };
+
+    // Act
+    quadratic_voter_test
+        .configure_registrar(&mut registrar_cookie, quadratic_coefficients, None)
+        .await?;
+
+    // Assert
+    let registrar = quadratic_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.quadratic_coefficients, quadratic_coefficients);
+    assert_eq!(registrar, registrar_cookie.account);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_registrar_new_previous_plugin() -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _) = quadratic_voter_test.setup(false).await?;
+
+    // Act
+    let predecessor_program_id = PredecessorPluginTest::program_id();
+    quadratic_voter_test
+        .configure_registrar(
+            &mut registrar_cookie,
+            QuadraticCoefficients::default(),
+            Some(predecessor_program_id),
+        )
+        .await?;
+
+    // Assert
+    let registrar = quadratic_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.previous_voter_weight_plugin_program_id,
+        Some(predecessor_program_id)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_registrar_remove_previous_plugin() -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _) = quadratic_voter_test.setup(true).await?;
+
+    // Act
+    quadratic_voter_test
+        .configure_registrar(
+            &mut registrar_cookie,
+            QuadraticCoefficients::default(),
+            None,
+        )
+        .await?;
+
+    // Assert
+    let registrar = quadratic_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.previous_voter_weight_plugin_program_id, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_registrar_with_invalid_realm_authority_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _) = quadratic_voter_test.setup(false).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = quadratic_voter_test
+        .configure_registrar_using_ix(
+            &mut registrar_cookie,
+            QuadraticCoefficients::default(),
+            None,
+            false,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_quadratic_err(err, QuadraticError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_registrar_with_realm_authority_must_sign_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _) = quadratic_voter_test.setup(false).await?;
+
+    // Act
+    let err = quadratic_voter_test
+        .configure_registrar_using_ix(
+            &mut registrar_cookie,
+            QuadraticCoefficients::default(),
+            None,
+            false,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_registrar_with_missing_previous_plugin_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _) = quadratic_voter_test.setup(false).await?;
+
+    // Act
+    let err = quadratic_voter_test
+        .configure_registrar_using_ix(
+            &mut registrar_cookie,
+            QuadraticCoefficients::default(),
+            None,
+            true, // This causes the error
+            NopOverride,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_quadratic_err(err, QuadraticError::MissingPreviousVoterWeightPlugin);
+
+    Ok(())
+}
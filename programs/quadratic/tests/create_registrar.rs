@@ -0,0 +1,168 @@
+mod program_test;
+
+use anchor_lang::prelude::{ErrorCode, Pubkey};
+use program_test::quadratic_voter_test::QuadraticVoterTest;
+
+use gpl_quadratic::error::QuadraticError;
+use gpl_quadratic::state::QuadraticCoefficients;
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, transport::TransportError};
+
+use crate::program_test::predecessor_plugin_test::PredecessorPluginTest;
+use crate::program_test::tools::NopOverride;
+use program_test::tools::{assert_anchor_err, assert_quadratic_err};
+
+#[tokio::test]
+async fn test_create_registrar() -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let realm_cookie = quadratic_voter_test.governance.with_realm().await?;
+
+    // Act
+    let registrar_cookie = quadratic_voter_test
+        .with_registrar(&realm_cookie, QuadraticCoefficients::default(), None)
+        .await?;
+
+    // Assert
+    let registrar = quadratic_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar, registrar_cookie.account);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_registrar_with_previous_plugin() -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let realm_cookie = quadratic_voter_test.governance.with_realm().await?;
+
+    // Act
+    let predecessor_program_id = PredecessorPluginTest::program_id();
+    let registrar_cookie = quadratic_voter_test
+        .with_registrar(
+            &realm_cookie,
+            QuadraticCoefficients { a: 2, b: 1, c: 10 },
+            Some(predecessor_program_id),
+        )
+        .await?;
+
+    // Assert
+    let registrar = quadratic_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar, registrar_cookie.account);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_registrar_with_missing_previous_plugin_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let realm_cookie = quadratic_voter_test.governance.with_realm().await?;
+
+    // Act
+    let err = quadratic_voter_test
+        .with_registrar_using_ix(
+            &realm_cookie,
+            QuadraticCoefficients::default(),
+            None,
+            true, // This causes the error
+            NopOverride,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_quadratic_err(err, QuadraticError::MissingPreviousVoterWeightPlugin);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_registrar_with_invalid_realm_authority_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let mut realm_cookie = quadratic_voter_test.governance.with_realm().await?;
+    realm_cookie.realm_authority = Keypair::new();
+
+    // Act
+    let err = quadratic_voter_test
+        .with_registrar(&realm_cookie, QuadraticCoefficients::default(), None)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_quadratic_err(err, QuadraticError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_registrar_with_realm_authority_must_sign_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let realm_cookie = quadratic_voter_test.governance.with_realm().await?;
+
+    // Act
+    let err = quadratic_voter_test
+        .with_registrar_using_ix(
+            &realm_cookie,
+            QuadraticCoefficients::default(),
+            None,
+            false,
+            |i| i.accounts[4].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_registrar_with_invalid_realm_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let realm_cookie = quadratic_voter_test.governance.with_realm().await?;
+
+    // Act
+    let err = quadratic_voter_test
+        .with_registrar_using_ix(
+            &realm_cookie,
+            QuadraticCoefficients::default(),
+            None,
+            false,
+            |i| i.accounts[2].pubkey = Pubkey::new_unique(), // realm
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+
+    // PDA doesn't match and hence the error is ConstraintSeeds
+    assert_anchor_err(err, ErrorCode::ConstraintSeeds);
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anchor_lang::prelude::Pubkey;
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use gpl_quadratic::state::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+use spl_governance_addin_mock::instruction::*;
+
+use crate::program_test::{
+    governance_test::RealmCookie,
+    program_test_bench::{ProgramTestBench, WalletCookie},
+    quadratic_voter_test::{MaxVoterWeightRecordCookie, VoterWeightRecordCookie},
+};
+use solana_program_test::ProgramTest;
+
+pub struct PredecessorPluginTest {
+    pub bench: Arc<ProgramTestBench>,
+}
+
+impl PredecessorPluginTest {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("GovAddinMock1111111111111111111111111111111").unwrap()
+    }
+
+    #[allow(dead_code)]
+    pub fn add_program(program_test: &mut ProgramTest) {
+        program_test.add_program("spl_governance_addin_mock", Self::program_id(), None);
+    }
+
+    #[allow(dead_code)]
+    pub fn new(bench: Arc<ProgramTestBench>) -> Self {
+        PredecessorPluginTest { bench }
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_voter_weight_record(
+        &self,
+        realm_cookie: &RealmCookie,
+        voter_cookie: &WalletCookie,
+        voter_weight: u64,
+    ) -> Result<VoterWeightRecordCookie, TransportError> {
+        self.with_voter_weight_record_using_expiry(realm_cookie, voter_cookie, voter_weight, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_voter_weight_record_using_expiry(
+        &self,
+        realm_cookie: &RealmCookie,
+        voter_cookie: &WalletCookie,
+        voter_weight: u64,
+        voter_weight_expiry: Option<u64>,
+    ) -> Result<VoterWeightRecordCookie, TransportError> {
+        self.with_voter_weight_record_using_action(
+            realm_cookie,
+            voter_cookie,
+            voter_weight,
+            voter_weight_expiry,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_voter_weight_record_using_action(
+        &self,
+        realm_cookie: &RealmCookie,
+        voter_cookie: &WalletCookie,
+        voter_weight: u64,
+        voter_weight_expiry: Option<u64>,
+        weight_action: Option<VoterWeightAction>,
+    ) -> Result<VoterWeightRecordCookie, TransportError> {
+        let governing_token_owner = voter_cookie.address;
+        let voter_weight_record_account = Keypair::new();
+
+        // VoterWeightAction is redefined by the plugin and it has the same layout as the spl-governance-addin-api enum
+        let addin_weight_action = weight_action.map(|weight_action| {
+            spl_governance_addin_api::voter_weight::VoterWeightAction::try_from_slice(
+                &weight_action.try_to_vec().unwrap(),
+            )
+            .unwrap()
+        });
+
+        let setup_voter_weight_record_ix = setup_voter_weight_record(
+            &Self::program_id(),
+            &realm_cookie.address,
+            &realm_cookie.account.community_mint,
+            &voter_cookie.address,
+            &voter_weight_record_account.pubkey(),
+            &self.bench.payer.pubkey(),
+            voter_weight,
+            voter_weight_expiry,
+            addin_weight_action,
+            None,
+        );
+
+        self.bench
+            .process_transaction(
+                &[setup_voter_weight_record_ix],
+                Some(&[&voter_weight_record_account]),
+            )
+            .await?;
+
+        let account = VoterWeightRecord {
+            realm: realm_cookie.address,
+            governing_token_mint: realm_cookie.account.community_mint,
+            governing_token_owner,
+            voter_weight,
+            voter_weight_expiry,
+            weight_action,
+            weight_action_target: None,
+            reserved: [0; 8],
+        };
+
+        Ok(VoterWeightRecordCookie {
+            address: voter_weight_record_account.pubkey(),
+            account,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_max_voter_weight_record(
+        &self,
+        realm_cookie: &RealmCookie,
+        max_voter_weight: u64,
+        max_voter_weight_expiry: Option<u64>,
+    ) -> Result<MaxVoterWeightRecordCookie, TransportError> {
+        let max_voter_weight_record_account = Keypair::new();
+
+        let setup_max_voter_weight_record_ix = setup_max_voter_weight_record(
+            &Self::program_id(),
+            &realm_cookie.address,
+            &realm_cookie.account.community_mint,
+            &max_voter_weight_record_account.pubkey(),
+            &self.bench.payer.pubkey(),
+            max_voter_weight,
+            max_voter_weight_expiry,
+        );
+
+        self.bench
+            .process_transaction(
+                &[setup_max_voter_weight_record_ix],
+                Some(&[&max_voter_weight_record_account]),
+            )
+            .await?;
+
+        let account = MaxVoterWeightRecord {
+            realm: realm_cookie.address,
+            governing_token_mint: realm_cookie.account.community_mint,
+            max_voter_weight,
+            max_voter_weight_expiry,
+            reserved: [0; 8],
+        };
+
+        Ok(MaxVoterWeightRecordCookie {
+            address: max_voter_weight_record_account.pubkey(),
+            account,
+        })
+    }
+}
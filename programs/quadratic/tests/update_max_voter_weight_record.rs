@@ -0,0 +1,267 @@
+use gpl_quadratic::error::QuadraticError;
+use gpl_quadratic::state::MaxVoterWeightMode;
+use program_test::{quadratic_voter_test::QuadraticVoterTest, tools::*};
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_mint_supply() -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, voter_cookie) =
+        quadratic_voter_test.setup(false).await?;
+
+    // Mint governing tokens to increase the mint supply
+    quadratic_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, 10_000)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = quadratic_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    quadratic_voter_test
+        .configure_max_voter_weight_mode(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::InputMaxVoterWeight,
+        )
+        .await?;
+
+    quadratic_voter_test.bench.advance_clock().await;
+    let clock = quadratic_voter_test.bench.get_clock().await;
+
+    // Act
+    quadratic_voter_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = quadratic_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    // sqrt(10_000)
+    assert_eq!(max_voter_weight_record.max_voter_weight, 100);
+    assert_eq!(
+        max_voter_weight_record.max_voter_weight_expiry,
+        Some(clock.slot)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_predecessor_max_voter_weight_record(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, _) = quadratic_voter_test.setup(true).await?;
+
+    let predecessor_max_voter_weight_record_cookie = quadratic_voter_test
+        .predecessor_plugin
+        .with_max_voter_weight_record(&realm_cookie, 250_000, None)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = quadratic_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    quadratic_voter_test
+        .configure_max_voter_weight_mode(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::InputMaxVoterWeight,
+        )
+        .await?;
+
+    quadratic_voter_test.bench.advance_clock().await;
+
+    // Act
+    quadratic_voter_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            Some(&predecessor_max_voter_weight_record_cookie),
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = quadratic_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    // sqrt(250_000)
+    assert_eq!(max_voter_weight_record.max_voter_weight, 500);
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_missing_predecessor_max_voter_weight_record_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (_, mut registrar_cookie, _) = quadratic_voter_test.setup(true).await?;
+
+    let mut max_voter_weight_record_cookie = quadratic_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    quadratic_voter_test
+        .configure_max_voter_weight_mode(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::InputMaxVoterWeight,
+        )
+        .await?;
+
+    // Act
+    let err = quadratic_voter_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_quadratic_err(err, QuadraticError::MissingPredecessorMaxVoterWeightRecord);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_expired_predecessor_max_voter_weight_record_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, _) = quadratic_voter_test.setup(true).await?;
+
+    let predecessor_max_voter_weight_record_cookie = quadratic_voter_test
+        .predecessor_plugin
+        .with_max_voter_weight_record(&realm_cookie, 250_000, Some(0))
+        .await?;
+
+    let mut max_voter_weight_record_cookie = quadratic_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    quadratic_voter_test
+        .configure_max_voter_weight_mode(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::InputMaxVoterWeight,
+        )
+        .await?;
+
+    quadratic_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = quadratic_voter_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            Some(&predecessor_max_voter_weight_record_cookie),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_quadratic_err(err, QuadraticError::PredecessorMaxVoterWeightRecordExpired);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_fixed_max_voter_weight_mode(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, voter_cookie) =
+        quadratic_voter_test.setup(false).await?;
+
+    // Mint governing tokens to show the mint supply is not used in Fixed mode
+    quadratic_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, 10_000)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = quadratic_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    quadratic_voter_test
+        .configure_max_voter_weight_mode(
+            &mut registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::Fixed(1_000),
+        )
+        .await?;
+
+    quadratic_voter_test.bench.advance_clock().await;
+
+    // Act
+    quadratic_voter_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = quadratic_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 1_000);
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_max_voter_weight_mode_not_configured_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (_, registrar_cookie, _) = quadratic_voter_test.setup(false).await?;
+
+    let mut max_voter_weight_record_cookie = quadratic_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = quadratic_voter_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_quadratic_err(err, QuadraticError::MaxVoterWeightModeNotConfigured);
+
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use gpl_quadratic::error::QuadraticError;
+use gpl_quadratic::state::QuadraticCoefficients;
+use itertools::Either;
+use program_test::{quadratic_voter_test::QuadraticVoterTest, tools::*};
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_token_owner_record() -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, voter_cookie) = quadratic_voter_test.setup(false).await?;
+
+    let voter_token_owner_record_cookie = quadratic_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie, 10_000)
+        .await?;
+
+    let mut voter_weight_record_cookie = quadratic_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    quadratic_voter_test.bench.advance_clock().await;
+    let clock = quadratic_voter_test.bench.get_clock().await;
+
+    // Act
+    quadratic_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Right(&voter_token_owner_record_cookie),
+            &mut voter_weight_record_cookie,
+        )
+        .await?;
+
+    // Assert
+
+    let voter_weight_record = quadratic_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // sqrt(10_000)
+    assert_eq!(voter_weight_record.voter_weight, 100);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+    assert_eq!(voter_weight_record.weight_action, None);
+    assert_eq!(voter_weight_record.weight_action_target, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_predecessor_voter_weight_record(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, voter_cookie) =
+        quadratic_voter_test.setup(true).await?;
+
+    let previous_voter_weight_plugin_program_id = registrar_cookie
+        .account
+        .previous_voter_weight_plugin_program_id;
+
+    quadratic_voter_test
+        .configure_registrar(
+            &mut registrar_cookie,
+            QuadraticCoefficients { a: 2, b: 1, c: 5 },
+            previous_voter_weight_plugin_program_id,
+        )
+        .await?;
+
+    // The voter weight record from the registered predecessor plugin (will give a constant weight)
+    let predecessor_voter_weight_record_cookie = quadratic_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record(&realm_cookie, &voter_cookie, 400)
+        .await?;
+
+    let mut voter_weight_record_cookie = quadratic_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    quadratic_voter_test.bench.advance_clock().await;
+    let clock = quadratic_voter_test.bench.get_clock().await;
+
+    // Act
+    quadratic_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Left(&predecessor_voter_weight_record_cookie),
+            &mut voter_weight_record_cookie,
+        )
+        .await?;
+
+    // Assert
+
+    let voter_weight_record = quadratic_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // 2 * sqrt(400) + 1 * 400 + 5
+    assert_eq!(voter_weight_record.voter_weight, 445);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_expired_predecessor_voter_weight_record_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (realm_cookie, registrar_cookie, voter_cookie) = quadratic_voter_test.setup(true).await?;
+
+    let predecessor_voter_weight_record_cookie = quadratic_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record_using_expiry(&realm_cookie, &voter_cookie, 400, Some(0))
+        .await?;
+
+    let mut voter_weight_record_cookie = quadratic_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    quadratic_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = quadratic_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Left(&predecessor_voter_weight_record_cookie),
+            &mut voter_weight_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_quadratic_err(err, QuadraticError::PredecessorVoterWeightRecordExpired);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_overflow_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut quadratic_voter_test = QuadraticVoterTest::start_new().await;
+
+    let (realm_cookie, mut registrar_cookie, voter_cookie) =
+        quadratic_voter_test.setup(true).await?;
+
+    let previous_voter_weight_plugin_program_id = registrar_cookie
+        .account
+        .previous_voter_weight_plugin_program_id;
+
+    quadratic_voter_test
+        .configure_registrar(
+            &mut registrar_cookie,
+            QuadraticCoefficients { a: 0, b: 2, c: 0 },
+            previous_voter_weight_plugin_program_id,
+        )
+        .await?;
+
+    let predecessor_voter_weight_record_cookie = quadratic_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record(&realm_cookie, &voter_cookie, u64::MAX)
+        .await?;
+
+    let mut voter_weight_record_cookie = quadratic_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = quadratic_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut Either::Left(&predecessor_voter_weight_record_cookie),
+            &mut voter_weight_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_quadratic_err(err, QuadraticError::VoterWeightOverflow);
+
+    Ok(())
+}
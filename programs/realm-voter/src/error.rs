@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use gpl_plugin_core::input_voter_weight::InputVoterWeightError;
 
 #[error_code]
 pub enum RealmVoterError {
@@ -22,4 +23,121 @@ pub enum RealmVoterError {
 
     #[msg("Governing TokenOwner must match")]
     GoverningTokenOwnerMustMatch,
+
+    #[msg(
+        "Max governance programs can't be less than the number of configured governance programs"
+    )]
+    InvalidMaxGovernancePrograms,
+
+    #[msg("Invalid TokenOwner for VoterWeightRecord")]
+    InvalidTokenOwnerForVoterWeightRecord,
+
+    #[msg("VoterWeightRecord must be expired")]
+    VoterWeightRecordMustBeExpired,
+
+    #[msg("Previous voter weight plugin required but not provided")]
+    MissingPreviousVoterWeightPlugin,
+
+    #[msg("Input voter weight account required but not provided")]
+    MissingInputVoterWeight,
+
+    #[msg("Invalid input TokenOwnerRecord")]
+    InvalidInputTokenOwnerRecord,
+
+    #[msg("Invalid predecessor VoterWeightRecord")]
+    InvalidPredecessorVoterWeightRecord,
+
+    #[msg("Invalid predecessor VoterWeightRecord Realm")]
+    InvalidPredecessorVoterWeightRecordRealm,
+
+    #[msg("Invalid predecessor VoterWeightRecord GoverningTokenMint")]
+    InvalidPredecessorVoterWeightRecordGovTokenMint,
+
+    #[msg("Invalid predecessor VoterWeightRecord GoverningTokenOwner")]
+    InvalidPredecessorVoterWeightRecordGovTokenOwner,
+
+    #[msg("Predecessor VoterWeightRecord expired")]
+    PredecessorVoterWeightRecordExpired,
+
+    #[msg("Invalid realm member weight mode")]
+    InvalidRealmMemberWeightMode,
+
+    #[msg("Realm not configured")]
+    RealmNotConfigured,
+
+    #[msg("Max realms can't be less than the number of configured realms")]
+    InvalidMaxRealms,
+
+    #[msg("Governing token mint must be the community or council mint of the Realm")]
+    InvalidRealmGoverningTokenMint,
+
+    #[msg("Governing token mint not allowed for the Realm")]
+    GoverningTokenMintNotAllowed,
+
+    #[msg("TokenOwnerRecord deposit is below the min required for membership")]
+    TokenOwnerRecordDepositBelowMin,
+
+    #[msg("TokenOwnerRecord outstanding proposals are above the max allowed for membership")]
+    TokenOwnerRecordOutstandingProposalsAboveMax,
+
+    #[msg("Duplicated Realm membership detected")]
+    DuplicatedRealmMembershipDetected,
+
+    #[msg("VoterMembership required but not provided")]
+    MissingVoterMembership,
+
+    #[msg("Invalid VoterMembership")]
+    InvalidVoterMembership,
+
+    #[msg("VoterMembership requires a membership with voter weight")]
+    VoterMembershipWithoutVoterWeight,
+
+    #[msg("MemberCount max voter weight mode requires the same voter weight for every member")]
+    MemberCountMaxVoterWeightModeNotSupported,
+
+    #[msg("VoterMembership can only be created when max voter weight mode is MemberCount")]
+    MemberCountMaxVoterWeightModeRequired,
+
+    #[msg("VoterMembership was created before the member count was reset")]
+    VoterMembershipExpired,
+
+    #[msg("VoterMembership can't be closed while the voter is still a member")]
+    VoterMembershipStillValid,
+
+    #[msg("Registrar with counted VoterMemberships can't be closed")]
+    RegistrarHasVoterMemberships,
+
+    #[msg("Registrar can't grow by more than 10KB in a single resize")]
+    RegistrarSizeIncreaseTooLarge,
+
+    #[msg("Membership aggregation requires the Realm allowlist")]
+    MembershipAggregationRequiresRealmAllowlist,
+}
+
+impl From<InputVoterWeightError> for RealmVoterError {
+    fn from(error: InputVoterWeightError) -> Self {
+        match error {
+            InputVoterWeightError::InvalidTokenOwnerRecord => {
+                RealmVoterError::InvalidInputTokenOwnerRecord
+            }
+            // The plugin takes the action and target from the input voter weight and never restricts them
+            InputVoterWeightError::InvalidVoterWeightRecord
+            | InputVoterWeightError::InvalidWeightAction
+            | InputVoterWeightError::InvalidWeightActionTarget => {
+                RealmVoterError::InvalidPredecessorVoterWeightRecord
+            }
+            InputVoterWeightError::InvalidRealm => {
+                RealmVoterError::InvalidPredecessorVoterWeightRecordRealm
+            }
+            InputVoterWeightError::InvalidGoverningTokenMint => {
+                RealmVoterError::InvalidPredecessorVoterWeightRecordGovTokenMint
+            }
+            InputVoterWeightError::InvalidGoverningTokenOwner => {
+                RealmVoterError::InvalidPredecessorVoterWeightRecordGovTokenOwner
+            }
+            InputVoterWeightError::VoterWeightExpired => {
+                RealmVoterError::PredecessorVoterWeightRecordExpired
+            }
+        }
+    }
 }
@@ -0,0 +1,82 @@
+use crate::error::RealmVoterError;
+use crate::state::max_voter_weight_record::MaxVoterWeightRecord;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Closes VoterMembership and removes the voter from Registrar.member_count
+/// The instruction is permissionless and the VoterMembership can be closed by anybody
+/// once its TokenOwnerRecord no longer qualifies for voter weight, for example when the TokenOwnerRecord was closed
+/// or the spl-governance instance or the Realm is no longer configured
+///
+/// VoterMembership created for a previous member_count_epoch isn't counted and it can always be closed
+/// The rent is returned to the governing_token_owner of the VoterMembership
+#[derive(Accounts)]
+pub struct CloseVoterMembership<'info> {
+    /// The Registrar the voter is counted for
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        close = governing_token_owner,
+        constraint = voter_membership.registrar == registrar.key()
+        @ RealmVoterError::InvalidVoterMembership,
+    )]
+    pub voter_membership: Account<'info, VoterMembership>,
+
+    /// The TokenOwnerRecord the voter was counted with
+    /// CHECK: The account can be closed or owned by any program and it's evaluated in the instruction
+    #[account(
+        address = voter_membership.token_owner_record @ RealmVoterError::InvalidVoterMembership
+    )]
+    pub token_owner_record: UncheckedAccount<'info>,
+
+    /// The voter who receives lamports from the closed VoterMembership
+    /// CHECK: Validated against voter_membership.governing_token_owner
+    #[account(
+        mut,
+        address = voter_membership.governing_token_owner @ RealmVoterError::InvalidVoterMembership
+    )]
+    pub governing_token_owner: UncheckedAccount<'info>,
+
+    /// MaxVoterWeightRecord for the given registrar.realm and registrar.governing_token_mint
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ RealmVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ RealmVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+pub fn close_voter_membership(ctx: Context<CloseVoterMembership>) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+    let voter_membership = &ctx.accounts.voter_membership;
+
+    // Expired VoterMemberships were discarded when member_count was reset
+    if voter_membership.member_count_epoch != registrar.member_count_epoch {
+        return Ok(());
+    }
+
+    // The voter is still a member if the TokenOwnerRecord would be given voter weight
+    let is_member = registrar
+        .resolve_realm_member_voter_weight(
+            &voter_membership.governing_token_owner,
+            &ctx.accounts.token_owner_record,
+        )
+        .map_or(false, |(_, realm_member_voter_weight)| {
+            realm_member_voter_weight > 0
+        });
+
+    require!(!is_member, RealmVoterError::VoterMembershipStillValid);
+
+    registrar.member_count = registrar.member_count.checked_sub(1).unwrap();
+
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+    max_voter_weight_record.max_voter_weight = registrar.get_max_voter_weight();
+    max_voter_weight_record.max_voter_weight_expiry = None;
+
+    Ok(())
+}
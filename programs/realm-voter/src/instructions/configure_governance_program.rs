@@ -5,14 +5,15 @@ use anchor_lang::{
 };
 
 use anchor_lang::prelude::*;
-use spl_governance::state::realm;
+use gpl_plugin_core::realm::is_realm_authority;
 
 use crate::error::RealmVoterError;
-use crate::state::{GovernanceProgramConfig, Registrar};
+use crate::state::{GovernanceProgramConfig, RealmMemberWeightMode, Registrar};
 
 /// Creates or updates configuration for spl-governance program instances to define which spl-governance instances can be used to grant governance power
+/// and how the voter weight of the members of their Realms is derived
 #[derive(Accounts)]
-#[instruction(change_type: crate::state::CollectionItemChangeType)]
+#[instruction(change_type: crate::state::CollectionItemChangeType, weight_mode: Option<RealmMemberWeightMode>)]
 pub struct ConfigureGovernanceProgram<'info> {
     /// Registrar which we configure the provided spl-governance instance for
     #[account(mut)]
@@ -36,29 +37,43 @@ pub struct ConfigureGovernanceProgram<'info> {
     pub governance_program_id: UncheckedAccount<'info>,
 }
 
+/// Configures the given spl-governance instance
+/// If weight_mode is not provided then RealmMemberWeightMode::Membership is used
 pub fn configure_governance_program(
     ctx: Context<ConfigureGovernanceProgram>,
     change_type: crate::state::CollectionItemChangeType,
+    weight_mode: Option<RealmMemberWeightMode>,
 ) -> Result<()> {
     let registrar = &mut ctx.accounts.registrar;
+    let weight_mode = weight_mode.unwrap_or_default();
 
-    let realm = realm::get_realm_data_for_governing_token_mint(
-        &registrar.governance_program_id,
-        &ctx.accounts.realm,
-        &registrar.governing_token_mint,
-    )?;
-
-    require_eq!(
-        realm.authority.unwrap(),
-        ctx.accounts.realm_authority.key(),
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
         RealmVoterError::InvalidRealmAuthority
     );
 
+    if let RealmMemberWeightMode::Deposit {
+        multiplier_bps,
+        cap,
+    } = weight_mode
+    {
+        require!(
+            multiplier_bps > 0 && cap > 0,
+            RealmVoterError::InvalidRealmMemberWeightMode
+        );
+    }
+
     let governance_program_id = &ctx.accounts.governance_program_id;
 
     let governance_program_config = GovernanceProgramConfig {
         program_id: governance_program_id.key(),
-        reserved: [0; 8],
+        weight_mode,
+        reserved: [0; 7],
     };
 
     let governance_program_config_idx = registrar
@@ -69,13 +84,12 @@ pub fn configure_governance_program(
     match (change_type, governance_program_config_idx) {
         // Update
         (crate::state::CollectionItemChangeType::Upsert, Some(config_idx)) => {
-            // Note: Update in this version is nop because we only store governance_program_id
             registrar.governance_program_configs[config_idx] = governance_program_config;
         }
         // Insert
         (crate::state::CollectionItemChangeType::Upsert, None) => {
             // Note: In the current version push() would throw an error if we exceed
-            // max_governance_programs specified when the Registrar was created
+            // max_governance_programs specified when the Registrar was created or last resized
             registrar
                 .governance_program_configs
                 .push(governance_program_config);
@@ -89,5 +103,7 @@ pub fn configure_governance_program(
         }
     }
 
+    registrar.assert_max_voter_weight_mode()?;
+
     Ok(())
 }
@@ -0,0 +1,69 @@
+use crate::error::RealmVoterError;
+use crate::state::max_voter_weight_record::MaxVoterWeightRecord;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+/// Configures how the max voter weight is established for Registrar
+/// It also sets MaxVoterWeightRecord.max_voter_weight to the max voter weight for the configured mode
+#[derive(Accounts)]
+pub struct ConfigureMaxVoterWeightMode<'info> {
+    /// The Registrar for the given realm and governing_token_mint
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        address = registrar.realm @ RealmVoterError::InvalidRealmForRegistrar,
+        owner = registrar.governance_program_id
+     )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// MaxVoterWeightRecord for the given registrar.realm and registrar.governing_token_mint
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ RealmVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ RealmVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+/// Configures max_voter_weight_mode
+/// Note: member_count is reset when the mode changes and the existing VoterMemberships are no longer counted
+/// The voters must be counted again using CreateVoterMembership after their expired VoterMemberships are closed
+pub fn configure_max_voter_weight_mode(
+    ctx: Context<ConfigureMaxVoterWeightMode>,
+    max_voter_weight_mode: MaxVoterWeightMode,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        RealmVoterError::InvalidRealmAuthority
+    );
+
+    if registrar.max_voter_weight_mode != max_voter_weight_mode {
+        registrar.member_count = 0;
+        registrar.member_count_epoch = registrar.member_count_epoch.checked_add(1).unwrap();
+    }
+
+    registrar.max_voter_weight_mode = max_voter_weight_mode;
+    registrar.assert_max_voter_weight_mode()?;
+
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+    max_voter_weight_record.max_voter_weight = registrar.get_max_voter_weight();
+    max_voter_weight_record.max_voter_weight_expiry = None;
+
+    Ok(())
+}
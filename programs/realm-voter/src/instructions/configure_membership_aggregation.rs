@@ -0,0 +1,49 @@
+use crate::error::RealmVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+/// Configures how the voter weights of the memberships in several Realms are aggregated
+#[derive(Accounts)]
+pub struct ConfigureMembershipAggregation<'info> {
+    /// The Registrar to be updated
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ RealmVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign the transaction and must match realm.authority
+    pub realm_authority: Signer<'info>,
+}
+
+/// Configures the membership aggregation
+/// Any mode other than MembershipAggregation::Disabled requires the Realm allowlist to be enabled using ConfigureRealm
+/// Note: max_voter_weight is not changed and it should be reconfigured using ConfigureVoterWeights if needed
+pub fn configure_membership_aggregation(
+    ctx: Context<ConfigureMembershipAggregation>,
+    membership_aggregation: MembershipAggregation,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        RealmVoterError::InvalidRealmAuthority
+    );
+
+    registrar.membership_aggregation = membership_aggregation;
+
+    registrar.assert_membership_aggregation()?;
+    registrar.assert_max_voter_weight_mode()?;
+
+    Ok(())
+}
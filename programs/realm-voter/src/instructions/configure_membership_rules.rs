@@ -0,0 +1,45 @@
+use crate::error::RealmVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+/// Configures the rules the source TokenOwnerRecord must satisfy to be granted governance power
+#[derive(Accounts)]
+pub struct ConfigureMembershipRules<'info> {
+    /// The Registrar to be updated
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ RealmVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign the transaction and must match realm.authority
+    pub realm_authority: Signer<'info>,
+}
+
+/// Configures the membership rules
+/// Passing the default rules removes any restriction on the source TokenOwnerRecord
+pub fn configure_membership_rules(
+    ctx: Context<ConfigureMembershipRules>,
+    membership_rules: MembershipRules,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        RealmVoterError::InvalidRealmAuthority
+    );
+
+    registrar.membership_rules = membership_rules;
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use crate::error::RealmVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+/// Configures the previous voter weight plugin the Realm voter plugin is chained with
+/// When the predecessor is configured UpdateVoterWeightRecord takes the predecessor VoterWeightRecord
+/// as the first remaining account and combines its weight with realm_member_voter_weight using membership_weight_mode
+/// Without the predecessor the voter TokenOwnerRecord for the Registrar realm is used as the input voter weight
+///
+/// Note: MaxVoterWeightRecord.max_voter_weight is static and it must be configured to account for the input voter weight
+#[derive(Accounts)]
+#[instruction(use_previous_voter_weight_plugin:bool)]
+pub struct ConfigurePreviousVoterWeightPlugin<'info> {
+    /// The Registrar for the given realm and governing_token_mint
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        address = registrar.realm @ RealmVoterError::InvalidRealmForRegistrar,
+        owner = registrar.governance_program_id
+     )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match realm.authority
+    pub realm_authority: Signer<'info>,
+}
+
+/// Configures the previous voter weight plugin and how its weight is combined with realm_member_voter_weight
+/// If use_previous_voter_weight_plugin is true then the predecessor program id must be provided as the first remaining account
+pub fn configure_previous_voter_weight_plugin(
+    ctx: Context<ConfigurePreviousVoterWeightPlugin>,
+    use_previous_voter_weight_plugin: bool,
+    membership_weight_mode: MembershipWeightMode,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        RealmVoterError::InvalidRealmAuthority
+    );
+
+    // If the plugin has a previous plugin, it "inherits" the vote weight from a voter_weight_record owned
+    // by the previous plugin. This chain is registered here.
+    let remaining_accounts = ctx.remaining_accounts;
+
+    registrar.previous_voter_weight_plugin_program_id = use_previous_voter_weight_plugin
+        .then(|| {
+            remaining_accounts
+                .get(0)
+                .ok_or(RealmVoterError::MissingPreviousVoterWeightPlugin)
+                .map(|account| account.key)
+        })
+        .transpose()?
+        .copied();
+
+    registrar.membership_weight_mode = membership_weight_mode;
+    registrar.assert_max_voter_weight_mode()?;
+
+    Ok(())
+}
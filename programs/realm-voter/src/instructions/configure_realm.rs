@@ -0,0 +1,120 @@
+use anchor_lang::{
+    account,
+    prelude::{Context, Signer},
+    Accounts,
+};
+
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+use spl_governance::state::realm;
+
+use crate::error::RealmVoterError;
+use crate::state::{CollectionItemChangeType, RealmConfig, Registrar};
+
+/// Creates or updates configuration for Realms to restrict governance power to the members of the configured Realms
+/// rather than the members of any Realm created using the configured spl-governance instances
+/// Once the first Realm is configured the restriction stays enabled and removing the last Realm doesn't lift it
+#[derive(Accounts)]
+#[instruction(change_type: CollectionItemChangeType, governing_token_mint: Option<Pubkey>, realm_member_voter_weight: u64)]
+pub struct ConfigureRealm<'info> {
+    /// Registrar which we configure the provided Realm for
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ RealmVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign the transaction and must match realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// Realm which will be inserted, updated or removed to configured Realms whose members are granted governance power
+    /// CHECK: Owned by any of the spl-governance instances specified in registrar.governance_program_configs
+    pub member_realm: UncheckedAccount<'info>,
+}
+
+pub fn configure_realm(
+    ctx: Context<ConfigureRealm>,
+    change_type: CollectionItemChangeType,
+    governing_token_mint: Option<Pubkey>,
+    realm_member_voter_weight: u64,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        RealmVoterError::InvalidRealmAuthority
+    );
+
+    let member_realm = &ctx.accounts.member_realm;
+
+    let realm_config_idx = registrar
+        .realm_configs
+        .iter()
+        .position(|rc| rc.realm == member_realm.key());
+
+    if change_type == CollectionItemChangeType::Remove {
+        let realm_config_idx = realm_config_idx.ok_or(RealmVoterError::RealmNotConfigured)?;
+        registrar.realm_configs.remove(realm_config_idx);
+
+        registrar.assert_membership_aggregation()?;
+
+        return Ok(());
+    }
+
+    // Membership of the Realm the plugin is configured for is not allowed as a source of governance power
+    require_keys_neq!(
+        member_realm.key(),
+        registrar.realm,
+        RealmVoterError::TokenOwnerRecordFromOwnRealmNotAllowed
+    );
+
+    let governance_program_id = *member_realm.owner;
+    registrar.get_governance_program_config(&governance_program_id)?;
+
+    let member_realm_data = realm::get_realm_data(&governance_program_id, member_realm)?;
+
+    if let Some(governing_token_mint) = governing_token_mint {
+        require!(
+            member_realm_data.community_mint == governing_token_mint
+                || member_realm_data.config.council_mint == Some(governing_token_mint),
+            RealmVoterError::InvalidRealmGoverningTokenMint
+        );
+    }
+
+    let realm_config = RealmConfig {
+        realm: member_realm.key(),
+        governance_program_id,
+        governing_token_mint,
+        realm_member_voter_weight,
+        reserved: [0; 8],
+    };
+
+    match realm_config_idx {
+        // Update
+        Some(config_idx) => {
+            registrar.realm_configs[config_idx] = realm_config;
+        }
+        // Insert
+        None => {
+            // Note: In the current version push() would throw an error if we exceed
+            // max_realms specified when the Registrar was created or last resized
+            registrar.realm_configs.push(realm_config);
+        }
+    }
+
+    registrar.realm_allowlist_enabled = true;
+
+    registrar.assert_membership_aggregation()?;
+    registrar.assert_max_voter_weight_mode()?;
+
+    Ok(())
+}
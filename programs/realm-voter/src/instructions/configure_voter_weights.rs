@@ -2,11 +2,12 @@ use crate::error::RealmVoterError;
 use crate::state::max_voter_weight_record::MaxVoterWeightRecord;
 use crate::state::*;
 use anchor_lang::prelude::*;
-use spl_governance::state::realm;
+use gpl_plugin_core::realm::is_realm_authority;
 
 /// Configures realm_member_voter_weight and max_voter_weight for Registrar
 /// It also sets MaxVoterWeightRecord.max_voter_weight to the provided value
-/// MaxVoterWeightRecord.max_voter_weight is static and can only be set using this instruction and hence it never expires
+/// or to the value derived from the member count if max_voter_weight_mode is MemberCount
+/// MaxVoterWeightRecord.max_voter_weight is static and can only be changed by the Registrar configuration instructions and CreateVoterMembership and hence it never expires
 #[derive(Accounts)]
 pub struct ConfigureVoterWeights<'info> {
     /// The Registrar for the given realm and governing_token_mint
@@ -46,22 +47,22 @@ pub fn configure_voter_weights(
     registrar.realm_member_voter_weight = realm_member_voter_weight;
     registrar.max_voter_weight = max_voter_weight;
 
-    let realm = realm::get_realm_data_for_governing_token_mint(
-        &registrar.governance_program_id,
-        &ctx.accounts.realm,
-        &registrar.governing_token_mint,
-    )?;
-
-    require_eq!(
-        realm.authority.unwrap(),
-        ctx.accounts.realm_authority.key(),
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
         RealmVoterError::InvalidRealmAuthority
     );
 
+    registrar.assert_max_voter_weight_mode()?;
+
     let voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
-    voter_weight_record.max_voter_weight = max_voter_weight;
+    voter_weight_record.max_voter_weight = registrar.get_max_voter_weight();
 
-    // max_voter_weight can only be updated using this instruction and it never expires
+    // max_voter_weight can only be updated by the Registrar configuration and it never expires
     voter_weight_record.max_voter_weight_expiry = None;
 
     Ok(())
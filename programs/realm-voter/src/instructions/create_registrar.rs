@@ -2,7 +2,7 @@ use crate::error::RealmVoterError;
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
-use spl_governance::state::realm;
+use gpl_plugin_core::realm::is_realm_authority;
 
 /// Creates Registrar storing Realm Voter configuration for spl-governance Realm
 /// This instruction should only be executed once per realm/governing_token_mint to create the account
@@ -16,7 +16,7 @@ pub struct CreateRegistrar<'info> {
         seeds = [b"registrar".as_ref(),realm.key().as_ref(), governing_token_mint.key().as_ref()],
         bump,
         payer = payer,
-        space = Registrar::get_space(max_governance_programs)
+        space = Registrar::get_space(max_governance_programs, 0)
     )]
     pub registrar: Account<'info, Registrar>,
 
@@ -57,7 +57,8 @@ pub struct CreateRegistrar<'info> {
 /// used for governance
 ///
 /// max_governance_programs is used to allocate account size for the maximum number of configured spl-governance instances
-/// Note: Once Solana runtime supports account resizing the max value won't be required
+/// The Registrar is created without space for configured Realms and ResizeRegistrar must be used to allocate it
+/// Note: The Registrar can be resized later using ResizeRegistrar if more spl-governance instances or Realms are needed
 pub fn create_registrar(ctx: Context<CreateRegistrar>, _max_governance_programs: u8) -> Result<()> {
     let registrar = &mut ctx.accounts.registrar;
     registrar.governance_program_id = ctx.accounts.governance_program_id.key();
@@ -66,15 +67,13 @@ pub fn create_registrar(ctx: Context<CreateRegistrar>, _max_governance_programs:
 
     // Verify that realm_authority is the expected authority of the Realm
     // and that the mint matches one of the realm mints too
-    let realm = realm::get_realm_data_for_governing_token_mint(
-        &registrar.governance_program_id,
-        &ctx.accounts.realm,
-        &registrar.governing_token_mint,
-    )?;
-
-    require_eq!(
-        realm.authority.unwrap(),
-        ctx.accounts.realm_authority.key(),
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
         RealmVoterError::InvalidRealmAuthority
     );
 
@@ -0,0 +1,102 @@
+use crate::error::RealmVoterError;
+use crate::state::max_voter_weight_record::MaxVoterWeightRecord;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Creates VoterMembership which counts the voter towards Registrar.member_count
+/// This instruction should only be executed once per registrar/governing_token_owner to create the account
+/// and it's expected to be executed on the first use by the voter before UpdateVoterWeightRecord
+/// The voter can only be counted when max_voter_weight_mode is MemberCount
+/// and the governing_token_owner must sign the instruction to prevent inflating member_count on behalf of others
+#[derive(Accounts)]
+pub struct CreateVoterMembership<'info> {
+    /// The Registrar the voter is counted for
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        init,
+        seeds = [ b"voter-membership".as_ref(),
+                registrar.key().as_ref(),
+                voter_weight_record.governing_token_owner.as_ref()],
+        bump,
+        payer = payer,
+        space = VoterMembership::get_space()
+    )]
+    pub voter_membership: Account<'info, VoterMembership>,
+
+    /// VoterWeightRecord of the voter
+    #[account(
+        constraint = voter_weight_record.realm == registrar.realm
+        @ RealmVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ RealmVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    /// TokenOwnerRecord of the voter for any of the configured spl-governance instances
+    /// It proves the voter is a member who would be given voter weight
+    /// CHECK: Owned by any of the spl-governance instances specified in registrar.governance_program_configs
+    pub token_owner_record: UncheckedAccount<'info>,
+
+    /// MaxVoterWeightRecord for the given registrar.realm and registrar.governing_token_mint
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ RealmVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ RealmVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+
+    /// The voter who is counted must sign the instruction
+    #[account(
+        address = voter_weight_record.governing_token_owner
+        @ RealmVoterError::GoverningTokenOwnerMustMatch
+    )]
+    pub governing_token_owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_voter_membership(ctx: Context<CreateVoterMembership>) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+    let governing_token_owner = ctx.accounts.voter_weight_record.governing_token_owner;
+
+    require!(
+        registrar.max_voter_weight_mode == MaxVoterWeightMode::MemberCount,
+        RealmVoterError::MemberCountMaxVoterWeightModeRequired
+    );
+
+    // Only the voters who would be given voter weight are counted
+    let (_, realm_member_voter_weight) = registrar.resolve_realm_member_voter_weight(
+        &governing_token_owner,
+        &ctx.accounts.token_owner_record,
+    )?;
+
+    require_gt!(
+        realm_member_voter_weight,
+        0,
+        RealmVoterError::VoterMembershipWithoutVoterWeight
+    );
+
+    let voter_membership = &mut ctx.accounts.voter_membership;
+    voter_membership.registrar = registrar.key();
+    voter_membership.governing_token_owner = governing_token_owner;
+    voter_membership.token_owner_record = ctx.accounts.token_owner_record.key();
+    voter_membership.member_count_epoch = registrar.member_count_epoch;
+
+    registrar.member_count = registrar.member_count.checked_add(1).unwrap();
+
+    // The max voter weight grows with the member count and it never expires
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+    max_voter_weight_record.max_voter_weight = registrar.get_max_voter_weight();
+    max_voter_weight_record.max_voter_weight_expiry = None;
+
+    Ok(())
+}
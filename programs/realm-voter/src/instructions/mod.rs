@@ -1,17 +1,50 @@
 pub use configure_governance_program::*;
 mod configure_governance_program;
 
+pub use configure_realm::*;
+mod configure_realm;
+
 pub use create_registrar::*;
 mod create_registrar;
 
+pub use resize_registrar::*;
+mod resize_registrar;
+
+pub use close_registrar::*;
+mod close_registrar;
+
 pub use create_voter_weight_record::*;
 mod create_voter_weight_record;
 
+pub use close_voter_weight_record::*;
+mod close_voter_weight_record;
+
 pub use create_max_voter_weight_record::*;
 mod create_max_voter_weight_record;
 
+pub use close_max_voter_weight_record::*;
+mod close_max_voter_weight_record;
+
+pub use create_voter_membership::*;
+mod create_voter_membership;
+
+pub use close_voter_membership::*;
+mod close_voter_membership;
+
 pub use update_voter_weight_record::*;
 mod update_voter_weight_record;
 
 pub use configure_voter_weights::*;
 mod configure_voter_weights;
+
+pub use configure_max_voter_weight_mode::*;
+mod configure_max_voter_weight_mode;
+
+pub use configure_membership_aggregation::*;
+mod configure_membership_aggregation;
+
+pub use configure_membership_rules::*;
+mod configure_membership_rules;
+
+pub use configure_previous_voter_weight_plugin::*;
+mod configure_previous_voter_weight_plugin;
@@ -0,0 +1,74 @@
+use crate::error::RealmVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+use solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+
+/// Resizes Registrar to allow a different number of spl-governance instances and Realms to be configured
+/// The rent difference is paid by the payer when the account grows
+///
+/// Solana limits the account growth to MAX_PERMITTED_DATA_INCREASE (10KB) per instruction
+/// and the capacity must be increased using several ResizeRegistrar instructions if it exceeds the limit
+#[derive(Accounts)]
+#[instruction(max_governance_programs: u8, max_realms: u8)]
+pub struct ResizeRegistrar<'info> {
+    /// The Realm Voter Registrar
+    #[account(
+        mut,
+        constraint = Registrar::get_space(max_governance_programs, max_realms)
+            <= registrar.to_account_info().data_len() + MAX_PERMITTED_DATA_INCREASE
+            @ RealmVoterError::RegistrarSizeIncreaseTooLarge,
+        realloc = Registrar::get_space(max_governance_programs, max_realms),
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        address = registrar.realm @ RealmVoterError::InvalidRealmForRegistrar,
+        owner = registrar.governance_program_id
+     )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match realm.authority
+    pub realm_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Resizes Registrar to the space required for max_governance_programs and max_realms
+/// max_governance_programs can't be less than the number of currently configured spl-governance instances
+/// and max_realms can't be less than the number of currently configured Realms
+pub fn resize_registrar(
+    ctx: Context<ResizeRegistrar>,
+    max_governance_programs: u8,
+    max_realms: u8,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        RealmVoterError::InvalidRealmAuthority
+    );
+
+    require!(
+        registrar.governance_program_configs.len() <= max_governance_programs as usize,
+        RealmVoterError::InvalidMaxGovernancePrograms
+    );
+
+    require!(
+        registrar.realm_configs.len() <= max_realms as usize,
+        RealmVoterError::InvalidMaxRealms
+    );
+
+    Ok(())
+}
@@ -1,13 +1,24 @@
 use crate::error::RealmVoterError;
 use crate::state::*;
 use anchor_lang::prelude::*;
-use spl_governance::state::token_owner_record;
+use gpl_plugin_core::generic_voter_weight::GenericVoterWeight;
 
 /// Updates VoterWeightRecord based on Realm DAO membership
 /// The membership is evaluated via a valid TokenOwnerRecord which must belong to one of the configured spl-governance instances
+/// The membership voter weight is defined by the weight_mode of the spl-governance instance config
 ///
 /// This instruction sets VoterWeightRecord.voter_weight which is valid for the current slot only
 /// and must be executed inside the same transaction as the corresponding spl-gov instruction
+///
+/// The input voter weight is provided as the first remaining account when the Registrar has a predecessor plugin
+/// or membership_weight_mode is Add. It's the predecessor VoterWeightRecord if the predecessor is configured
+/// and the voter TokenOwnerRecord for the Registrar realm otherwise
+/// The weight_action, weight_action_target and voter_weight_expiry of the input voter weight are carried forward
+///
+/// If max_voter_weight_mode is MemberCount then the voter VoterMembership must be provided as the next remaining account
+///
+/// If membership_aggregation is enabled then TokenOwnerRecords for the voter memberships in other Realms
+/// can be provided as the last remaining accounts and their voter weights are aggregated
 #[derive(Accounts)]
 pub struct UpdateVoterWeightRecord<'info> {
     /// The RealmVoter voting Registrar
@@ -28,50 +39,103 @@ pub struct UpdateVoterWeightRecord<'info> {
     pub token_owner_record: UncheckedAccount<'info>,
 }
 
-pub fn update_voter_weight_record(ctx: Context<UpdateVoterWeightRecord>) -> Result<()> {
+pub fn update_voter_weight_record<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, UpdateVoterWeightRecord<'info>>,
+) -> Result<()> {
     let registrar = &ctx.accounts.registrar;
     let voter_weight_record = &mut ctx.accounts.voter_weight_record;
 
-    let governance_program_id = ctx.accounts.token_owner_record.owner;
-
-    // Note: We only verify a valid TokenOwnerRecord account exists for one of the configured spl-governance instances
-    // The existence of the account proofs the governing_token_owner has interacted with spl-governance Realm at least once in the past
-    if !registrar
-        .governance_program_configs
-        .iter()
-        .any(|cc| cc.program_id == governance_program_id.key())
-    {
-        return err!(RealmVoterError::GovernanceProgramNotConfigured);
-    };
+    let governing_token_owner = voter_weight_record.governing_token_owner;
 
-    let token_owner_record = token_owner_record::get_token_owner_record_data(
-        governance_program_id,
+    let (realm, mut realm_member_voter_weight) = registrar.resolve_realm_member_voter_weight(
+        &governing_token_owner,
         &ctx.accounts.token_owner_record,
     )?;
 
-    // Ensure VoterWeightRecord and TokenOwnerRecord are for the same governing_token_owner
-    require_eq!(
-        token_owner_record.governing_token_owner,
-        voter_weight_record.governing_token_owner,
-        RealmVoterError::GoverningTokenOwnerMustMatch
-    );
+    let current_slot = Clock::get()?.slot;
 
-    // Membership of the Realm the plugin is configured for is not allowed as a source of governance power
-    require_neq!(
-        token_owner_record.realm,
-        registrar.realm,
-        RealmVoterError::TokenOwnerRecordFromOwnRealmNotAllowed
-    );
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+
+    let input_voter_weight = if registrar.requires_input_voter_weight() {
+        let input_voter_weight_info = remaining_accounts
+            .next()
+            .ok_or(RealmVoterError::MissingInputVoterWeight)?;
+
+        Some(resolve_input_voter_weight(
+            registrar,
+            voter_weight_record,
+            input_voter_weight_info,
+            current_slot,
+        )?)
+    } else {
+        None
+    };
+
+    // The voter must be counted towards the member count if the max voter weight is derived from it
+    if registrar.max_voter_weight_mode == MaxVoterWeightMode::MemberCount {
+        let voter_membership_info = remaining_accounts
+            .next()
+            .ok_or(RealmVoterError::MissingVoterMembership)?;
+
+        assert_voter_membership(
+            voter_membership_info,
+            &registrar.key(),
+            &governing_token_owner,
+            registrar.member_count_epoch,
+        )?;
+    }
+
+    // Aggregate the memberships in other Realms provided after the input voter weight and VoterMembership accounts
+    if registrar.membership_aggregation != MembershipAggregation::Disabled {
+        let mut realms = vec![realm];
+
+        for token_owner_record_info in remaining_accounts {
+            let (realm, voter_weight) = registrar.resolve_realm_member_voter_weight(
+                &governing_token_owner,
+                token_owner_record_info,
+            )?;
+
+            // A single Realm membership can only be counted once
+            require!(
+                !realms.contains(&realm),
+                RealmVoterError::DuplicatedRealmMembershipDetected
+            );
+            realms.push(realm);
+
+            realm_member_voter_weight =
+                realm_member_voter_weight.checked_add(voter_weight).unwrap();
+        }
+
+        realm_member_voter_weight = registrar
+            .membership_aggregation
+            .get_aggregated_voter_weight(realm_member_voter_weight);
+    }
 
     // Setup voter_weight
-    voter_weight_record.voter_weight = registrar.realm_member_voter_weight;
+    voter_weight_record.voter_weight = registrar
+        .get_voter_weight_with_input(realm_member_voter_weight, input_voter_weight.as_ref());
 
-    // Record is only valid as of the current slot
-    voter_weight_record.voter_weight_expiry = Some(Clock::get()?.slot);
+    // Record is only valid as of the current slot and it can't outlive the input voter weight
+    voter_weight_record.voter_weight_expiry = get_voter_weight_expiry_with_input(
+        current_slot,
+        input_voter_weight
+            .as_ref()
+            .and_then(|input| input.get_voter_weight_expiry()),
+    );
 
-    // Set action and target to None to indicate the weight is valid for any action and target
-    voter_weight_record.weight_action = None;
-    voter_weight_record.weight_action_target = None;
+    // Carry forward action and target of the input voter weight
+    // None indicates the weight is valid for any action and target
+    match input_voter_weight {
+        Some(input_voter_weight) => {
+            voter_weight_record.weight_action = input_voter_weight.get_weight_action();
+            voter_weight_record.weight_action_target =
+                input_voter_weight.get_weight_action_target();
+        }
+        None => {
+            voter_weight_record.weight_action = None;
+            voter_weight_record.weight_action_target = None;
+        }
+    }
 
     Ok(())
 }
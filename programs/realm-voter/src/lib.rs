@@ -23,6 +23,18 @@ pub mod realm_voter {
         log_version();
         instructions::create_registrar(ctx, max_governance_programs)
     }
+    pub fn resize_registrar(
+        ctx: Context<ResizeRegistrar>,
+        max_governance_programs: u8,
+        max_realms: u8,
+    ) -> Result<()> {
+        log_version();
+        instructions::resize_registrar(ctx, max_governance_programs, max_realms)
+    }
+    pub fn close_registrar(ctx: Context<CloseRegistrar>) -> Result<()> {
+        log_version();
+        instructions::close_registrar(ctx)
+    }
     pub fn create_voter_weight_record(
         ctx: Context<CreateVoterWeightRecord>,
         governing_token_owner: Pubkey,
@@ -30,11 +42,29 @@ pub mod realm_voter {
         log_version();
         instructions::create_voter_weight_record(ctx, governing_token_owner)
     }
+    pub fn close_voter_weight_record(ctx: Context<CloseVoterWeightRecord>) -> Result<()> {
+        log_version();
+        instructions::close_voter_weight_record(ctx)
+    }
     pub fn create_max_voter_weight_record(ctx: Context<CreateMaxVoterWeightRecord>) -> Result<()> {
         log_version();
         instructions::create_max_voter_weight_record(ctx)
     }
-    pub fn update_voter_weight_record(ctx: Context<UpdateVoterWeightRecord>) -> Result<()> {
+    pub fn close_max_voter_weight_record(ctx: Context<CloseMaxVoterWeightRecord>) -> Result<()> {
+        log_version();
+        instructions::close_max_voter_weight_record(ctx)
+    }
+    pub fn create_voter_membership(ctx: Context<CreateVoterMembership>) -> Result<()> {
+        log_version();
+        instructions::create_voter_membership(ctx)
+    }
+    pub fn close_voter_membership(ctx: Context<CloseVoterMembership>) -> Result<()> {
+        log_version();
+        instructions::close_voter_membership(ctx)
+    }
+    pub fn update_voter_weight_record<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, UpdateVoterWeightRecord<'info>>,
+    ) -> Result<()> {
         log_version();
         instructions::update_voter_weight_record(ctx)
     }
@@ -50,9 +80,62 @@ pub mod realm_voter {
     pub fn configure_governance_program(
         ctx: Context<ConfigureGovernanceProgram>,
         change_type: crate::state::CollectionItemChangeType,
+        weight_mode: Option<crate::state::RealmMemberWeightMode>,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_governance_program(ctx, change_type, weight_mode)
+    }
+
+    pub fn configure_realm(
+        ctx: Context<ConfigureRealm>,
+        change_type: crate::state::CollectionItemChangeType,
+        governing_token_mint: Option<Pubkey>,
+        realm_member_voter_weight: u64,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_realm(
+            ctx,
+            change_type,
+            governing_token_mint,
+            realm_member_voter_weight,
+        )
+    }
+
+    pub fn configure_membership_rules(
+        ctx: Context<ConfigureMembershipRules>,
+        membership_rules: crate::state::MembershipRules,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_membership_rules(ctx, membership_rules)
+    }
+
+    pub fn configure_max_voter_weight_mode(
+        ctx: Context<ConfigureMaxVoterWeightMode>,
+        max_voter_weight_mode: crate::state::MaxVoterWeightMode,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_max_voter_weight_mode(ctx, max_voter_weight_mode)
+    }
+
+    pub fn configure_membership_aggregation(
+        ctx: Context<ConfigureMembershipAggregation>,
+        membership_aggregation: crate::state::MembershipAggregation,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_membership_aggregation(ctx, membership_aggregation)
+    }
+
+    pub fn configure_previous_voter_weight_plugin(
+        ctx: Context<ConfigurePreviousVoterWeightPlugin>,
+        use_previous_voter_weight_plugin: bool,
+        membership_weight_mode: crate::state::MembershipWeightMode,
     ) -> Result<()> {
         log_version();
-        instructions::configure_governance_program(ctx, change_type)
+        instructions::configure_previous_voter_weight_plugin(
+            ctx,
+            use_previous_voter_weight_plugin,
+            membership_weight_mode,
+        )
     }
 }
 
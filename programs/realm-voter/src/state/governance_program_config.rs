@@ -1,4 +1,56 @@
 use anchor_lang::prelude::*;
+use std::cmp::min;
+
+/// The scale of the deposit weight multiplier expressed in basis points
+pub const DEPOSIT_WEIGHT_MULTIPLIER_BPS_SCALE: u64 = 10_000;
+
+/// Defines how the voter weight of a member of a Realm from the configured spl-governance instance is derived
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum RealmMemberWeightMode {
+    /// Every member of the Realm is given Registrar.realm_member_voter_weight
+    Membership,
+
+    /// The member is given the voter weight scaled from TokenOwnerRecord.governing_token_deposit_amount
+    /// voter_weight = min(governing_token_deposit_amount * multiplier_bps / 10_000, cap)
+    ///
+    /// Note: The deposit is expressed in the units of the partner Realm governing token mint
+    /// and the multiplier must account for the difference with the units of the Registrar governing_token_mint
+    Deposit {
+        /// The multiplier applied to the deposit expressed in basis points
+        multiplier_bps: u64,
+
+        /// The max voter weight a single member can be given
+        cap: u64,
+    },
+}
+
+impl Default for RealmMemberWeightMode {
+    fn default() -> Self {
+        RealmMemberWeightMode::Membership
+    }
+}
+
+impl RealmMemberWeightMode {
+    /// Returns the voter weight of a Realm member with the given TokenOwnerRecord deposit
+    pub fn get_realm_member_voter_weight(
+        &self,
+        realm_member_voter_weight: u64,
+        governing_token_deposit_amount: u64,
+    ) -> u64 {
+        match self {
+            RealmMemberWeightMode::Membership => realm_member_voter_weight,
+            RealmMemberWeightMode::Deposit {
+                multiplier_bps,
+                cap,
+            } => {
+                let voter_weight = governing_token_deposit_amount as u128 * *multiplier_bps as u128
+                    / DEPOSIT_WEIGHT_MULTIPLIER_BPS_SCALE as u128;
+
+                min(voter_weight, *cap as u128) as u64
+            }
+        }
+    }
+}
 
 /// Configuration of an spl-governance instance used to grant governance power
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Default)]
@@ -6,6 +58,72 @@ pub struct GovernanceProgramConfig {
     /// The program id of the configured spl-governance instance
     pub program_id: Pubkey,
 
+    /// Defines how the voter weight of the members of the spl-governance instance Realms is derived
+    /// Note: Membership mode takes the single byte of the original reserved space
+    /// and configs created before the mode was introduced are read as Membership
+    /// Registrars created before the mode was introduced must be resized to fit Deposit configs
+    pub weight_mode: RealmMemberWeightMode,
+
     /// Reserved for future upgrades
-    pub reserved: [u8; 8],
+    pub reserved: [u8; 7],
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_realm_member_voter_weight_with_membership() {
+        // Arrange
+        let weight_mode = RealmMemberWeightMode::Membership;
+
+        // Act
+        let voter_weight = weight_mode.get_realm_member_voter_weight(10, 1_000);
+
+        // Assert
+        assert_eq!(voter_weight, 10);
+    }
+
+    #[test]
+    fn test_get_realm_member_voter_weight_with_deposit() {
+        // Arrange
+        let weight_mode = RealmMemberWeightMode::Deposit {
+            multiplier_bps: 15_000,
+            cap: 1_000,
+        };
+
+        // Act
+        let voter_weight = weight_mode.get_realm_member_voter_weight(10, 100);
+
+        // Assert
+        assert_eq!(voter_weight, 150);
+    }
+
+    #[test]
+    fn test_get_realm_member_voter_weight_with_deposit_capped() {
+        // Arrange
+        let weight_mode = RealmMemberWeightMode::Deposit {
+            multiplier_bps: u64::MAX,
+            cap: 1_000,
+        };
+
+        // Act
+        let voter_weight = weight_mode.get_realm_member_voter_weight(10, u64::MAX);
+
+        // Assert
+        assert_eq!(voter_weight, 1_000);
+    }
+
+    #[test]
+    fn test_membership_config_keeps_original_layout() {
+        // Arrange
+        let config = GovernanceProgramConfig::default();
+
+        // Act
+        let space = config.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(space, 32 + 8);
+    }
 }
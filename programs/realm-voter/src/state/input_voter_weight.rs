@@ -0,0 +1,87 @@
+use crate::error::RealmVoterError;
+use crate::state::{Registrar, VoterWeightRecord};
+use anchor_lang::prelude::*;
+use gpl_plugin_core::generic_voter_weight::{GenericVoterWeight, GenericVoterWeightEnum};
+use gpl_plugin_core::input_voter_weight::InputVoterWeightScope;
+use std::cmp::min;
+
+/// Defines how the Realm membership voter weight is combined with the input voter weight
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum MembershipWeightMode {
+    /// The membership voter weight overrides the input voter weight
+    /// The predecessor plugin, if configured, still gates the action through its weight_action, weight_action_target and expiry
+    Override,
+
+    /// The membership voter weight is added to the input voter weight
+    Add,
+}
+
+impl Default for MembershipWeightMode {
+    fn default() -> Self {
+        MembershipWeightMode::Override
+    }
+}
+
+impl Registrar {
+    /// Returns true if the input voter weight account must be provided to update VoterWeightRecord
+    pub fn requires_input_voter_weight(&self) -> bool {
+        self.previous_voter_weight_plugin_program_id.is_some()
+            || self.membership_weight_mode == MembershipWeightMode::Add
+    }
+
+    /// Returns the given membership voter weight combined with the input voter weight according to membership_weight_mode
+    pub fn get_voter_weight_with_input(
+        &self,
+        realm_member_voter_weight: u64,
+        input_voter_weight: Option<&GenericVoterWeightEnum>,
+    ) -> u64 {
+        match input_voter_weight {
+            Some(input_voter_weight)
+                if self.membership_weight_mode == MembershipWeightMode::Add =>
+            {
+                realm_member_voter_weight
+                    .checked_add(input_voter_weight.get_voter_weight())
+                    .unwrap()
+            }
+            _ => realm_member_voter_weight,
+        }
+    }
+}
+
+/// Resolves the input voter weight for the given VoterWeightRecord
+/// If the Registrar has a predecessor plugin then the input account must be the predecessor VoterWeightRecord
+/// Otherwise the input account must be the voter TokenOwnerRecord for the Registrar realm and governing_token_mint
+///
+/// The input voter weight can't be expired and its action and target are carried forward by the caller
+pub fn resolve_input_voter_weight(
+    registrar: &Registrar,
+    voter_weight_record: &VoterWeightRecord,
+    input_voter_weight_info: &AccountInfo,
+    current_slot: u64,
+) -> Result<GenericVoterWeightEnum> {
+    let input_voter_weight = gpl_plugin_core::input_voter_weight::resolve_input_voter_weight(
+        input_voter_weight_info,
+        &InputVoterWeightScope {
+            governance_program_id: registrar.governance_program_id,
+            previous_voter_weight_plugin_program_id: registrar
+                .previous_voter_weight_plugin_program_id,
+            realm: registrar.realm,
+            governing_token_mint: registrar.governing_token_mint,
+            governing_token_owner: voter_weight_record.governing_token_owner,
+            weight_action: None,
+            weight_action_target: None,
+            current_slot,
+        },
+    )
+    .map_err(RealmVoterError::from)?;
+
+    Ok(input_voter_weight)
+}
+
+/// Returns the expiry of the combined voter weight which can't outlive the input voter weight
+pub fn get_voter_weight_expiry_with_input(
+    current_slot: u64,
+    input_voter_weight_expiry: Option<u64>,
+) -> Option<u64> {
+    Some(input_voter_weight_expiry.map_or(current_slot, |expiry| min(expiry, current_slot)))
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+/// Defines how the max voter weight is established
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum MaxVoterWeightMode {
+    /// The max voter weight is Registrar.max_voter_weight set manually using ConfigureVoterWeights
+    Manual,
+
+    /// The max voter weight is Registrar.member_count * Registrar.realm_member_voter_weight
+    /// The member count is the number of distinct voters with VoterMembership
+    ///
+    /// Note: The mode assumes every voter is given realm_member_voter_weight
+    /// and it can't be configured together with deposit based weights, Realm specific weights or aggregated memberships
+    /// The mode requires the Realm allowlist to be enabled using ConfigureRealm
+    MemberCount,
+}
+
+impl Default for MaxVoterWeightMode {
+    fn default() -> Self {
+        MaxVoterWeightMode::Manual
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use std::cmp::min;
+
+/// Defines how the voter weights of the memberships in several Realms are aggregated
+/// The additional TokenOwnerRecords are provided as remaining accounts after the input voter weight account
+///
+/// Note: The aggregated voter weight of a single voter can exceed the membership voter weight of a single Realm
+/// and Registrar.max_voter_weight should account for the expected number of memberships per voter
+/// Aggregation can only be enabled together with the Realm allowlist because anybody can create Realms
+/// for the configured spl-governance instances and farm memberships in them
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum MembershipAggregation {
+    /// Only the membership of the token_owner_record Realm is used and any additional TokenOwnerRecords are ignored
+    Disabled,
+
+    /// The voter weights of the memberships are summed
+    Sum,
+
+    /// The voter weights of the memberships are summed and the sum is capped at the given voter weight
+    CappedSum(u64),
+}
+
+impl Default for MembershipAggregation {
+    fn default() -> Self {
+        MembershipAggregation::Disabled
+    }
+}
+
+impl MembershipAggregation {
+    /// Returns the aggregated voter weight for the summed voter weight of the memberships
+    pub fn get_aggregated_voter_weight(&self, voter_weight_sum: u64) -> u64 {
+        match self {
+            MembershipAggregation::CappedSum(cap) => min(voter_weight_sum, *cap),
+            MembershipAggregation::Disabled | MembershipAggregation::Sum => voter_weight_sum,
+        }
+    }
+}
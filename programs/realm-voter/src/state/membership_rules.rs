@@ -0,0 +1,119 @@
+use crate::error::RealmVoterError;
+use anchor_lang::prelude::*;
+use spl_governance::state::token_owner_record::TokenOwnerRecordV2;
+
+/// Rules the source TokenOwnerRecord must satisfy to be granted governance power
+/// The rules raise the bar for sybil membership created right before voting
+/// The default rules don't restrict the membership
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct MembershipRules {
+    /// The min governing_token_deposit_amount of the TokenOwnerRecord
+    /// It's expressed in the units of the source Realm governing token mint
+    pub min_governing_token_deposit_amount: u64,
+
+    /// The max outstanding_proposal_count of the TokenOwnerRecord
+    /// If None then the outstanding proposals are not restricted
+    pub max_outstanding_proposal_count: Option<u8>,
+}
+
+impl MembershipRules {
+    /// Asserts the given TokenOwnerRecord satisfies the membership rules
+    pub fn assert_token_owner_record(&self, token_owner_record: &TokenOwnerRecordV2) -> Result<()> {
+        require_gte!(
+            token_owner_record.governing_token_deposit_amount,
+            self.min_governing_token_deposit_amount,
+            RealmVoterError::TokenOwnerRecordDepositBelowMin
+        );
+
+        if let Some(max_outstanding_proposal_count) = self.max_outstanding_proposal_count {
+            require_gte!(
+                max_outstanding_proposal_count,
+                token_owner_record.outstanding_proposal_count,
+                RealmVoterError::TokenOwnerRecordOutstandingProposalsAboveMax
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use spl_governance::state::enums::GovernanceAccountType;
+    use spl_governance::state::token_owner_record::TOKEN_OWNER_RECORD_LAYOUT_VERSION;
+
+    fn create_token_owner_record(
+        governing_token_deposit_amount: u64,
+        outstanding_proposal_count: u8,
+    ) -> TokenOwnerRecordV2 {
+        TokenOwnerRecordV2 {
+            account_type: GovernanceAccountType::TokenOwnerRecordV2,
+            realm: Pubkey::new_unique(),
+            governing_token_mint: Pubkey::new_unique(),
+            governing_token_owner: Pubkey::new_unique(),
+            governing_token_deposit_amount,
+            unrelinquished_votes_count: 0,
+            outstanding_proposal_count,
+            version: TOKEN_OWNER_RECORD_LAYOUT_VERSION,
+            reserved: [0; 6],
+            governance_delegate: None,
+            reserved_v2: [0; 128],
+        }
+    }
+
+    #[test]
+    fn test_assert_token_owner_record_with_default_rules() {
+        // Arrange
+        let membership_rules = MembershipRules::default();
+        let token_owner_record = create_token_owner_record(0, 10);
+
+        // Act
+        let result = membership_rules.assert_token_owner_record(&token_owner_record);
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_assert_token_owner_record_with_deposit_below_min_error() {
+        // Arrange
+        let membership_rules = MembershipRules {
+            min_governing_token_deposit_amount: 100,
+            max_outstanding_proposal_count: None,
+        };
+        let token_owner_record = create_token_owner_record(99, 0);
+
+        // Act
+        let err = membership_rules
+            .assert_token_owner_record(&token_owner_record)
+            .err()
+            .unwrap();
+
+        // Assert
+        assert_eq!(err, RealmVoterError::TokenOwnerRecordDepositBelowMin.into());
+    }
+
+    #[test]
+    fn test_assert_token_owner_record_with_outstanding_proposals_above_max_error() {
+        // Arrange
+        let membership_rules = MembershipRules {
+            min_governing_token_deposit_amount: 100,
+            max_outstanding_proposal_count: Some(1),
+        };
+        let token_owner_record = create_token_owner_record(100, 2);
+
+        // Act
+        let err = membership_rules
+            .assert_token_owner_record(&token_owner_record)
+            .err()
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            err,
+            RealmVoterError::TokenOwnerRecordOutstandingProposalsAboveMax.into()
+        );
+    }
+}
@@ -4,7 +4,25 @@ pub mod registrar;
 pub use governance_program_config::*;
 pub mod governance_program_config;
 
+pub use membership_aggregation::*;
+pub mod membership_aggregation;
+
+pub use membership_rules::*;
+pub mod membership_rules;
+
+pub use realm_config::*;
+pub mod realm_config;
+
+pub use input_voter_weight::*;
+pub mod input_voter_weight;
+
+pub use max_voter_weight_mode::*;
+pub mod max_voter_weight_mode;
+
 pub mod max_voter_weight_record;
 
+pub use voter_membership::*;
+pub mod voter_membership;
+
 pub use voter_weight_record::*;
 pub mod voter_weight_record;
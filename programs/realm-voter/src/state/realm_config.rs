@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// Configuration of a Realm whose members are granted governance power
+/// Once any Realm is configured then only the members of the configured Realms are granted governance power
+/// and not the members of any Realm created using the configured spl-governance instances
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct RealmConfig {
+    /// The configured Realm
+    pub realm: Pubkey,
+
+    /// The spl-governance instance the Realm belongs to
+    /// The instance must be configured in Registrar.governance_program_configs
+    pub governance_program_id: Pubkey,
+
+    /// If set then only the members of the Realm with TokenOwnerRecord for the given mint are granted governance power
+    /// The mint is either the Realm community mint or the council mint
+    pub governing_token_mint: Option<Pubkey>,
+
+    /// Vote weight assigned to a member of the Realm
+    /// It's used instead of Registrar.realm_member_voter_weight when the spl-governance instance is configured with RealmMemberWeightMode::Membership
+    pub realm_member_voter_weight: u64,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 8],
+}
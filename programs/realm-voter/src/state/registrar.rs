@@ -1,7 +1,16 @@
-use crate::{id, state::GovernanceProgramConfig, tools::anchor::DISCRIMINATOR_SIZE};
+use crate::{
+    error::RealmVoterError,
+    id,
+    state::{
+        GovernanceProgramConfig, MaxVoterWeightMode, MembershipAggregation, MembershipRules,
+        MembershipWeightMode, RealmConfig, RealmMemberWeightMode,
+    },
+    tools::anchor::DISCRIMINATOR_SIZE,
+};
 use anchor_lang::prelude::*;
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use solana_program::pubkey::PUBKEY_BYTES;
+use spl_governance::state::token_owner_record::{self, TokenOwnerRecordV2};
 
 /// Enum defining collection item change type
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
@@ -34,41 +43,245 @@ pub struct Registrar {
     pub governance_program_configs: Vec<GovernanceProgramConfig>,
 
     /// Vote weight assigned to a member of any of the Realms from the configured spl-governances
+    /// It's used for the spl-governance instances configured with RealmMemberWeightMode::Membership when the Realm allowlist is not enabled
     pub realm_member_voter_weight: u64,
 
     /// Max voter weight (expressed in governing_token_mint decimal units) is used to establish the theoretical Max Attendance Quorum which is then used to calculate Approval Quorum
     /// This manual configuration is a rough estimate because it's not practical to calculate on-chain the number of all DAO members for the given spl-governance instances
+    /// When membership_aggregation is enabled then it should account for the voters with memberships in several Realms
+    /// It stays the quorum anchor for the spl-governance instances configured with RealmMemberWeightMode::Deposit
     ///
     /// Note: This is not a security vulnerability because the plugin is inherently not secure and used only to encourage DAO usage and registration of spl-governance instances
+    /// The manual configuration is only used when max_voter_weight_mode is Manual
     pub max_voter_weight: u64,
 
+    /// If the plugin has a predecessor plugin then the predecessor VoterWeightRecord is used as the input voter weight
+    /// Otherwise the voter TokenOwnerRecord deposit for the Realm is used as the input voter weight
+    pub previous_voter_weight_plugin_program_id: Option<Pubkey>,
+
+    /// Defines how realm_member_voter_weight is combined with the input voter weight
+    pub membership_weight_mode: MembershipWeightMode,
+
+    /// Realms whose members are granted governance power
+    /// If the Realm allowlist is not enabled then the members of any Realm created using the configured spl-governance instances are granted governance power
+    /// Note: The collection takes the length prefix from the original reserved space and it's empty for Registrars created before it was introduced
+    pub realm_configs: Vec<RealmConfig>,
+
+    /// Rules the source TokenOwnerRecord must satisfy to be granted governance power
+    pub membership_rules: MembershipRules,
+
+    /// Defines how the voter weights of the memberships in several Realms are aggregated
+    pub membership_aggregation: MembershipAggregation,
+
+    /// Defines how the max voter weight is established
+    pub max_voter_weight_mode: MaxVoterWeightMode,
+
+    /// The number of distinct voters with VoterMembership for the current member_count_epoch
+    /// It's used to derive the max voter weight from the actual member base when max_voter_weight_mode is MemberCount
+    pub member_count: u64,
+
+    /// The epoch of member_count which is incremented when member_count is reset on max_voter_weight_mode change
+    /// VoterMemberships created for previous epochs are no longer counted and they can be closed using CloseVoterMembership
+    pub member_count_epoch: u64,
+
+    /// Indicates whether governance power is restricted to the members of the Realms configured in realm_configs
+    /// It's set when the first Realm is configured and it stays set when Realms are removed
+    /// Once enabled, removing the last Realm revokes governance power from all members rather than
+    /// granting it to the members of any Realm of the configured spl-governance instances
+    pub realm_allowlist_enabled: bool,
+
     /// Reserved for future upgrades
-    pub reserved: [u8; 128],
+    /// Note: The reserved space is split because Borsh doesn't support arrays of arbitrary length
+    pub reserved: [u8; 31],
+
+    /// Reserved for future upgrades
+    pub reserved2: [u8; 22],
 }
 
 impl Registrar {
-    pub fn get_space(max_governance_programs: u8) -> usize {
+    pub fn get_space(max_governance_programs: u8, max_realms: u8) -> usize {
         DISCRIMINATOR_SIZE
             + PUBKEY_BYTES * 3
             + 4
-            + max_governance_programs as usize * (PUBKEY_BYTES + 8)
+            + max_governance_programs as usize * (PUBKEY_BYTES + (1 + 8 + 8) + 7)
             + 8
             + 8
-            + 128
+            + (PUBKEY_BYTES + 1)
+            + 1
+            + 4
+            + max_realms as usize * (PUBKEY_BYTES * 2 + (1 + PUBKEY_BYTES) + 8 + 8)
+            + 8
+            + (1 + 1)
+            + (1 + 8)
+            + 1
+            + 8
+            + 8
+            + 1
+            + 31
+            + 22
     }
-}
 
-/// Returns Registrar PDA seeds
-pub fn get_registrar_seeds<'a>(
-    realm: &'a Pubkey,
-    governing_token_mint: &'a Pubkey,
-) -> [&'a [u8]; 3] {
-    [b"registrar", realm.as_ref(), governing_token_mint.as_ref()]
+    /// Returns the max voter weight according to max_voter_weight_mode
+    pub fn get_max_voter_weight(&self) -> u64 {
+        match self.max_voter_weight_mode {
+            MaxVoterWeightMode::Manual => self.max_voter_weight,
+            MaxVoterWeightMode::MemberCount => self
+                .member_count
+                .checked_mul(self.realm_member_voter_weight)
+                .unwrap(),
+        }
+    }
+
+    /// Asserts max_voter_weight_mode is supported by the current membership configuration
+    /// MemberCount assumes every member is given realm_member_voter_weight and hence it can't be used with
+    /// Deposit weights, Realm specific weights, aggregated memberships or the input voter weight added to the membership
+    /// MemberCount also requires the Realm allowlist because anybody can create Realms for the configured
+    /// spl-governance instances and inflate the member count with memberships in them
+    pub fn assert_max_voter_weight_mode(&self) -> Result<()> {
+        if self.max_voter_weight_mode == MaxVoterWeightMode::MemberCount {
+            require!(
+                self.realm_allowlist_enabled
+                    && self
+                        .governance_program_configs
+                        .iter()
+                        .all(|gc| gc.weight_mode == RealmMemberWeightMode::Membership)
+                    && self
+                        .realm_configs
+                        .iter()
+                        .all(|rc| rc.realm_member_voter_weight == self.realm_member_voter_weight)
+                    && self.membership_aggregation == MembershipAggregation::Disabled
+                    && self.membership_weight_mode == MembershipWeightMode::Override,
+                RealmVoterError::MemberCountMaxVoterWeightModeNotSupported
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Asserts membership_aggregation is supported by the current Realm configuration
+    /// Memberships can only be aggregated across the Realms of the Realm allowlist
+    pub fn assert_membership_aggregation(&self) -> Result<()> {
+        require!(
+            self.membership_aggregation == MembershipAggregation::Disabled
+                || self.realm_allowlist_enabled,
+            RealmVoterError::MembershipAggregationRequiresRealmAllowlist
+        );
+
+        Ok(())
+    }
+
+    /// Returns the configuration of the given spl-governance instance or an error if it's not configured
+    pub fn get_governance_program_config(
+        &self,
+        governance_program_id: &Pubkey,
+    ) -> Result<&GovernanceProgramConfig> {
+        self.governance_program_configs
+            .iter()
+            .find(|cc| cc.program_id == *governance_program_id)
+            .ok_or_else(|| RealmVoterError::GovernanceProgramNotConfigured.into())
+    }
+
+    /// Returns the configuration of the given Realm or an error if it's not configured
+    pub fn get_realm_config(&self, realm: &Pubkey) -> Result<&RealmConfig> {
+        self.realm_configs
+            .iter()
+            .find(|rc| rc.realm == *realm)
+            .ok_or_else(|| RealmVoterError::RealmNotConfigured.into())
+    }
+
+    /// Resolves the membership voter weight for the given TokenOwnerRecord and returns the Realm of the membership and its voter weight
+    /// The TokenOwnerRecord must belong to one of the configured spl-governance instances and the given governing_token_owner
+    /// and it must satisfy the membership rules
+    pub fn resolve_realm_member_voter_weight(
+        &self,
+        governing_token_owner: &Pubkey,
+        token_owner_record_info: &AccountInfo,
+    ) -> Result<(Pubkey, u64)> {
+        let governance_program_id = token_owner_record_info.owner;
+
+        // Note: We only verify a valid TokenOwnerRecord account exists for one of the configured spl-governance instances
+        // The existence of the account proofs the governing_token_owner has interacted with spl-governance Realm at least once in the past
+        self.get_governance_program_config(governance_program_id)?;
+
+        let token_owner_record = token_owner_record::get_token_owner_record_data(
+            governance_program_id,
+            token_owner_record_info,
+        )?;
+
+        // Ensure VoterWeightRecord and TokenOwnerRecord are for the same governing_token_owner
+        require_eq!(
+            token_owner_record.governing_token_owner,
+            *governing_token_owner,
+            RealmVoterError::GoverningTokenOwnerMustMatch
+        );
+
+        // Membership of the Realm the plugin is configured for is not allowed as a source of governance power
+        require_neq!(
+            token_owner_record.realm,
+            self.realm,
+            RealmVoterError::TokenOwnerRecordFromOwnRealmNotAllowed
+        );
+
+        // The TokenOwnerRecord must satisfy the membership rules to prevent sybil membership created right before voting
+        self.membership_rules
+            .assert_token_owner_record(&token_owner_record)?;
+
+        // The membership voter weight is either fixed or scaled from the deposit in the partner Realm
+        // and the Realm must be configured if the Registrar restricts membership to specific Realms
+        let realm_member_voter_weight =
+            self.get_realm_member_voter_weight(governance_program_id, &token_owner_record)?;
+
+        Ok((token_owner_record.realm, realm_member_voter_weight))
+    }
+
+    /// Returns the voter weight of the member of a Realm from the given spl-governance instance
+    /// If the Realm allowlist is enabled then the TokenOwnerRecord must belong to one of the configured Realms
+    /// and the Realm weight is used instead of realm_member_voter_weight
+    pub fn get_realm_member_voter_weight(
+        &self,
+        governance_program_id: &Pubkey,
+        token_owner_record: &TokenOwnerRecordV2,
+    ) -> Result<u64> {
+        let governance_program_config =
+            self.get_governance_program_config(governance_program_id)?;
+
+        let realm_member_voter_weight = if !self.realm_allowlist_enabled {
+            self.realm_member_voter_weight
+        } else {
+            let realm_config = self.get_realm_config(&token_owner_record.realm)?;
+
+            // The Realm account is owned by the spl-governance instance and the same Realm can't exist for any other instance
+            require_keys_eq!(
+                realm_config.governance_program_id,
+                *governance_program_id,
+                RealmVoterError::RealmNotConfigured
+            );
+
+            if let Some(governing_token_mint) = realm_config.governing_token_mint {
+                require_keys_eq!(
+                    token_owner_record.governing_token_mint,
+                    governing_token_mint,
+                    RealmVoterError::GoverningTokenMintNotAllowed
+                );
+            }
+
+            realm_config.realm_member_voter_weight
+        };
+
+        Ok(governance_program_config
+            .weight_mode
+            .get_realm_member_voter_weight(
+                realm_member_voter_weight,
+                token_owner_record.governing_token_deposit_amount,
+            ))
+    }
 }
 
+pub use gpl_plugin_core::registrar::get_registrar_seeds;
+
 /// Returns Registrar PDA address
 pub fn get_registrar_address(realm: &Pubkey, governing_token_mint: &Pubkey) -> Pubkey {
-    Pubkey::find_program_address(&get_registrar_seeds(realm, governing_token_mint), &id()).0
+    gpl_plugin_core::registrar::get_registrar_address(&id(), realm, governing_token_mint)
 }
 
 #[cfg(test)]
@@ -79,20 +292,48 @@ mod test {
     #[test]
     fn test_get_space() {
         // Arrange
-        let expected_space = Registrar::get_space(3);
+        let expected_space = Registrar::get_space(3, 2);
 
         let registrar = Registrar {
             governance_program_id: Pubkey::default(),
             realm: Pubkey::default(),
             governing_token_mint: Pubkey::default(),
             governance_program_configs: vec![
-                GovernanceProgramConfig::default(),
-                GovernanceProgramConfig::default(),
-                GovernanceProgramConfig::default(),
+                GovernanceProgramConfig {
+                    program_id: Pubkey::default(),
+                    weight_mode: RealmMemberWeightMode::Deposit {
+                        multiplier_bps: 10_000,
+                        cap: 100,
+                    },
+                    reserved: [0; 7],
+                };
+                3
+            ],
+            realm_configs: vec![
+                RealmConfig {
+                    realm: Pubkey::default(),
+                    governance_program_id: Pubkey::default(),
+                    governing_token_mint: Some(Pubkey::default()),
+                    realm_member_voter_weight: 10,
+                    reserved: [0; 8],
+                };
+                2
             ],
-            reserved: [0; 128],
+            membership_rules: MembershipRules {
+                min_governing_token_deposit_amount: 100,
+                max_outstanding_proposal_count: Some(1),
+            },
+            membership_aggregation: MembershipAggregation::CappedSum(100),
+            max_voter_weight_mode: MaxVoterWeightMode::MemberCount,
+            member_count: 10,
+            member_count_epoch: 1,
+            realm_allowlist_enabled: true,
+            reserved: [0; 31],
+            reserved2: [0; 22],
             max_voter_weight: 100,
             realm_member_voter_weight: 10,
+            previous_voter_weight_plugin_program_id: Some(Pubkey::default()),
+            membership_weight_mode: MembershipWeightMode::Add,
         };
 
         // Act
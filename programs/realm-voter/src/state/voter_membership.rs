@@ -0,0 +1,110 @@
+use crate::{error::RealmVoterError, id, tools::anchor::DISCRIMINATOR_SIZE};
+use anchor_lang::prelude::*;
+use solana_program::pubkey::PUBKEY_BYTES;
+
+/// Record of a voter counted towards Registrar.member_count
+/// The PDA of the record is ["voter-membership",registrar,governing_token_owner]
+/// It guarantees uniqueness and ensures the same voter can't be counted twice
+///
+/// The record is created using CreateVoterMembership on the first use by the voter
+/// and it's closed using CloseVoterMembership once its TokenOwnerRecord no longer qualifies for voter weight
+#[account]
+#[derive(Debug, PartialEq, Default)]
+pub struct VoterMembership {
+    /// The Registrar the voter is counted for
+    pub registrar: Pubkey,
+
+    /// The voter counted towards the member count
+    /// It's a Realm member pubkey corresponding to TokenOwnerRecord.governing_token_owner
+    pub governing_token_owner: Pubkey,
+
+    /// The TokenOwnerRecord which proved the membership when the voter was counted
+    /// The voter is counted for as long as the TokenOwnerRecord qualifies for voter weight
+    pub token_owner_record: Pubkey,
+
+    /// Registrar.member_count_epoch the voter was counted for
+    pub member_count_epoch: u64,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 8],
+}
+
+impl VoterMembership {
+    pub fn get_space() -> usize {
+        DISCRIMINATOR_SIZE + PUBKEY_BYTES * 3 + 8 + 8
+    }
+}
+
+/// Returns VoterMembership PDA seeds
+pub fn get_voter_membership_seeds<'a>(
+    registrar: &'a Pubkey,
+    governing_token_owner: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        b"voter-membership",
+        registrar.as_ref(),
+        governing_token_owner.as_ref(),
+    ]
+}
+
+/// Returns VoterMembership PDA address
+pub fn get_voter_membership_address(registrar: &Pubkey, governing_token_owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_voter_membership_seeds(registrar, governing_token_owner),
+        &id(),
+    )
+    .0
+}
+
+/// Asserts the given account is the VoterMembership of the governing_token_owner for the Registrar
+/// and the voter is counted for the current member_count_epoch
+pub fn assert_voter_membership(
+    voter_membership_info: &AccountInfo,
+    registrar: &Pubkey,
+    governing_token_owner: &Pubkey,
+    member_count_epoch: u64,
+) -> Result<()> {
+    require_keys_eq!(
+        voter_membership_info.key(),
+        get_voter_membership_address(registrar, governing_token_owner),
+        RealmVoterError::InvalidVoterMembership
+    );
+
+    // The account must be created using CreateVoterMembership
+    require_keys_eq!(
+        *voter_membership_info.owner,
+        id(),
+        RealmVoterError::InvalidVoterMembership
+    );
+
+    let voter_membership =
+        VoterMembership::try_deserialize(&mut &voter_membership_info.try_borrow_data()?[..])
+            .map_err(|_| RealmVoterError::InvalidVoterMembership)?;
+
+    require_eq!(
+        voter_membership.member_count_epoch,
+        member_count_epoch,
+        RealmVoterError::VoterMembershipExpired
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = VoterMembership::get_space();
+
+        // Act
+        let actual_space =
+            DISCRIMINATOR_SIZE + VoterMembership::default().try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+}
@@ -1 +1 @@
-pub const DISCRIMINATOR_SIZE: usize = 8;
+pub use gpl_plugin_core::anchor::DISCRIMINATOR_SIZE;
@@ -0,0 +1,173 @@
+use gpl_realm_voter::error::RealmVoterError;
+use gpl_realm_voter::state::{CollectionItemChangeType, MaxVoterWeightMode};
+use program_test::realm_voter_test::RealmVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_close_registrar() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    realm_voter_test.close_registrar(&registrar_cookie).await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .bench
+        .get_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(None, registrar);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_registrar_with_realm_authority_must_sign_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    let err = realm_voter_test
+        .close_registrar_using_ix(
+            &registrar_cookie,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_registrar_with_invalid_realm_authority_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = realm_voter_test
+        .close_registrar_using_ix(
+            &registrar_cookie,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_registrar_with_voter_memberships_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // MemberCount requires the Realm allowlist
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+        )
+        .await?;
+
+    realm_voter_test
+        .with_voter_membership(
+            &registrar_cookie,
+            &token_owner_cookie,
+            &voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            &max_voter_weight_record_cookie,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .close_registrar(&registrar_cookie)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::RegistrarHasVoterMemberships);
+
+    Ok(())
+}
@@ -0,0 +1,435 @@
+use crate::program_test::realm_voter_test::RealmVoterTest;
+use gpl_realm_voter::{
+    error::RealmVoterError,
+    state::{CollectionItemChangeType, MaxVoterWeightMode},
+};
+use program_test::tools::assert_realm_voter_err;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_close_voter_membership() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // MemberCount requires the Realm allowlist
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+        )
+        .await?;
+
+    let voter_membership_cookie = realm_voter_test
+        .with_voter_membership(
+            &registrar_cookie,
+            &token_owner_cookie,
+            &voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            &max_voter_weight_record_cookie,
+        )
+        .await?;
+
+    // The membership no longer qualifies once the spl-governance instance is removed
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Remove,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .close_voter_membership(
+            &registrar_cookie,
+            &voter_membership_cookie,
+            &max_voter_weight_record_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_membership = realm_voter_test
+        .bench
+        .get_account(&voter_membership_cookie.address)
+        .await;
+
+    assert_eq!(None, voter_membership);
+
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.member_count, 0);
+
+    let max_voter_weight_record = realm_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_voter_membership_with_expired_voter_membership() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // MemberCount requires the Realm allowlist
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+        )
+        .await?;
+
+    let voter_membership_cookie = realm_voter_test
+        .with_voter_membership(
+            &registrar_cookie,
+            &token_owner_cookie,
+            &voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            &max_voter_weight_record_cookie,
+        )
+        .await?;
+
+    // Reset the member count by switching the mode
+    realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::Manual,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .close_voter_membership(
+            &registrar_cookie,
+            &voter_membership_cookie,
+            &max_voter_weight_record_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_membership = realm_voter_test
+        .bench
+        .get_account(&voter_membership_cookie.address)
+        .await;
+
+    assert_eq!(None, voter_membership);
+
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.member_count, 0);
+
+    let max_voter_weight_record = realm_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 110);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_voter_membership_with_voter_still_member_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // MemberCount requires the Realm allowlist
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+        )
+        .await?;
+
+    let voter_membership_cookie = realm_voter_test
+        .with_voter_membership(
+            &registrar_cookie,
+            &token_owner_cookie,
+            &voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            &max_voter_weight_record_cookie,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .close_voter_membership(
+            &registrar_cookie,
+            &voter_membership_cookie,
+            &max_voter_weight_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::VoterMembershipStillValid);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_voter_membership_with_invalid_token_owner_record_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // MemberCount requires the Realm allowlist
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+        )
+        .await?;
+
+    let voter_membership_cookie = realm_voter_test
+        .with_voter_membership(
+            &registrar_cookie,
+            &token_owner_cookie,
+            &voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            &max_voter_weight_record_cookie,
+        )
+        .await?;
+
+    // Create TokenOwnerRecord for other Realm which doesn't qualify for membership
+    let realm_cookie3 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie3 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie3, &token_owner_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Remove,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .close_voter_membership_using_ix(
+            &registrar_cookie,
+            &voter_membership_cookie,
+            &max_voter_weight_record_cookie,
+            |i| i.accounts[2].pubkey = token_owner_record_cookie3.address, // token_owner_record
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidVoterMembership);
+
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use crate::program_test::realm_voter_test::RealmVoterTest;
+use gpl_realm_voter::{error::RealmVoterError, state::CollectionItemChangeType};
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+mod program_test;
+
+#[tokio::test]
+async fn test_close_voter_weight_record() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let voter_cookie = realm_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    realm_voter_test
+        .close_voter_weight_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .bench
+        .get_account(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(None, voter_weight_record);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_voter_weight_record_with_unexpired_voter_weight_record_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .close_voter_weight_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &token_owner_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::VoterWeightRecordMustBeExpired);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_voter_weight_record_with_invalid_governing_token_owner_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let voter_cookie = realm_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Try to close the record using a different wallet
+    let voter_cookie2 = realm_voter_test.bench.with_wallet().await;
+
+    // Act
+    let err = realm_voter_test
+        .close_voter_weight_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie2,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidTokenOwnerForVoterWeightRecord);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_voter_weight_record_with_governing_token_owner_must_sign_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let voter_cookie = realm_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .close_voter_weight_record_using_ix(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie,
+            |i| i.accounts[2].is_signer = false, // governing_token_owner
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
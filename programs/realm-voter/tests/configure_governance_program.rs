@@ -1,4 +1,7 @@
-use gpl_realm_voter::{error::RealmVoterError, state::CollectionItemChangeType};
+use gpl_realm_voter::{
+    error::RealmVoterError,
+    state::{CollectionItemChangeType, RealmMemberWeightMode},
+};
 use program_test::realm_voter_test::RealmVoterTest;
 use solana_program_test::*;
 use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
@@ -37,6 +40,11 @@ async fn test_configure_governance_program() -> Result<(), TransportError> {
         governance_program_config_cookie.program_config
     );
 
+    assert_eq!(
+        registrar.governance_program_configs[0].weight_mode,
+        RealmMemberWeightMode::Membership
+    );
+
     Ok(())
 }
 
@@ -126,6 +134,88 @@ async fn test_configure_governance_program_for_existing_program() -> Result<(),
     Ok(())
 }
 
+#[tokio::test]
+async fn test_configure_governance_program_with_deposit_weight_mode() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Act
+    let governance_program_config_cookie = realm_voter_test
+        .configure_governance_program_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+            RealmMemberWeightMode::Deposit {
+                multiplier_bps: 20_000,
+                cap: 1_000,
+            },
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.governance_program_configs.len(), 1);
+
+    assert_eq!(
+        registrar.governance_program_configs[0],
+        governance_program_config_cookie.program_config
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_governance_program_with_invalid_deposit_weight_mode_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    // Act
+    let err = realm_voter_test
+        .configure_governance_program_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+            RealmMemberWeightMode::Deposit {
+                multiplier_bps: 0,
+                cap: 1_000,
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+
+    assert_realm_voter_err(err, RealmVoterError::InvalidRealmMemberWeightMode);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_configure_governance_program_with_invalid_realm_error() -> Result<(), TransportError>
 {
@@ -147,6 +237,7 @@ async fn test_configure_governance_program_with_invalid_realm_error() -> Result<
             &registrar_cookie,
             &governance_program_cookie,
             CollectionItemChangeType::Upsert,
+            None,
             |i| i.accounts[1].pubkey = realm_cookie2.address, // realm
             None,
         )
@@ -179,6 +270,7 @@ async fn test_configure_governance_program_with_realm_authority_must_sign_error(
             &registrar_cookie,
             &governance_program_cookie,
             CollectionItemChangeType::Upsert,
+            None,
             |i| i.accounts[2].is_signer = false, // realm_authority
             Some(&[]),
         )
@@ -213,6 +305,7 @@ async fn test_configure_governance_program_with_invalid_realm_authority_error(
             &registrar_cookie,
             &governance_program_cookie,
             CollectionItemChangeType::Upsert,
+            None,
             |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
             Some(&[&realm_authority]),
         )
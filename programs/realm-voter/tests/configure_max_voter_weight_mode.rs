@@ -0,0 +1,360 @@
+use gpl_realm_voter::{
+    error::RealmVoterError,
+    state::{CollectionItemChangeType, MaxVoterWeightMode, RealmMemberWeightMode},
+};
+use program_test::realm_voter_test::RealmVoterTest;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+mod program_test;
+use program_test::tools::{assert_anchor_err, assert_realm_voter_err};
+
+#[tokio::test]
+async fn test_configure_max_voter_weight_mode() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Act
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+
+    // MemberCount requires the Realm allowlist
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.max_voter_weight_mode,
+        MaxVoterWeightMode::MemberCount
+    );
+    assert_eq!(registrar.member_count, 0);
+    assert_eq!(registrar.member_count_epoch, 1);
+
+    // No voter is counted yet
+    let max_voter_weight_record = realm_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 0);
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_max_voter_weight_mode_back_to_manual() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // MemberCount requires the Realm allowlist
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+        )
+        .await?;
+
+    realm_voter_test
+        .with_voter_membership(
+            &registrar_cookie,
+            &token_owner_cookie,
+            &voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            &max_voter_weight_record_cookie,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::Manual,
+        )
+        .await?;
+
+    // Assert
+
+    // The member count is reset when the mode changes
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.member_count, 0);
+    assert_eq!(registrar.member_count_epoch, 2);
+
+    let max_voter_weight_record = realm_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 110);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_max_voter_weight_mode_with_deposit_weight_mode_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+            RealmMemberWeightMode::Deposit {
+                multiplier_bps: 10_000,
+                cap: 100,
+            },
+        )
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+
+    // MemberCount requires the Realm allowlist and the Realm weight to match the Registrar weight
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            registrar_cookie.account.realm_member_voter_weight,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(
+        err,
+        RealmVoterError::MemberCountMaxVoterWeightModeNotSupported,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_max_voter_weight_mode_with_realm_allowlist_not_enabled_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(
+        err,
+        RealmVoterError::MemberCountMaxVoterWeightModeNotSupported,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_max_voter_weight_mode_with_realm_authority_must_sign_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_max_voter_weight_mode_using_ix(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_max_voter_weight_mode_with_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = realm_voter_test
+        .configure_max_voter_weight_mode_using_ix(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
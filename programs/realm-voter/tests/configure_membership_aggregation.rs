@@ -0,0 +1,204 @@
+use gpl_realm_voter::{
+    error::RealmVoterError,
+    state::{CollectionItemChangeType, MaxVoterWeightMode, MembershipAggregation},
+};
+use program_test::realm_voter_test::RealmVoterTest;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+mod program_test;
+use program_test::tools::{assert_anchor_err, assert_realm_voter_err};
+
+#[tokio::test]
+async fn test_configure_membership_aggregation() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Memberships can only be aggregated across the Realms of the Realm allowlist
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    let membership_aggregation = MembershipAggregation::CappedSum(100);
+
+    // Act
+    realm_voter_test
+        .configure_membership_aggregation(&registrar_cookie, membership_aggregation)
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.membership_aggregation, membership_aggregation);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_membership_aggregation_with_realm_authority_must_sign_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_membership_aggregation_using_ix(
+            &registrar_cookie,
+            MembershipAggregation::Sum,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_membership_aggregation_with_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = realm_voter_test
+        .configure_membership_aggregation_using_ix(
+            &registrar_cookie,
+            MembershipAggregation::Sum,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_membership_aggregation_with_member_count_max_voter_weight_mode_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Memberships can only be aggregated across the Realms of the Realm allowlist
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            // MemberCount requires the Realm weight to match the Registrar weight
+            registrar_cookie.account.realm_member_voter_weight,
+        )
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_membership_aggregation(&registrar_cookie, MembershipAggregation::Sum)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(
+        err,
+        RealmVoterError::MemberCountMaxVoterWeightModeNotSupported,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_membership_aggregation_with_realm_allowlist_not_enabled_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_membership_aggregation(&registrar_cookie, MembershipAggregation::Sum)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(
+        err,
+        RealmVoterError::MembershipAggregationRequiresRealmAllowlist,
+    );
+
+    Ok(())
+}
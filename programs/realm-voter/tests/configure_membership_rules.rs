@@ -0,0 +1,93 @@
+use gpl_realm_voter::{error::RealmVoterError, state::MembershipRules};
+use program_test::realm_voter_test::RealmVoterTest;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+mod program_test;
+use program_test::tools::{assert_anchor_err, assert_realm_voter_err};
+
+#[tokio::test]
+async fn test_configure_membership_rules() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let membership_rules = MembershipRules {
+        min_governing_token_deposit_amount: 100,
+        max_outstanding_proposal_count: Some(0),
+    };
+
+    // Act
+    realm_voter_test
+        .configure_membership_rules(&registrar_cookie, membership_rules)
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.membership_rules, membership_rules);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_membership_rules_with_realm_authority_must_sign_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_membership_rules_using_ix(
+            &registrar_cookie,
+            MembershipRules::default(),
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_membership_rules_with_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = realm_voter_test
+        .configure_membership_rules_using_ix(
+            &registrar_cookie,
+            MembershipRules::default(),
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
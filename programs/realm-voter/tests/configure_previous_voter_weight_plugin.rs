@@ -0,0 +1,172 @@
+use gpl_realm_voter::error::RealmVoterError;
+use gpl_realm_voter::state::MembershipWeightMode;
+use program_test::predecessor_plugin_test::PredecessorPluginTest;
+use program_test::{realm_voter_test::RealmVoterTest, tools::*};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_configure_previous_voter_weight_plugin() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            MembershipWeightMode::Add,
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.previous_voter_weight_plugin_program_id,
+        Some(PredecessorPluginTest::program_id())
+    );
+    assert_eq!(registrar.membership_weight_mode, MembershipWeightMode::Add);
+
+    assert_eq!(registrar, registrar_cookie.account);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_previous_voter_weight_plugin_with_existing_plugin_removed(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            MembershipWeightMode::Override,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            None,
+            MembershipWeightMode::Override,
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.previous_voter_weight_plugin_program_id, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_previous_voter_weight_plugin_with_missing_previous_plugin_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_previous_voter_weight_plugin_using_ix(
+            &mut registrar_cookie,
+            None,
+            true,
+            MembershipWeightMode::Override,
+            NopOverride,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::MissingPreviousVoterWeightPlugin);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_previous_voter_weight_plugin_with_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = realm_voter_test
+        .configure_previous_voter_weight_plugin_using_ix(
+            &mut registrar_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            true,
+            MembershipWeightMode::Override,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_previous_voter_weight_plugin_with_realm_authority_must_sign_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_previous_voter_weight_plugin_using_ix(
+            &mut registrar_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            true,
+            MembershipWeightMode::Override,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
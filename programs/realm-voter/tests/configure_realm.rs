@@ -0,0 +1,400 @@
+use anchor_lang::prelude::Pubkey;
+use gpl_realm_voter::{error::RealmVoterError, state::CollectionItemChangeType};
+use program_test::realm_voter_test::RealmVoterTest;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+mod program_test;
+use program_test::tools::assert_realm_voter_err;
+
+#[tokio::test]
+async fn test_configure_realm() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    // Act
+    let realm_config_cookie = realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.realm_configs.len(), 1);
+    assert_eq!(registrar.realm_configs[0], realm_config_cookie.realm_config);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_realm_with_council_mint() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let council_mint = member_realm_cookie.account.config.council_mint;
+
+    // Act
+    let realm_config_cookie = realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            council_mint,
+            10,
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.realm_configs.len(), 1);
+    assert_eq!(registrar.realm_configs[0], realm_config_cookie.realm_config);
+    assert_eq!(
+        registrar.realm_configs[0].governing_token_mint,
+        council_mint
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_realm_for_existing_realm() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    // Act
+    let realm_config_cookie = realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            Some(member_realm_cookie.account.community_mint),
+            20,
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.realm_configs.len(), 1);
+    assert_eq!(registrar.realm_configs[0], realm_config_cookie.realm_config);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_realm_with_invalid_governing_token_mint_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            Some(Pubkey::new_unique()),
+            10,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidRealmGoverningTokenMint);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_realm_with_own_realm_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::TokenOwnerRecordFromOwnRealmNotAllowed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_realm_with_governance_program_not_configured_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::GovernanceProgramNotConfigured);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_realm_with_invalid_realm_authority_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = realm_voter_test
+        .configure_realm_using_ix(
+            &registrar_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_realm_configuration() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Remove,
+            None,
+            0,
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.realm_configs.len(), 0);
+    assert!(registrar.realm_allowlist_enabled);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_realm_configuration_with_realm_not_configured_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Remove,
+            None,
+            0,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::RealmNotConfigured);
+
+    Ok(())
+}
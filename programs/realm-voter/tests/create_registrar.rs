@@ -2,13 +2,14 @@ mod program_test;
 
 use anchor_lang::prelude::{ErrorCode, Pubkey};
 use gpl_realm_voter::error::RealmVoterError;
+use gpl_realm_voter::state::Registrar;
 use program_test::realm_voter_test::RealmVoterTest;
 
 use solana_program::instruction::InstructionError;
 use solana_program_test::*;
 use solana_sdk::{signature::Keypair, transport::TransportError};
 
-use program_test::tools::{assert_anchor_err, assert_ix_err, assert_realm_voter_err};
+use program_test::tools::{assert_anchor_err, assert_ix_err, assert_realm_voter_err, NopOverride};
 
 #[tokio::test]
 async fn test_create_registrar() -> Result<(), TransportError> {
@@ -18,7 +19,9 @@ async fn test_create_registrar() -> Result<(), TransportError> {
     let realm_cookie = realm_voter_test.governance.with_realm().await?;
 
     // Act
-    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+    let registrar_cookie = realm_voter_test
+        .with_registrar_using_ix(&realm_cookie, NopOverride, None)
+        .await?;
 
     // Assert
     let registrar = realm_voter_test
@@ -27,6 +30,18 @@ async fn test_create_registrar() -> Result<(), TransportError> {
 
     assert_eq!(registrar, registrar_cookie.account);
 
+    // Space for Realms is not allocated until the Registrar is resized
+    let registrar_account = realm_voter_test
+        .bench
+        .get_account(&registrar_cookie.address)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        registrar_account.data.len(),
+        Registrar::get_space(registrar_cookie.max_governance_programs, 0)
+    );
+
     Ok(())
 }
 
@@ -322,6 +322,26 @@ impl GovernanceTest {
         realm_cookie: &RealmCookie,
         token_owner_cookie: &WalletCookie,
     ) -> Result<TokenOwnerRecordCookie, TransportError> {
+        self.with_token_owner_record_and_deposit(realm_cookie, token_owner_cookie, 0)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_token_owner_record_and_deposit(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        token_owner_cookie: &WalletCookie,
+        tokens_to_deposit: u64,
+    ) -> Result<TokenOwnerRecordCookie, TransportError> {
+        let token_account_cookie = self
+            .bench
+            .with_tokens(
+                &realm_cookie.community_mint_cookie,
+                &token_owner_cookie.address,
+                tokens_to_deposit,
+            )
+            .await?;
+
         let token_owner_record_key = get_token_owner_record_address(
             &self.program_id,
             &realm_cookie.address,
@@ -337,8 +357,27 @@ impl GovernanceTest {
             &self.bench.payer.pubkey(),
         );
 
+        let mut instructions = vec![create_tor_ix];
+        let mut signers = vec![];
+
+        if tokens_to_deposit > 0 {
+            let deposit_tokens_ix = deposit_governing_tokens(
+                &self.program_id,
+                &realm_cookie.address,
+                &token_account_cookie.address,
+                &token_owner_cookie.address,
+                &token_owner_cookie.address,
+                &self.bench.payer.pubkey(),
+                tokens_to_deposit,
+                &realm_cookie.account.community_mint,
+            );
+
+            instructions.push(deposit_tokens_ix);
+            signers.push(&token_owner_cookie.signer);
+        }
+
         self.bench
-            .process_transaction(&[create_tor_ix], None)
+            .process_transaction(instructions.as_slice(), Some(signers.as_slice()))
             .await?;
 
         let account = TokenOwnerRecordV2 {
@@ -346,7 +385,7 @@ impl GovernanceTest {
             realm: realm_cookie.address,
             governing_token_mint: realm_cookie.account.community_mint,
             governing_token_owner: token_owner_cookie.address,
-            governing_token_deposit_amount: 0,
+            governing_token_deposit_amount: tokens_to_deposit,
             unrelinquished_votes_count: 0,
 
             outstanding_proposal_count: 0,
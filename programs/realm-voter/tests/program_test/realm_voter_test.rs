@@ -8,11 +8,12 @@ use gpl_realm_voter::state::max_voter_weight_record::{
 use gpl_realm_voter::state::*;
 
 use solana_program_test::{BanksClientError, ProgramTest};
-use solana_sdk::instruction::Instruction;
+use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 
 use crate::program_test::governance_test::GovernanceTest;
+use crate::program_test::predecessor_plugin_test::PredecessorPluginTest;
 use crate::program_test::program_test_bench::ProgramTestBench;
 
 use crate::program_test::governance_test::RealmCookie;
@@ -29,6 +30,7 @@ pub struct RegistrarCookie {
 
     pub realm_authority: Keypair,
     pub max_governance_programs: u8,
+    pub max_realms: u8,
 }
 
 pub struct VoterWeightRecordCookie {
@@ -45,6 +47,15 @@ pub struct GovernanceProgramConfigCookie {
     pub program_config: GovernanceProgramConfig,
 }
 
+pub struct RealmConfigCookie {
+    pub realm_config: RealmConfig,
+}
+
+pub struct VoterMembershipCookie {
+    pub address: Pubkey,
+    pub account: VoterMembership,
+}
+
 pub struct GovernanceProgramCookie {
     pub program_id: Pubkey,
 }
@@ -53,6 +64,7 @@ pub struct RealmVoterTest {
     pub program_id: Pubkey,
     pub bench: Arc<ProgramTestBench>,
     pub governance: GovernanceTest,
+    pub predecessor_plugin: PredecessorPluginTest,
 }
 
 impl RealmVoterTest {
@@ -67,6 +79,7 @@ impl RealmVoterTest {
 
         RealmVoterTest::add_program(&mut program_test);
         GovernanceTest::add_program(&mut program_test);
+        PredecessorPluginTest::add_program(&mut program_test);
 
         let program_id = gpl_realm_voter::id();
 
@@ -75,11 +88,13 @@ impl RealmVoterTest {
 
         let governance_bench =
             GovernanceTest::new(bench_rc.clone(), Some(program_id), Some(program_id));
+        let predecessor_plugin_bench = PredecessorPluginTest::new(bench_rc.clone());
 
         Self {
             program_id,
             bench: bench_rc,
             governance: governance_bench,
+            predecessor_plugin: predecessor_plugin_bench,
         }
     }
 
@@ -99,8 +114,17 @@ impl RealmVoterTest {
         &mut self,
         realm_cookie: &RealmCookie,
     ) -> Result<RegistrarCookie, BanksClientError> {
-        self.with_registrar_using_ix(realm_cookie, NopOverride, None)
-            .await
+        let mut registrar_cookie = self
+            .with_registrar_using_ix(realm_cookie, NopOverride, None)
+            .await?;
+
+        // Space for Realms is only allocated by ResizeRegistrar
+        let max_governance_programs = registrar_cookie.max_governance_programs;
+
+        self.resize_registrar(&mut registrar_cookie, max_governance_programs, 10)
+            .await?;
+
+        Ok(registrar_cookie)
     }
 
     #[allow(dead_code)]
@@ -153,9 +177,19 @@ impl RealmVoterTest {
             realm: realm_cookie.address,
             governing_token_mint: realm_cookie.account.community_mint,
             governance_program_configs: vec![],
-            reserved: [0; 128],
+            realm_configs: vec![],
+            membership_rules: MembershipRules::default(),
+            membership_aggregation: MembershipAggregation::Disabled,
+            max_voter_weight_mode: MaxVoterWeightMode::Manual,
+            member_count: 0,
+            member_count_epoch: 0,
+            realm_allowlist_enabled: false,
+            reserved: [0; 31],
+            reserved2: [0; 22],
             max_voter_weight: 0,
             realm_member_voter_weight: 0,
+            previous_voter_weight_plugin_program_id: None,
+            membership_weight_mode: MembershipWeightMode::Override,
         };
 
         Ok(RegistrarCookie {
@@ -163,9 +197,116 @@ impl RealmVoterTest {
             account,
             realm_authority: realm_cookie.get_realm_authority(),
             max_governance_programs,
+            max_realms: 0,
         })
     }
 
+    #[allow(dead_code)]
+    pub async fn resize_registrar(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        max_governance_programs: u8,
+        max_realms: u8,
+    ) -> Result<(), BanksClientError> {
+        self.resize_registrar_using_ix(
+            registrar_cookie,
+            max_governance_programs,
+            max_realms,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn resize_registrar_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        max_governance_programs: u8,
+        max_realms: u8,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_realm_voter::instruction::ResizeRegistrar {
+                max_governance_programs,
+                max_realms,
+            });
+
+        let accounts = anchor_lang::ToAccountMetas::to_account_metas(
+            &gpl_realm_voter::accounts::ResizeRegistrar {
+                registrar: registrar_cookie.address,
+                realm: registrar_cookie.account.realm,
+                realm_authority: registrar_cookie.realm_authority.pubkey(),
+                payer: self.bench.payer.pubkey(),
+                system_program: solana_sdk::system_program::id(),
+            },
+            None,
+        );
+
+        let mut resize_registrar_ix = Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts,
+            data,
+        };
+
+        instruction_override(&mut resize_registrar_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[resize_registrar_ix], Some(signers))
+            .await?;
+
+        registrar_cookie.max_governance_programs = max_governance_programs;
+        registrar_cookie.max_realms = max_realms;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_registrar(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+    ) -> Result<(), BanksClientError> {
+        self.close_registrar_using_ix(registrar_cookie, NopOverride, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_registrar_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_realm_voter::instruction::CloseRegistrar {});
+
+        let accounts = gpl_realm_voter::accounts::CloseRegistrar {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            beneficiary: self.bench.payer.pubkey(),
+        };
+
+        let mut close_registrar_ix = Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut close_registrar_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[close_registrar_ix], Some(signers))
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn with_voter_weight_record(
         &self,
@@ -237,6 +378,59 @@ impl RealmVoterTest {
         })
     }
 
+    #[allow(dead_code)]
+    pub async fn close_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        voter_cookie: &WalletCookie,
+    ) -> Result<(), BanksClientError> {
+        self.close_voter_weight_record_using_ix(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            voter_cookie,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        voter_cookie: &WalletCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_realm_voter::instruction::CloseVoterWeightRecord {},
+        );
+
+        let accounts = gpl_realm_voter::accounts::CloseVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            governing_token_owner: voter_cookie.address,
+            beneficiary: self.bench.payer.pubkey(),
+        };
+
+        let mut close_voter_weight_record_ix = Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut close_voter_weight_record_ix);
+
+        let default_signers = &[&voter_cookie.signer];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[close_voter_weight_record_ix], Some(signers))
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn with_max_voter_weight_record(
         &mut self,
@@ -294,12 +488,125 @@ impl RealmVoterTest {
         })
     }
 
+    #[allow(dead_code)]
+    pub async fn close_max_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+    ) -> Result<(), BanksClientError> {
+        self.close_max_voter_weight_record_using_ix(
+            registrar_cookie,
+            max_voter_weight_record_cookie,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_max_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_realm_voter::instruction::CloseMaxVoterWeightRecord {},
+        );
+
+        let accounts = gpl_realm_voter::accounts::CloseMaxVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            beneficiary: self.bench.payer.pubkey(),
+        };
+
+        let mut close_max_voter_weight_record_ix = Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut close_max_voter_weight_record_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[close_max_voter_weight_record_ix], Some(signers))
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn update_voter_weight_record(
         &self,
         registrar_cookie: &RegistrarCookie,
         voter_weight_record_cookie: &mut VoterWeightRecordCookie,
         token_owner_record_cookie: &TokenOwnerRecordCookie,
+    ) -> Result<(), BanksClientError> {
+        self.update_voter_weight_record_with_input(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            token_owner_record_cookie,
+            None,
+        )
+        .await
+    }
+
+    /// Updates VoterWeightRecord with the given input voter weight account
+    /// which is either the predecessor VoterWeightRecord or the voter TokenOwnerRecord
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record_with_input(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &mut VoterWeightRecordCookie,
+        token_owner_record_cookie: &TokenOwnerRecordCookie,
+        input_voter_weight: Option<&Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        self.update_voter_weight_record_with_memberships(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            token_owner_record_cookie,
+            input_voter_weight,
+            None,
+            &[],
+        )
+        .await
+    }
+
+    /// Updates VoterWeightRecord with the given VoterMembership counting the voter towards the member count
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record_with_voter_membership(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &mut VoterWeightRecordCookie,
+        token_owner_record_cookie: &TokenOwnerRecordCookie,
+        voter_membership: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        self.update_voter_weight_record_with_memberships(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            token_owner_record_cookie,
+            None,
+            Some(voter_membership),
+            &[],
+        )
+        .await
+    }
+
+    /// Updates VoterWeightRecord with the given input voter weight account, VoterMembership
+    /// and the TokenOwnerRecords for the voter memberships in other Realms
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record_with_memberships(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &mut VoterWeightRecordCookie,
+        token_owner_record_cookie: &TokenOwnerRecordCookie,
+        input_voter_weight: Option<&Pubkey>,
+        voter_membership: Option<&Pubkey>,
+        membership_token_owner_record_cookies: &[&TokenOwnerRecordCookie],
     ) -> Result<(), BanksClientError> {
         let data = anchor_lang::InstructionData::data(
             &gpl_realm_voter::instruction::UpdateVoterWeightRecord {},
@@ -311,7 +618,22 @@ impl RealmVoterTest {
             token_owner_record: token_owner_record_cookie.address,
         };
 
-        let account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        if let Some(input_voter_weight) = input_voter_weight {
+            account_metas.push(AccountMeta::new_readonly(*input_voter_weight, false));
+        }
+
+        if let Some(voter_membership) = voter_membership {
+            account_metas.push(AccountMeta::new_readonly(*voter_membership, false));
+        }
+
+        for token_owner_record_cookie in membership_token_owner_record_cookies {
+            account_metas.push(AccountMeta::new_readonly(
+                token_owner_record_cookie.address,
+                false,
+            ));
+        }
 
         let instructions = vec![Instruction {
             program_id: gpl_realm_voter::id(),
@@ -393,6 +715,26 @@ impl RealmVoterTest {
             registrar_cookie,
             governance_program_cookie,
             change_type,
+            None,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_governance_program_with_weight_mode(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        governance_program_cookie: &GovernanceProgramCookie,
+        change_type: CollectionItemChangeType,
+        weight_mode: RealmMemberWeightMode,
+    ) -> Result<GovernanceProgramConfigCookie, BanksClientError> {
+        self.configure_governance_program_using_ix(
+            registrar_cookie,
+            governance_program_cookie,
+            change_type,
+            Some(weight_mode),
             NopOverride,
             None,
         )
@@ -405,11 +747,15 @@ impl RealmVoterTest {
         registrar_cookie: &RegistrarCookie,
         governance_program_cookie: &GovernanceProgramCookie,
         change_type: CollectionItemChangeType,
+        weight_mode: Option<RealmMemberWeightMode>,
         instruction_override: F,
         signers_override: Option<&[&Keypair]>,
     ) -> Result<GovernanceProgramConfigCookie, BanksClientError> {
         let data = anchor_lang::InstructionData::data(
-            &gpl_realm_voter::instruction::ConfigureGovernanceProgram { change_type },
+            &gpl_realm_voter::instruction::ConfigureGovernanceProgram {
+                change_type,
+                weight_mode,
+            },
         );
 
         let accounts = gpl_realm_voter::accounts::ConfigureGovernanceProgram {
@@ -436,7 +782,8 @@ impl RealmVoterTest {
 
         let governance_program_config = GovernanceProgramConfig {
             program_id: governance_program_cookie.program_id.clone(),
-            reserved: [0; 8],
+            weight_mode: weight_mode.unwrap_or_default(),
+            reserved: [0; 7],
         };
 
         Ok(GovernanceProgramConfigCookie {
@@ -445,22 +792,466 @@ impl RealmVoterTest {
     }
 
     #[allow(dead_code)]
-    pub async fn get_registrar_account(&mut self, registrar: &Pubkey) -> Registrar {
-        self.bench.get_anchor_account::<Registrar>(*registrar).await
+    pub async fn configure_realm(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        member_realm_cookie: &RealmCookie,
+        change_type: CollectionItemChangeType,
+        governing_token_mint: Option<Pubkey>,
+        realm_member_voter_weight: u64,
+    ) -> Result<RealmConfigCookie, BanksClientError> {
+        self.configure_realm_using_ix(
+            registrar_cookie,
+            member_realm_cookie,
+            change_type,
+            governing_token_mint,
+            realm_member_voter_weight,
+            NopOverride,
+            None,
+        )
+        .await
     }
 
     #[allow(dead_code)]
-    pub async fn get_max_voter_weight_record(
-        &self,
-        max_voter_weight_record: &Pubkey,
-    ) -> MaxVoterWeightRecord {
-        self.bench
-            .get_anchor_account(*max_voter_weight_record)
-            .await
-    }
+    pub async fn configure_realm_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        member_realm_cookie: &RealmCookie,
+        change_type: CollectionItemChangeType,
+        governing_token_mint: Option<Pubkey>,
+        realm_member_voter_weight: u64,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<RealmConfigCookie, BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_realm_voter::instruction::ConfigureRealm {
+                change_type,
+                governing_token_mint,
+                realm_member_voter_weight,
+            });
+
+        let accounts = gpl_realm_voter::accounts::ConfigureRealm {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            member_realm: member_realm_cookie.address,
+        };
+
+        let mut configure_realm_ix = Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_realm_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_realm_ix], Some(signers))
+            .await?;
+
+        let realm_config = RealmConfig {
+            realm: member_realm_cookie.address,
+            governance_program_id: self.governance.program_id,
+            governing_token_mint,
+            realm_member_voter_weight,
+            reserved: [0; 8],
+        };
+
+        Ok(RealmConfigCookie { realm_config })
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_membership_rules(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        membership_rules: MembershipRules,
+    ) -> Result<(), BanksClientError> {
+        self.configure_membership_rules_using_ix(
+            registrar_cookie,
+            membership_rules,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_membership_rules_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        membership_rules: MembershipRules,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_realm_voter::instruction::ConfigureMembershipRules { membership_rules },
+        );
+
+        let accounts = gpl_realm_voter::accounts::ConfigureMembershipRules {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+        };
+
+        let mut configure_membership_rules_ix = Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_membership_rules_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_membership_rules_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_max_voter_weight_mode(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &mut MaxVoterWeightRecordCookie,
+        max_voter_weight_mode: MaxVoterWeightMode,
+    ) -> Result<(), BanksClientError> {
+        self.configure_max_voter_weight_mode_using_ix(
+            registrar_cookie,
+            max_voter_weight_record_cookie,
+            max_voter_weight_mode,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_max_voter_weight_mode_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &mut MaxVoterWeightRecordCookie,
+        max_voter_weight_mode: MaxVoterWeightMode,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_realm_voter::instruction::ConfigureMaxVoterWeightMode {
+                max_voter_weight_mode,
+            },
+        );
+
+        let accounts = gpl_realm_voter::accounts::ConfigureMaxVoterWeightMode {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+        };
+
+        let mut configure_max_voter_weight_mode_ix = Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_max_voter_weight_mode_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_max_voter_weight_mode_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_voter_membership(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &WalletCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        token_owner_record_cookie: &TokenOwnerRecordCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+    ) -> Result<VoterMembershipCookie, BanksClientError> {
+        self.with_voter_membership_using_ix(
+            registrar_cookie,
+            voter_cookie,
+            voter_weight_record_cookie,
+            token_owner_record_cookie,
+            max_voter_weight_record_cookie,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn with_voter_membership_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &WalletCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        token_owner_record_cookie: &TokenOwnerRecordCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<VoterMembershipCookie, BanksClientError> {
+        let governing_token_owner = voter_weight_record_cookie.account.governing_token_owner;
+
+        let voter_membership_key =
+            get_voter_membership_address(&registrar_cookie.address, &governing_token_owner);
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_realm_voter::instruction::CreateVoterMembership {},
+        );
+
+        let accounts = gpl_realm_voter::accounts::CreateVoterMembership {
+            registrar: registrar_cookie.address,
+            voter_membership: voter_membership_key,
+            voter_weight_record: voter_weight_record_cookie.address,
+            token_owner_record: token_owner_record_cookie.address,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            governing_token_owner: voter_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut create_voter_membership_ix = Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut create_voter_membership_ix);
+
+        let default_signers = &[&voter_cookie.signer];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[create_voter_membership_ix], Some(signers))
+            .await?;
+
+        let registrar = self
+            .bench
+            .get_anchor_account::<Registrar>(registrar_cookie.address)
+            .await;
+
+        let account = VoterMembership {
+            registrar: registrar_cookie.address,
+            governing_token_owner,
+            token_owner_record: token_owner_record_cookie.address,
+            member_count_epoch: registrar.member_count_epoch,
+            reserved: [0; 8],
+        };
+
+        Ok(VoterMembershipCookie {
+            address: voter_membership_key,
+            account,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_voter_membership(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_membership_cookie: &VoterMembershipCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+    ) -> Result<(), BanksClientError> {
+        self.close_voter_membership_using_ix(
+            registrar_cookie,
+            voter_membership_cookie,
+            max_voter_weight_record_cookie,
+            NopOverride,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_voter_membership_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_membership_cookie: &VoterMembershipCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        instruction_override: F,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_realm_voter::instruction::CloseVoterMembership {},
+        );
+
+        let accounts = gpl_realm_voter::accounts::CloseVoterMembership {
+            registrar: registrar_cookie.address,
+            voter_membership: voter_membership_cookie.address,
+            token_owner_record: voter_membership_cookie.account.token_owner_record,
+            governing_token_owner: voter_membership_cookie.account.governing_token_owner,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+        };
+
+        let mut close_voter_membership_ix = Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut close_voter_membership_ix);
+
+        self.bench
+            .process_transaction(&[close_voter_membership_ix], None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_membership_aggregation(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        membership_aggregation: MembershipAggregation,
+    ) -> Result<(), BanksClientError> {
+        self.configure_membership_aggregation_using_ix(
+            registrar_cookie,
+            membership_aggregation,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_membership_aggregation_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        membership_aggregation: MembershipAggregation,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_realm_voter::instruction::ConfigureMembershipAggregation {
+                membership_aggregation,
+            },
+        );
+
+        let accounts = gpl_realm_voter::accounts::ConfigureMembershipAggregation {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+        };
+
+        let mut configure_membership_aggregation_ix = Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_membership_aggregation_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_membership_aggregation_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_previous_voter_weight_plugin(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        previous_voter_weight_plugin_program_id: Option<Pubkey>,
+        membership_weight_mode: MembershipWeightMode,
+    ) -> Result<(), BanksClientError> {
+        self.configure_previous_voter_weight_plugin_using_ix(
+            registrar_cookie,
+            previous_voter_weight_plugin_program_id,
+            previous_voter_weight_plugin_program_id.is_some(),
+            membership_weight_mode,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_previous_voter_weight_plugin_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        previous_voter_weight_plugin_program_id: Option<Pubkey>,
+        use_previous_voter_weight_plugin: bool,
+        membership_weight_mode: MembershipWeightMode,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_realm_voter::instruction::ConfigurePreviousVoterWeightPlugin {
+                use_previous_voter_weight_plugin,
+                membership_weight_mode,
+            },
+        );
+
+        let mut accounts = anchor_lang::ToAccountMetas::to_account_metas(
+            &gpl_realm_voter::accounts::ConfigurePreviousVoterWeightPlugin {
+                registrar: registrar_cookie.address,
+                realm: registrar_cookie.account.realm,
+                realm_authority: registrar_cookie.realm_authority.pubkey(),
+            },
+            None,
+        );
+
+        if let Some(previous_voter_weight_plugin_program_id) =
+            previous_voter_weight_plugin_program_id
+        {
+            accounts.push(AccountMeta::new_readonly(
+                previous_voter_weight_plugin_program_id,
+                false,
+            ));
+        }
+
+        let mut configure_previous_voter_weight_plugin_ix = Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts,
+            data,
+        };
+
+        instruction_override(&mut configure_previous_voter_weight_plugin_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_previous_voter_weight_plugin_ix], Some(signers))
+            .await?;
+
+        registrar_cookie
+            .account
+            .previous_voter_weight_plugin_program_id = previous_voter_weight_plugin_program_id;
+        registrar_cookie.account.membership_weight_mode = membership_weight_mode;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_registrar_account(&mut self, registrar: &Pubkey) -> Registrar {
+        self.bench.get_anchor_account::<Registrar>(*registrar).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_max_voter_weight_record(
+        &self,
+        max_voter_weight_record: &Pubkey,
+    ) -> MaxVoterWeightRecord {
+        self.bench
+            .get_anchor_account(*max_voter_weight_record)
+            .await
+    }
 
     #[allow(dead_code)]
     pub async fn get_voter_weight_record(&self, voter_weight_record: &Pubkey) -> VoterWeightRecord {
         self.bench.get_anchor_account(*voter_weight_record).await
     }
+
+    #[allow(dead_code)]
+    pub async fn get_voter_membership(&self, voter_membership: &Pubkey) -> VoterMembership {
+        self.bench.get_anchor_account(*voter_membership).await
+    }
 }
@@ -0,0 +1,195 @@
+use gpl_realm_voter::error::RealmVoterError;
+use gpl_realm_voter::state::{CollectionItemChangeType, Registrar};
+use program_test::realm_voter_test::RealmVoterTest;
+use program_test::tools::assert_realm_voter_err;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_resize_registrar() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    let governance_program_config_cookie = realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let max_governance_programs = registrar_cookie.max_governance_programs + 5;
+    let max_realms = registrar_cookie.max_realms + 5;
+
+    // Act
+    realm_voter_test
+        .resize_registrar(&mut registrar_cookie, max_governance_programs, max_realms)
+        .await?;
+
+    // Assert
+    let registrar_account = realm_voter_test
+        .bench
+        .get_account(&registrar_cookie.address)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        registrar_account.data.len(),
+        Registrar::get_space(max_governance_programs, max_realms)
+    );
+
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.governance_program_configs.len(), 1);
+    assert_eq!(
+        registrar.governance_program_configs[0],
+        governance_program_config_cookie.program_config
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resize_registrar_with_invalid_max_governance_programs_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let max_realms = registrar_cookie.max_realms;
+
+    // Act
+    let err = realm_voter_test
+        .resize_registrar(&mut registrar_cookie, 0, max_realms)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidMaxGovernancePrograms);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resize_registrar_with_invalid_max_realms_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    let max_governance_programs = registrar_cookie.max_governance_programs;
+
+    // Act
+    let err = realm_voter_test
+        .resize_registrar(&mut registrar_cookie, max_governance_programs, 0)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidMaxRealms);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resize_registrar_with_invalid_realm_authority_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = realm_voter_test
+        .resize_registrar_using_ix(
+            &mut registrar_cookie,
+            20,
+            20,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resize_registrar_with_size_increase_too_large_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_governance_programs = registrar_cookie.max_governance_programs;
+
+    // Act
+    let err = realm_voter_test
+        .resize_registrar(&mut registrar_cookie, max_governance_programs, u8::MAX)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::RegistrarSizeIncreaseTooLarge);
+
+    Ok(())
+}
@@ -1,8 +1,17 @@
+use crate::program_test::predecessor_plugin_test::{
+    PredecessorPluginTest, PredecessorVoterWeightRecordArgs,
+};
 use crate::program_test::realm_voter_test::RealmVoterTest;
-use gpl_realm_voter::{error::RealmVoterError, state::CollectionItemChangeType};
+use gpl_realm_voter::{
+    error::RealmVoterError,
+    state::{
+        CollectionItemChangeType, MaxVoterWeightMode, MembershipAggregation, MembershipRules,
+        MembershipWeightMode, RealmMemberWeightMode, VoterWeightAction,
+    },
+};
 use program_test::tools::*;
 use solana_program_test::*;
-use solana_sdk::transport::TransportError;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
 mod program_test;
 
 #[tokio::test]
@@ -74,6 +83,975 @@ async fn test_update_voter_weight_record() -> Result<(), TransportError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_update_voter_weight_record_with_deposit_weight_mode() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord with deposit for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record_and_deposit(&realm_cookie2, &token_owner_cookie, 200)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+            RealmMemberWeightMode::Deposit {
+                multiplier_bps: 5_000,
+                cap: 1_000,
+            },
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    let clock = realm_voter_test.bench.get_clock().await;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await?;
+
+    // Assert
+
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 100);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_deposit_weight_mode_capped(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord with deposit for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record_and_deposit(&realm_cookie2, &token_owner_cookie, 10000)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+            RealmMemberWeightMode::Deposit {
+                multiplier_bps: 5_000,
+                cap: 1_000,
+            },
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    let clock = realm_voter_test.bench.get_clock().await;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await?;
+
+    // Assert
+
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 1_000);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_configured_realm() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            Some(realm_cookie2.account.community_mint),
+            25,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 25);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_realm_not_configured_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Restrict membership to some other Realm
+    let realm_cookie3 = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie3,
+            CollectionItemChangeType::Upsert,
+            None,
+            25,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::RealmNotConfigured);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_last_realm_removed_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            25,
+        )
+        .await?;
+
+    // Removing the last Realm must not open membership to any Realm of the configured spl-governance instances
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Remove,
+            None,
+            0,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::RealmNotConfigured);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_governing_token_mint_not_allowed_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Restrict membership to the council of the Realm
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            realm_cookie2.account.config.council_mint,
+            25,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::GoverningTokenMintNotAllowed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_membership_rules() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord with deposit for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record_and_deposit(&realm_cookie2, &token_owner_cookie, 100)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_membership_rules(
+            &registrar_cookie,
+            MembershipRules {
+                min_governing_token_deposit_amount: 100,
+                max_outstanding_proposal_count: Some(0),
+            },
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_deposit_below_min_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord with deposit for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record_and_deposit(&realm_cookie2, &token_owner_cookie, 99)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_membership_rules(
+            &registrar_cookie,
+            MembershipRules {
+                min_governing_token_deposit_amount: 100,
+                max_outstanding_proposal_count: Some(0),
+            },
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::TokenOwnerRecordDepositBelowMin);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_aggregated_memberships() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecords for other Realms
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let realm_cookie3 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie3 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie3, &token_owner_cookie)
+        .await?;
+
+    let realm_cookie4 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie4 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie4, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Memberships can only be aggregated across the Realms of the Realm allowlist
+    for member_realm_cookie in [&realm_cookie2, &realm_cookie3, &realm_cookie4] {
+        realm_voter_test
+            .configure_realm(
+                &registrar_cookie,
+                member_realm_cookie,
+                CollectionItemChangeType::Upsert,
+                Some(member_realm_cookie.account.community_mint),
+                10,
+            )
+            .await?;
+    }
+
+    realm_voter_test
+        .configure_membership_aggregation(&registrar_cookie, MembershipAggregation::Sum)
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_memberships(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            None,
+            None,
+            &[&token_owner_record_cookie3, &token_owner_record_cookie4],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 30);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_capped_aggregated_memberships(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecords for other Realms
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let realm_cookie3 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie3 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie3, &token_owner_cookie)
+        .await?;
+
+    let realm_cookie4 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie4 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie4, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Memberships can only be aggregated across the Realms of the Realm allowlist
+    for member_realm_cookie in [&realm_cookie2, &realm_cookie3, &realm_cookie4] {
+        realm_voter_test
+            .configure_realm(
+                &registrar_cookie,
+                member_realm_cookie,
+                CollectionItemChangeType::Upsert,
+                Some(member_realm_cookie.account.community_mint),
+                10,
+            )
+            .await?;
+    }
+
+    realm_voter_test
+        .configure_membership_aggregation(&registrar_cookie, MembershipAggregation::CappedSum(25))
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_memberships(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            None,
+            None,
+            &[&token_owner_record_cookie3, &token_owner_record_cookie4],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 25);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_membership_aggregation_disabled(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecords for other Realms
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let realm_cookie3 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie3 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie3, &token_owner_cookie)
+        .await?;
+
+    let realm_cookie4 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie4 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie4, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_membership_aggregation(&registrar_cookie, MembershipAggregation::Disabled)
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_memberships(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            None,
+            None,
+            &[&token_owner_record_cookie3, &token_owner_record_cookie4],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_duplicated_realm_membership_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecords for other Realms
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let realm_cookie3 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie3 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie3, &token_owner_cookie)
+        .await?;
+
+    let realm_cookie4 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie4 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie4, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Memberships can only be aggregated across the Realms of the Realm allowlist
+    for member_realm_cookie in [&realm_cookie2, &realm_cookie3, &realm_cookie4] {
+        realm_voter_test
+            .configure_realm(
+                &registrar_cookie,
+                member_realm_cookie,
+                CollectionItemChangeType::Upsert,
+                Some(member_realm_cookie.account.community_mint),
+                10,
+            )
+            .await?;
+    }
+
+    realm_voter_test
+        .configure_membership_aggregation(&registrar_cookie, MembershipAggregation::Sum)
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record_with_memberships(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            None,
+            None,
+            &[
+                &token_owner_record_cookie3,
+                &token_owner_record_cookie4,
+                &token_owner_record_cookie3,
+            ],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::DuplicatedRealmMembershipDetected);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_update_voter_weight_record_with_token_owner_record_from_own_realm_not_allowed_error(
 ) -> Result<(), TransportError> {
@@ -82,12 +1060,947 @@ async fn test_update_voter_weight_record_with_token_owner_record_from_own_realm_
 
     let realm_cookie = realm_voter_test.governance.with_realm().await?;
 
-    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::TokenOwnerRecordFromOwnRealmNotAllowed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_for_member_from_not_configured_governance_program_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+
+    assert_realm_voter_err(err, RealmVoterError::GovernanceProgramNotConfigured);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_token_owner_record_must_match_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let token_owner_cookie2 = realm_voter_test.bench.with_wallet().await;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie2)
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+
+    assert_realm_voter_err(err, RealmVoterError::GoverningTokenOwnerMustMatch);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_voter_membership() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // MemberCount requires the Realm allowlist
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+        )
+        .await?;
+
+    let voter_membership_cookie = realm_voter_test
+        .with_voter_membership(
+            &registrar_cookie,
+            &token_owner_cookie,
+            &voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            &max_voter_weight_record_cookie,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_voter_membership(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            &voter_membership_cookie.address,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_missing_voter_membership_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // MemberCount requires the Realm allowlist
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::MissingVoterMembership);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_voter_membership_for_other_voter_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // MemberCount requires the Realm allowlist
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+        )
+        .await?;
+
+    // Create VoterMembership for other voter
+    let token_owner_cookie2 = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie2 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie2)
+        .await?;
+
+    let voter_weight_record_cookie2 = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie2)
+        .await?;
+
+    let voter_membership_cookie2 = realm_voter_test
+        .with_voter_membership(
+            &registrar_cookie,
+            &token_owner_cookie2,
+            &voter_weight_record_cookie2,
+            &token_owner_record_cookie2,
+            &max_voter_weight_record_cookie,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record_with_voter_membership(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            &voter_membership_cookie2.address,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidVoterMembership);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_expired_voter_membership_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // MemberCount requires the Realm allowlist
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+        )
+        .await?;
+
+    let voter_membership_cookie = realm_voter_test
+        .with_voter_membership(
+            &registrar_cookie,
+            &token_owner_cookie,
+            &voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            &max_voter_weight_record_cookie,
+        )
+        .await?;
+
+    // Reset the member count by switching the mode back and forth
+    realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::Manual,
+        )
+        .await?;
+
+    realm_voter_test.bench.advance_clock().await;
+
+    realm_voter_test
+        .configure_max_voter_weight_mode(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            MaxVoterWeightMode::MemberCount,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record_with_voter_membership(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            &voter_membership_cookie.address,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::VoterMembershipExpired);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_predecessor_voter_weight_added(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            MembershipWeightMode::Add,
+        )
+        .await?;
+
+    let proposal = Keypair::new().pubkey();
+
+    let predecessor_voter_weight_record_cookie = realm_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record(
+            &realm_cookie,
+            &token_owner_cookie,
+            Some(PredecessorVoterWeightRecordArgs {
+                voter_weight: 25,
+                weight_action: Some(VoterWeightAction::CastVote),
+                weight_action_target: Some(proposal),
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let clock = realm_voter_test.bench.get_clock().await;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_input(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            Some(&predecessor_voter_weight_record_cookie.address),
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 35);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+    assert_eq!(
+        voter_weight_record.weight_action,
+        Some(VoterWeightAction::CastVote)
+    );
+    assert_eq!(voter_weight_record.weight_action_target, Some(proposal));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_predecessor_voter_weight_overridden(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            MembershipWeightMode::Override,
+        )
+        .await?;
+
+    let predecessor_voter_weight_record_cookie = realm_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record(&realm_cookie, &token_owner_cookie, None)
+        .await?;
+
+    let clock = realm_voter_test.bench.get_clock().await;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_input(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            Some(&predecessor_voter_weight_record_cookie.address),
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+    assert_eq!(voter_weight_record.weight_action, None);
+    assert_eq!(voter_weight_record.weight_action_target, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_token_owner_record_deposit_added(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            None,
+            MembershipWeightMode::Add,
+        )
+        .await?;
+
+    // Create TokenOwnerRecord with deposit for the Registrar Realm
+    let input_token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record_and_deposit(&realm_cookie, &token_owner_cookie, 100)
+        .await?;
+
+    let clock = realm_voter_test.bench.get_clock().await;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_input(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            Some(&input_token_owner_record_cookie.address),
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 110);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+    assert_eq!(voter_weight_record.weight_action, None);
+    assert_eq!(voter_weight_record.weight_action_target, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_missing_input_voter_weight_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            None,
+            MembershipWeightMode::Add,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record_with_input(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::MissingInputVoterWeight);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_input_token_owner_record_for_other_realm_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            None,
+            MembershipWeightMode::Add,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record_with_input(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            Some(&token_owner_record_cookie.address),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidInputTokenOwnerRecord);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_input_token_owner_record_for_other_voter_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
 
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
     let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
     let token_owner_record_cookie = realm_voter_test
         .governance
-        .with_token_owner_record(&realm_cookie, &token_owner_cookie)
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
         .await?;
 
     let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
@@ -104,32 +2017,64 @@ async fn test_update_voter_weight_record_with_token_owner_record_from_own_realm_
         .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
         .await?;
 
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            None,
+            MembershipWeightMode::Add,
+        )
+        .await?;
+
+    let token_owner_cookie2 = realm_voter_test.bench.with_wallet().await;
+
+    let input_token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record_and_deposit(&realm_cookie, &token_owner_cookie2, 100)
+        .await?;
+
     // Act
     let err = realm_voter_test
-        .update_voter_weight_record(
+        .update_voter_weight_record_with_input(
             &registrar_cookie,
             &mut voter_weight_record_cookie,
             &token_owner_record_cookie,
+            Some(&input_token_owner_record_cookie.address),
         )
         .await
         .err()
         .unwrap();
 
     // Assert
-    assert_realm_voter_err(err, RealmVoterError::TokenOwnerRecordFromOwnRealmNotAllowed);
+    assert_realm_voter_err(
+        err,
+        RealmVoterError::InvalidPredecessorVoterWeightRecordGovTokenOwner,
+    );
 
     Ok(())
 }
 
 #[tokio::test]
-async fn test_update_voter_weight_record_for_member_from_not_configured_governance_program_error(
+async fn test_update_voter_weight_record_with_predecessor_voter_weight_record_not_owned_by_predecessor_error(
 ) -> Result<(), TransportError> {
     // Arrange
     let mut realm_voter_test = RealmVoterTest::start_new().await;
 
     let realm_cookie = realm_voter_test.governance.with_realm().await?;
 
-    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
 
     // Create TokenOwnerRecord for other Realm
     let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
@@ -139,37 +2084,82 @@ async fn test_update_voter_weight_record_for_member_from_not_configured_governan
         .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
         .await?;
 
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
     let mut voter_weight_record_cookie = realm_voter_test
         .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
         .await?;
 
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            MembershipWeightMode::Add,
+        )
+        .await?;
+
+    // Create TokenOwnerRecord with deposit for the Registrar Realm which is not owned by the predecessor plugin
+    let input_token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record_and_deposit(&realm_cookie, &token_owner_cookie, 100)
+        .await?;
+
     // Act
     let err = realm_voter_test
-        .update_voter_weight_record(
+        .update_voter_weight_record_with_input(
             &registrar_cookie,
             &mut voter_weight_record_cookie,
             &token_owner_record_cookie,
+            Some(&input_token_owner_record_cookie.address),
         )
         .await
         .err()
         .unwrap();
 
     // Assert
-
-    assert_realm_voter_err(err, RealmVoterError::GovernanceProgramNotConfigured);
+    assert_realm_voter_err(err, RealmVoterError::InvalidPredecessorVoterWeightRecord);
 
     Ok(())
 }
 
 #[tokio::test]
-async fn test_update_voter_weight_record_with_token_owner_record_must_match_error(
+async fn test_update_voter_weight_record_with_predecessor_voter_weight_record_for_other_voter_error(
 ) -> Result<(), TransportError> {
     // Arrange
     let mut realm_voter_test = RealmVoterTest::start_new().await;
 
     let realm_cookie = realm_voter_test.governance.with_realm().await?;
 
-    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
 
     let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
 
@@ -181,6 +2171,69 @@ async fn test_update_voter_weight_record_with_token_owner_record_must_match_erro
         )
         .await?;
 
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            MembershipWeightMode::Add,
+        )
+        .await?;
+
+    let token_owner_cookie2 = realm_voter_test.bench.with_wallet().await;
+
+    let predecessor_voter_weight_record_cookie = realm_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record(&realm_cookie, &token_owner_cookie2, None)
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record_with_input(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            Some(&predecessor_voter_weight_record_cookie.address),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(
+        err,
+        RealmVoterError::InvalidPredecessorVoterWeightRecordGovTokenOwner,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_expired_predecessor_voter_weight_record_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
     // Create TokenOwnerRecord for other Realm
     let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
     let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
@@ -189,26 +2242,140 @@ async fn test_update_voter_weight_record_with_token_owner_record_must_match_erro
         .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
         .await?;
 
-    let token_owner_cookie2 = realm_voter_test.bench.with_wallet().await;
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
 
     let mut voter_weight_record_cookie = realm_voter_test
-        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie2)
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            MembershipWeightMode::Add,
+        )
+        .await?;
+
+    let predecessor_voter_weight_record_cookie = realm_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record(
+            &realm_cookie,
+            &token_owner_cookie,
+            Some(PredecessorVoterWeightRecordArgs {
+                voter_weight_expiry: Some(0),
+                ..Default::default()
+            }),
+        )
         .await?;
 
+    realm_voter_test.bench.advance_clock().await;
+
     // Act
     let err = realm_voter_test
-        .update_voter_weight_record(
+        .update_voter_weight_record_with_input(
             &registrar_cookie,
             &mut voter_weight_record_cookie,
             &token_owner_record_cookie,
+            Some(&predecessor_voter_weight_record_cookie.address),
         )
         .await
         .err()
         .unwrap();
 
     // Assert
+    assert_realm_voter_err(err, RealmVoterError::PredecessorVoterWeightRecordExpired);
 
-    assert_realm_voter_err(err, RealmVoterError::GoverningTokenOwnerMustMatch);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_missing_predecessor_voter_weight_record_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &mut registrar_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            MembershipWeightMode::Override,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record_with_input(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::MissingInputVoterWeight);
 
     Ok(())
 }
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum TokenHaverError {
+    #[msg("Invalid Realm Authority")]
+    InvalidRealmAuthority,
+
+    #[msg("Invalid Realm for Registrar")]
+    InvalidRealmForRegistrar,
+
+    #[msg("Invalid VoterWeightRecord Realm")]
+    InvalidVoterWeightRecordRealm,
+
+    #[msg("Invalid VoterWeightRecord Mint")]
+    InvalidVoterWeightRecordMint,
+
+    #[msg("Mint not configured")]
+    MintNotConfigured,
+
+    #[msg("Max mints can't be less than the number of configured mints")]
+    InvalidMaxMints,
+
+    #[msg("Voter does not own token account")]
+    VoterDoesNotOwnTokenAccount,
+
+    #[msg("Token account is frozen")]
+    TokenAccountFrozen,
+
+    #[msg("Duplicated token account detected")]
+    DuplicatedTokenAccountDetected,
+}
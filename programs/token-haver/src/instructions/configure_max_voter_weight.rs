@@ -0,0 +1,64 @@
+use crate::error::TokenHaverError;
+use crate::state::max_voter_weight_record::MaxVoterWeightRecord;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+/// Configures max_voter_weight for Registrar
+/// It also sets MaxVoterWeightRecord.max_voter_weight to the provided value
+/// MaxVoterWeightRecord.max_voter_weight is static and can only be set using this instruction and hence it never expires
+#[derive(Accounts)]
+pub struct ConfigureMaxVoterWeight<'info> {
+    /// The Registrar for the given realm and governing_token_mint
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        address = registrar.realm @ TokenHaverError::InvalidRealmForRegistrar,
+        owner = registrar.governance_program_id
+     )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// MaxVoterWeightRecord for the given registrar.realm and registrar.governing_token_mint
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ TokenHaverError::InvalidVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ TokenHaverError::InvalidVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+pub fn configure_max_voter_weight(
+    ctx: Context<ConfigureMaxVoterWeight>,
+    max_voter_weight: u64,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+    // Note: max_voter_weight is stored on Registrar for consistency sake to have the registrar as the source of truth for configuration
+    // And MaxVoterWeightRecord.max_voter_weight is used as data exchange account
+    registrar.max_voter_weight = max_voter_weight;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        TokenHaverError::InvalidRealmAuthority
+    );
+
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+    max_voter_weight_record.max_voter_weight = max_voter_weight;
+
+    // max_voter_weight can only be updated using this instruction and it never expires
+    max_voter_weight_record.max_voter_weight_expiry = None;
+
+    Ok(())
+}
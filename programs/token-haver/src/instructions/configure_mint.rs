@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use gpl_plugin_core::realm::is_realm_authority;
+
+use crate::error::TokenHaverError;
+use crate::state::{MintConfig, Registrar};
+
+/// Creates or updates configuration for SPL token mints to define which mints can be used to grant governance power
+#[derive(Accounts)]
+#[instruction(change_type: crate::state::CollectionItemChangeType, voter_weight: u64)]
+pub struct ConfigureMint<'info> {
+    /// Registrar which we configure the provided mint for
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ TokenHaverError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign the transaction and must match realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// SPL token mint which will be inserted, updated or removed to configured mints allowed to participate in governance
+    pub mint: Account<'info, Mint>,
+}
+
+pub fn configure_mint(
+    ctx: Context<ConfigureMint>,
+    change_type: crate::state::CollectionItemChangeType,
+    voter_weight: u64,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        TokenHaverError::InvalidRealmAuthority
+    );
+
+    let mint = &ctx.accounts.mint;
+
+    let mint_config = MintConfig {
+        mint: mint.key(),
+        voter_weight,
+        reserved: [0; 8],
+    };
+
+    let mint_config_idx = registrar
+        .mint_configs
+        .iter()
+        .position(|mc| mc.mint == mint.key());
+
+    match (change_type, mint_config_idx) {
+        // Update
+        (crate::state::CollectionItemChangeType::Upsert, Some(config_idx)) => {
+            registrar.mint_configs[config_idx] = mint_config;
+        }
+        // Insert
+        (crate::state::CollectionItemChangeType::Upsert, None) => {
+            // Note: push() would throw an error if we exceed
+            // max_mints specified when the Registrar was created or last resized
+            registrar.mint_configs.push(mint_config);
+        }
+        (crate::state::CollectionItemChangeType::Remove, Some(config_idx)) => {
+            registrar.mint_configs.remove(config_idx);
+        }
+
+        (crate::state::CollectionItemChangeType::Remove, None) => {
+            return err!(TokenHaverError::MintNotConfigured)
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use crate::error::TokenHaverError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_plugin_core::realm::is_realm_authority;
+
+/// Resizes Registrar to allow a different number of mints to be configured
+/// The rent difference is paid by the payer when the account grows
+#[derive(Accounts)]
+#[instruction(max_mints: u8)]
+pub struct ResizeRegistrar<'info> {
+    /// The Token Haver Registrar
+    #[account(
+        mut,
+        realloc = Registrar::get_space(max_mints),
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        address = registrar.realm @ TokenHaverError::InvalidRealmForRegistrar,
+        owner = registrar.governance_program_id
+     )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match realm.authority
+    pub realm_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Resizes Registrar to the space required for max_mints
+/// max_mints can't be less than the number of currently configured mints
+pub fn resize_registrar(ctx: Context<ResizeRegistrar>, max_mints: u8) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    require!(
+        is_realm_authority(
+            &registrar.governance_program_id,
+            &ctx.accounts.realm,
+            &registrar.governing_token_mint,
+            &ctx.accounts.realm_authority.key(),
+        )?,
+        TokenHaverError::InvalidRealmAuthority
+    );
+
+    require!(
+        registrar.mint_configs.len() <= max_mints as usize,
+        TokenHaverError::InvalidMaxMints
+    );
+
+    Ok(())
+}
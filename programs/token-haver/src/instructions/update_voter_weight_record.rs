@@ -0,0 +1,68 @@
+use crate::error::TokenHaverError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Updates VoterWeightRecord based on holding SPL tokens of any of the configured mints
+/// The token accounts of the voter are provided as remaining accounts
+///
+/// This instruction sets VoterWeightRecord.voter_weight which is valid for the current slot only
+/// and must be executed inside the same transaction as the corresponding spl-gov instruction
+///
+/// Warning: The plugin doesn't record which token accounts were used to vote on a proposal
+/// A holder can vote, transfer the tokens to another wallet and vote on the same proposal again
+/// The plugin is only safe when the tokens of the configured mints can't be transferred while proposals are voted on
+/// for example when the tokens are non-transferable or locked outside of the plugin for the duration of the vote
+#[derive(Accounts)]
+pub struct UpdateVoterWeightRecord<'info> {
+    /// The TokenHaver voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        constraint = voter_weight_record.realm == registrar.realm
+        @ TokenHaverError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ TokenHaverError::InvalidVoterWeightRecordMint,
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+}
+
+/// Sets VoterWeightRecord.voter_weight to the highest voter weight of the configured mints the voter holds
+/// Holding tokens of several mints doesn't accumulate the voter weight
+/// If the voter doesn't hold tokens of any of the configured mints then the voter weight is 0
+pub fn update_voter_weight_record(ctx: Context<UpdateVoterWeightRecord>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+    let governing_token_owner = voter_weight_record.governing_token_owner;
+
+    let mut voter_weight = 0u64;
+    let mut unique_token_accounts = vec![];
+
+    for token_account_info in ctx.remaining_accounts.iter() {
+        // Ensure the same token account was not provided more than once
+        if unique_token_accounts.contains(token_account_info.key) {
+            return Err(TokenHaverError::DuplicatedTokenAccountDetected.into());
+        }
+        unique_token_accounts.push(*token_account_info.key);
+
+        let token_account_voter_weight = resolve_token_account_voter_weight(
+            registrar,
+            &governing_token_owner,
+            token_account_info,
+        )?;
+
+        voter_weight = voter_weight.max(token_account_voter_weight);
+    }
+
+    voter_weight_record.voter_weight = voter_weight;
+
+    // Record is only valid as of the current slot
+    voter_weight_record.voter_weight_expiry = Some(Clock::get()?.slot);
+
+    // The record is only valid for the current slot and hence it can be used for any action and target
+    voter_weight_record.weight_action = None;
+    voter_weight_record.weight_action_target = None;
+
+    Ok(())
+}
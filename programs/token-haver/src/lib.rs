@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+pub mod error;
+
+mod instructions;
+use instructions::*;
+
+pub mod state;
+
+pub mod tools;
+
+declare_id!("7gobfUihgoxA14RUnVaseoah89ggCgYAzgz1JoaPAXam");
+
+#[program]
+pub mod token_haver {
+
+    use super::*;
+
+    pub fn create_registrar(ctx: Context<CreateRegistrar>, max_mints: u8) -> Result<()> {
+        log_version();
+        instructions::create_registrar(ctx, max_mints)
+    }
+    pub fn resize_registrar(ctx: Context<ResizeRegistrar>, max_mints: u8) -> Result<()> {
+        log_version();
+        instructions::resize_registrar(ctx, max_mints)
+    }
+    pub fn create_voter_weight_record(
+        ctx: Context<CreateVoterWeightRecord>,
+        governing_token_owner: Pubkey,
+    ) -> Result<()> {
+        log_version();
+        instructions::create_voter_weight_record(ctx, governing_token_owner)
+    }
+    pub fn create_max_voter_weight_record(ctx: Context<CreateMaxVoterWeightRecord>) -> Result<()> {
+        log_version();
+        instructions::create_max_voter_weight_record(ctx)
+    }
+    pub fn update_voter_weight_record(ctx: Context<UpdateVoterWeightRecord>) -> Result<()> {
+        log_version();
+        instructions::update_voter_weight_record(ctx)
+    }
+    pub fn configure_max_voter_weight(
+        ctx: Context<ConfigureMaxVoterWeight>,
+        max_voter_weight: u64,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_max_voter_weight(ctx, max_voter_weight)
+    }
+
+    pub fn configure_mint(
+        ctx: Context<ConfigureMint>,
+        change_type: crate::state::CollectionItemChangeType,
+        voter_weight: u64,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_mint(ctx, change_type, voter_weight)
+    }
+}
+
+fn log_version() {
+    // TODO: Check if Anchor allows to log it before instruction is deserialized
+    msg!("VERSION:{:?}", env!("CARGO_PKG_VERSION"));
+}
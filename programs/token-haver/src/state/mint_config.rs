@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+/// Configuration of an SPL token mint used to grant governance power
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct MintConfig {
+    /// The mint of the configured SPL token
+    pub mint: Pubkey,
+
+    /// Vote weight assigned to a holder of a non-zero balance of the mint
+    pub voter_weight: u64,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 8],
+}
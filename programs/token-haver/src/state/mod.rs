@@ -0,0 +1,10 @@
+pub use registrar::*;
+pub mod registrar;
+
+pub use mint_config::*;
+pub mod mint_config;
+
+pub mod max_voter_weight_record;
+
+pub use voter_weight_record::*;
+pub mod voter_weight_record;
@@ -0,0 +1,144 @@
+use crate::{
+    error::TokenHaverError,
+    id,
+    state::MintConfig,
+    tools::{
+        anchor::DISCRIMINATOR_SIZE,
+        spl_token::{get_spl_token_amount, is_spl_token_account_frozen},
+    },
+};
+use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use solana_program::pubkey::PUBKEY_BYTES;
+use spl_governance::tools::spl_token::{get_spl_token_mint, get_spl_token_owner};
+
+/// Enum defining collection item change type
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum CollectionItemChangeType {
+    /// Update item in the collection if it already exists and Insert the item if it doesn't
+    Upsert,
+    /// Remove item from the collection
+    Remove,
+}
+
+/// Registrar which stores the SPL token mints configuration for the given Realm
+#[account]
+#[derive(Debug, PartialEq)]
+pub struct Registrar {
+    /// spl-governance program the Realm belongs to
+    pub governance_program_id: Pubkey,
+
+    /// Realm of the Registrar
+    pub realm: Pubkey,
+
+    /// Governing token mint the Registrar is for
+    /// It can either be the Community or the Council mint of the Realm
+    /// When the plugin is enabled the mint is only used as the identity of the governing power (voting population)
+    /// and the actual token of the mint is not used
+    pub governing_token_mint: Pubkey,
+
+    /// SPL token mints used for governance power
+    /// A holder of a non-zero balance of any of the mints is given the voter weight configured for the mint
+    /// Note: Votes are not tracked per token account and the mints should be non-transferable during voting
+    /// otherwise the same tokens can be used to vote multiple times (see update_voter_weight_record)
+    pub mint_configs: Vec<MintConfig>,
+
+    /// Max voter weight (expressed in governing_token_mint decimal units) is used to establish the theoretical Max Attendance Quorum which is then used to calculate Approval Quorum
+    /// This manual configuration is a rough estimate because it's not practical to calculate on-chain the number of all holders of the configured mints
+    pub max_voter_weight: u64,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 128],
+}
+
+impl Registrar {
+    pub fn get_space(max_mints: u8) -> usize {
+        DISCRIMINATOR_SIZE
+            + PUBKEY_BYTES * 3
+            + 4
+            + max_mints as usize * (PUBKEY_BYTES + 8 + 8)
+            + 8
+            + 128
+    }
+
+    pub fn get_mint_config(&self, mint: &Pubkey) -> Result<&MintConfig> {
+        self.mint_configs
+            .iter()
+            .find(|mc| mc.mint == *mint)
+            .ok_or_else(|| TokenHaverError::MintNotConfigured.into())
+    }
+}
+
+pub use gpl_plugin_core::registrar::get_registrar_seeds;
+
+/// Returns Registrar PDA address
+pub fn get_registrar_address(realm: &Pubkey, governing_token_mint: &Pubkey) -> Pubkey {
+    gpl_plugin_core::registrar::get_registrar_address(&id(), realm, governing_token_mint)
+}
+
+/// Resolves the voter weight for the given token account
+///
+/// The token account must be owned by the voter, can't be frozen and its mint must be configured
+/// A token account with zero balance is accepted and resolves to zero voter weight
+pub fn resolve_token_account_voter_weight(
+    registrar: &Registrar,
+    governing_token_owner: &Pubkey,
+    token_account_info: &AccountInfo,
+) -> Result<u64> {
+    let token_amount = get_spl_token_amount(token_account_info)?;
+
+    let token_owner = get_spl_token_owner(token_account_info)?;
+
+    // voter_weight_record.governing_token_owner must be the owner of the token account
+    require!(
+        token_owner == *governing_token_owner,
+        TokenHaverError::VoterDoesNotOwnTokenAccount
+    );
+
+    // The freeze authority of the mint can freeze the token account to suspend the holder's governance power
+    require!(
+        !is_spl_token_account_frozen(token_account_info)?,
+        TokenHaverError::TokenAccountFrozen
+    );
+
+    let token_mint = get_spl_token_mint(token_account_info)?;
+
+    let mint_config = registrar.get_mint_config(&token_mint)?;
+
+    if token_amount == 0 {
+        return Ok(0);
+    }
+
+    Ok(mint_config.voter_weight)
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = Registrar::get_space(3);
+
+        let registrar = Registrar {
+            governance_program_id: Pubkey::default(),
+            realm: Pubkey::default(),
+            governing_token_mint: Pubkey::default(),
+            mint_configs: vec![
+                MintConfig::default(),
+                MintConfig::default(),
+                MintConfig::default(),
+            ],
+            max_voter_weight: 100,
+            reserved: [0; 128],
+        };
+
+        // Act
+        let actual_space = DISCRIMINATOR_SIZE + registrar.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+pub use gpl_plugin_core::voter_weight_action::VoterWeightAction;
+
+gpl_plugin_core::voter_weight_record_account!();
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::tools::anchor::DISCRIMINATOR_SIZE;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = VoterWeightRecord::get_space();
+
+        // Act
+        let actual_space =
+            DISCRIMINATOR_SIZE + VoterWeightRecord::default().try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+}
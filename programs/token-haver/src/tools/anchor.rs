@@ -0,0 +1 @@
+pub use gpl_plugin_core::anchor::DISCRIMINATOR_SIZE;
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use arrayref::array_ref;
+use spl_governance::tools::spl_token::assert_is_valid_spl_token_account;
+use spl_token::state::AccountState;
+
+/// Computationally cheap method to get amount from a token account
+/// It reads amount without deserializing full account data
+pub fn get_spl_token_amount(token_account_info: &AccountInfo) -> Result<u64> {
+    assert_is_valid_spl_token_account(token_account_info)?;
+
+    // TokeAccount layout:   mint(32), owner(32), amount(8), ...
+    let data = token_account_info.try_borrow_data()?;
+    let amount_bytes = array_ref![data, 64, 8];
+
+    Ok(u64::from_le_bytes(*amount_bytes))
+}
+
+/// Computationally cheap method to check whether a token account is frozen
+/// It reads state without deserializing full account data
+pub fn is_spl_token_account_frozen(token_account_info: &AccountInfo) -> Result<bool> {
+    assert_is_valid_spl_token_account(token_account_info)?;
+
+    // TokeAccount layout:   mint(32), owner(32), amount(8), delegate(36), state(1), ...
+    let data = token_account_info.try_borrow_data()?;
+    let state = data[108];
+
+    Ok(state == AccountState::Frozen as u8)
+}
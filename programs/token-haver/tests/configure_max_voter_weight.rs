@@ -0,0 +1,119 @@
+use gpl_token_haver::error::TokenHaverError;
+use program_test::token_haver_test::TokenHaverTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_configure_max_voter_weight() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mut max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    token_haver_test
+        .configure_max_voter_weight(&registrar_cookie, &mut max_voter_weight_record_cookie, 110)
+        .await?;
+
+    // Assert
+
+    let registrar = token_haver_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.max_voter_weight, 110);
+
+    let max_voter_weight_record = token_haver_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 110);
+
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+    assert_eq!(max_voter_weight_record.realm, realm_cookie.address);
+    assert_eq!(
+        max_voter_weight_record.governing_token_mint,
+        realm_cookie.account.community_mint
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_max_voter_weight_with_realm_authority_must_sign_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mut max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = token_haver_test
+        .configure_max_voter_weight_using_ix(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            110,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_max_voter_weight_with_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mut max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = token_haver_test
+        .configure_max_voter_weight_using_ix(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            110,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::InvalidRealmAuthority);
+
+    Ok(())
+}
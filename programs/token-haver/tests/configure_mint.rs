@@ -0,0 +1,259 @@
+use gpl_token_haver::{error::TokenHaverError, state::CollectionItemChangeType};
+use program_test::token_haver_test::TokenHaverTest;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+mod program_test;
+use program_test::tools::{assert_anchor_err, assert_token_haver_err};
+
+#[tokio::test]
+async fn test_configure_mint() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    // Act
+    let mint_config_cookie = token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie,
+            CollectionItemChangeType::Upsert,
+            10,
+        )
+        .await?;
+
+    // Assert
+    let registrar = token_haver_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.mint_configs.len(), 1);
+    assert_eq!(registrar.mint_configs[0], mint_config_cookie.mint_config);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_mint_with_multiple_mints() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie1 = token_haver_test.bench.with_mint().await?;
+    let mint_cookie2 = token_haver_test.bench.with_mint().await?;
+
+    // Act
+    token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie1,
+            CollectionItemChangeType::Upsert,
+            10,
+        )
+        .await?;
+
+    token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie2,
+            CollectionItemChangeType::Upsert,
+            20,
+        )
+        .await?;
+
+    // Assert
+    let registrar = token_haver_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.mint_configs.len(), 2);
+    assert_eq!(registrar.mint_configs[0].mint, mint_cookie1.address);
+    assert_eq!(registrar.mint_configs[1].mint, mint_cookie2.address);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_mint_for_existing_mint() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie,
+            CollectionItemChangeType::Upsert,
+            10,
+        )
+        .await?;
+
+    token_haver_test.bench.advance_clock().await;
+
+    // Act
+    let mint_config_cookie = token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie,
+            CollectionItemChangeType::Upsert,
+            20,
+        )
+        .await?;
+
+    // Assert
+    let registrar = token_haver_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.mint_configs.len(), 1);
+    assert_eq!(registrar.mint_configs[0], mint_config_cookie.mint_config);
+    assert_eq!(registrar.mint_configs[0].voter_weight, 20);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_mint_with_realm_authority_must_sign_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    // Act
+    let err = token_haver_test
+        .configure_mint_using_ix(
+            &registrar_cookie,
+            &mint_cookie,
+            CollectionItemChangeType::Upsert,
+            10,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_mint_with_invalid_realm_authority_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = token_haver_test
+        .configure_mint_using_ix(
+            &registrar_cookie,
+            &mint_cookie,
+            CollectionItemChangeType::Upsert,
+            10,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_mint_configuration() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie,
+            CollectionItemChangeType::Upsert,
+            10,
+        )
+        .await?;
+
+    // Act
+    token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie,
+            CollectionItemChangeType::Remove,
+            0,
+        )
+        .await?;
+
+    // Assert
+    let registrar = token_haver_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.mint_configs.len(), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_mint_configuration_with_mint_not_configured_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    // Act
+    let err = token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie,
+            CollectionItemChangeType::Remove,
+            0,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::MintNotConfigured);
+
+    Ok(())
+}
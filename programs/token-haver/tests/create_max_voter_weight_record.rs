@@ -0,0 +1,66 @@
+use crate::program_test::token_haver_test::TokenHaverTest;
+use program_test::tools::assert_ix_err;
+use solana_program::instruction::InstructionError;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_create_max_voter_weight_record() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    let max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Assert
+
+    let max_voter_weight_record = token_haver_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(
+        max_voter_weight_record_cookie.account,
+        max_voter_weight_record
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_max_voter_weight_record_with_already_exists_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_haver_test.bench.advance_clock().await;
+
+    // Act
+    let err = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+
+    // InstructionError::Custom(0) is returned for TransactionError::AccountInUse
+    assert_ix_err(err, InstructionError::Custom(0));
+
+    Ok(())
+}
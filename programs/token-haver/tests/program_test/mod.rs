@@ -0,0 +1,4 @@
+pub mod governance_test;
+pub mod program_test_bench;
+pub mod token_haver_test;
+pub mod tools;
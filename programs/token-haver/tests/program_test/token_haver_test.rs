@@ -0,0 +1,527 @@
+use std::sync::Arc;
+
+use anchor_lang::prelude::Pubkey;
+
+use gpl_token_haver::state::max_voter_weight_record::{
+    get_max_voter_weight_record_address, MaxVoterWeightRecord,
+};
+use gpl_token_haver::state::*;
+
+use solana_program_test::{BanksClientError, ProgramTest};
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+use crate::program_test::governance_test::GovernanceTest;
+use crate::program_test::program_test_bench::{MintCookie, ProgramTestBench, TokenAccountCookie};
+
+use crate::program_test::governance_test::RealmCookie;
+use crate::program_test::program_test_bench::WalletCookie;
+
+use crate::program_test::tools::NopOverride;
+
+#[derive(Debug, PartialEq)]
+pub struct RegistrarCookie {
+    pub address: Pubkey,
+    pub account: Registrar,
+
+    pub realm_authority: Keypair,
+    pub max_mints: u8,
+}
+
+pub struct VoterWeightRecordCookie {
+    pub address: Pubkey,
+    pub account: VoterWeightRecord,
+}
+
+pub struct MaxVoterWeightRecordCookie {
+    pub address: Pubkey,
+    pub account: MaxVoterWeightRecord,
+}
+
+pub struct MintConfigCookie {
+    pub mint_config: MintConfig,
+}
+
+pub struct TokenHaverTest {
+    pub program_id: Pubkey,
+    pub bench: Arc<ProgramTestBench>,
+    pub governance: GovernanceTest,
+}
+
+impl TokenHaverTest {
+    #[allow(dead_code)]
+    pub fn add_program(program_test: &mut ProgramTest) {
+        program_test.add_program("gpl_token_haver", gpl_token_haver::id(), None);
+    }
+
+    #[allow(dead_code)]
+    pub async fn start_new() -> Self {
+        let mut program_test = ProgramTest::default();
+
+        TokenHaverTest::add_program(&mut program_test);
+        GovernanceTest::add_program(&mut program_test);
+
+        let program_id = gpl_token_haver::id();
+
+        let bench = ProgramTestBench::start_new(program_test).await;
+        let bench_rc = Arc::new(bench);
+
+        let governance_bench =
+            GovernanceTest::new(bench_rc.clone(), Some(program_id), Some(program_id));
+
+        Self {
+            program_id,
+            bench: bench_rc,
+            governance: governance_bench,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_registrar(
+        &mut self,
+        realm_cookie: &RealmCookie,
+    ) -> Result<RegistrarCookie, BanksClientError> {
+        self.with_registrar_using_ix(realm_cookie, NopOverride, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_registrar_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<RegistrarCookie, BanksClientError> {
+        let registrar_key =
+            get_registrar_address(&realm_cookie.address, &realm_cookie.account.community_mint);
+
+        let max_mints = 10;
+
+        let data =
+            anchor_lang::InstructionData::data(&gpl_token_haver::instruction::CreateRegistrar {
+                max_mints,
+            });
+
+        let accounts = anchor_lang::ToAccountMetas::to_account_metas(
+            &gpl_token_haver::accounts::CreateRegistrar {
+                registrar: registrar_key,
+                realm: realm_cookie.address,
+                governance_program_id: self.governance.program_id,
+                governing_token_mint: realm_cookie.account.community_mint,
+                realm_authority: realm_cookie.get_realm_authority().pubkey(),
+                payer: self.bench.payer.pubkey(),
+                system_program: solana_sdk::system_program::id(),
+            },
+            None,
+        );
+
+        let mut create_registrar_ix = Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts,
+            data,
+        };
+
+        instruction_override(&mut create_registrar_ix);
+
+        let default_signers = &[&realm_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[create_registrar_ix], Some(signers))
+            .await?;
+
+        let account = Registrar {
+            governance_program_id: self.governance.program_id,
+            realm: realm_cookie.address,
+            governing_token_mint: realm_cookie.account.community_mint,
+            mint_configs: vec![],
+            max_voter_weight: 0,
+            reserved: [0; 128],
+        };
+
+        Ok(RegistrarCookie {
+            address: registrar_key,
+            account,
+            realm_authority: realm_cookie.get_realm_authority(),
+            max_mints,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn resize_registrar(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        max_mints: u8,
+    ) -> Result<(), BanksClientError> {
+        self.resize_registrar_using_ix(registrar_cookie, max_mints, NopOverride, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn resize_registrar_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        max_mints: u8,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_token_haver::instruction::ResizeRegistrar {
+                max_mints,
+            });
+
+        let accounts = anchor_lang::ToAccountMetas::to_account_metas(
+            &gpl_token_haver::accounts::ResizeRegistrar {
+                registrar: registrar_cookie.address,
+                realm: registrar_cookie.account.realm,
+                realm_authority: registrar_cookie.realm_authority.pubkey(),
+                payer: self.bench.payer.pubkey(),
+                system_program: solana_sdk::system_program::id(),
+            },
+            None,
+        );
+
+        let mut resize_registrar_ix = Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts,
+            data,
+        };
+
+        instruction_override(&mut resize_registrar_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[resize_registrar_ix], Some(signers))
+            .await?;
+
+        registrar_cookie.max_mints = max_mints;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &WalletCookie,
+    ) -> Result<VoterWeightRecordCookie, BanksClientError> {
+        self.with_voter_weight_record_using_ix(registrar_cookie, voter_cookie, NopOverride)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &WalletCookie,
+        instruction_override: F,
+    ) -> Result<VoterWeightRecordCookie, BanksClientError> {
+        let governing_token_owner = voter_cookie.address;
+
+        let (voter_weight_record_key, _) = Pubkey::find_program_address(
+            &[
+                b"voter-weight-record".as_ref(),
+                registrar_cookie.account.realm.as_ref(),
+                registrar_cookie.account.governing_token_mint.as_ref(),
+                governing_token_owner.as_ref(),
+            ],
+            &gpl_token_haver::id(),
+        );
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_token_haver::instruction::CreateVoterWeightRecord {
+                governing_token_owner,
+            },
+        );
+
+        let accounts = gpl_token_haver::accounts::CreateVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_key,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut create_voter_weight_record_ix = Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut create_voter_weight_record_ix);
+
+        self.bench
+            .process_transaction(&[create_voter_weight_record_ix], None)
+            .await?;
+
+        let account = VoterWeightRecord {
+            realm: registrar_cookie.account.realm,
+            governing_token_mint: registrar_cookie.account.governing_token_mint,
+            governing_token_owner,
+            voter_weight: 0,
+            voter_weight_expiry: Some(0),
+            weight_action: None,
+            weight_action_target: None,
+            reserved: [0; 8],
+        };
+
+        Ok(VoterWeightRecordCookie {
+            address: voter_weight_record_key,
+            account,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_max_voter_weight_record(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+    ) -> Result<MaxVoterWeightRecordCookie, BanksClientError> {
+        self.with_max_voter_weight_record_using_ix(registrar_cookie, NopOverride)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_max_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        instruction_override: F,
+    ) -> Result<MaxVoterWeightRecordCookie, BanksClientError> {
+        let max_voter_weight_record_key = get_max_voter_weight_record_address(
+            &registrar_cookie.account.realm,
+            &registrar_cookie.account.governing_token_mint,
+        );
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_token_haver::instruction::CreateMaxVoterWeightRecord {},
+        );
+
+        let accounts = gpl_token_haver::accounts::CreateMaxVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            max_voter_weight_record: max_voter_weight_record_key,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut create_max_voter_weight_record_ix = Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut create_max_voter_weight_record_ix);
+
+        self.bench
+            .process_transaction(&[create_max_voter_weight_record_ix], None)
+            .await?;
+
+        let account = MaxVoterWeightRecord {
+            realm: registrar_cookie.account.realm,
+            governing_token_mint: registrar_cookie.account.governing_token_mint,
+            max_voter_weight: 0,
+            max_voter_weight_expiry: Some(0),
+            reserved: [0; 8],
+        };
+
+        Ok(MaxVoterWeightRecordCookie {
+            account,
+            address: max_voter_weight_record_key,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &mut VoterWeightRecordCookie,
+        token_account_cookies: &[&TokenAccountCookie],
+    ) -> Result<(), BanksClientError> {
+        self.update_voter_weight_record_using_ix(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            token_account_cookies,
+            NopOverride,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &mut VoterWeightRecordCookie,
+        token_account_cookies: &[&TokenAccountCookie],
+        instruction_override: F,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_token_haver::instruction::UpdateVoterWeightRecord {},
+        );
+
+        let accounts = gpl_token_haver::accounts::UpdateVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        for token_account_cookie in token_account_cookies {
+            account_metas.push(AccountMeta::new_readonly(
+                token_account_cookie.address,
+                false,
+            ));
+        }
+
+        let mut update_voter_weight_record_ix = Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        instruction_override(&mut update_voter_weight_record_ix);
+
+        self.bench
+            .process_transaction(&[update_voter_weight_record_ix], None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_max_voter_weight(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &mut MaxVoterWeightRecordCookie,
+        max_voter_weight: u64,
+    ) -> Result<(), BanksClientError> {
+        self.configure_max_voter_weight_using_ix(
+            registrar_cookie,
+            max_voter_weight_record_cookie,
+            max_voter_weight,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_max_voter_weight_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &mut MaxVoterWeightRecordCookie,
+        max_voter_weight: u64,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_token_haver::instruction::ConfigureMaxVoterWeight { max_voter_weight },
+        );
+
+        let accounts = gpl_token_haver::accounts::ConfigureMaxVoterWeight {
+            registrar: registrar_cookie.address,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+        };
+
+        let account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        let mut configure_max_voter_weight_ix = Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts: account_metas,
+            data,
+        };
+        instruction_override(&mut configure_max_voter_weight_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_max_voter_weight_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_mint(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        mint_cookie: &MintCookie,
+        change_type: CollectionItemChangeType,
+        voter_weight: u64,
+    ) -> Result<MintConfigCookie, BanksClientError> {
+        self.configure_mint_using_ix(
+            registrar_cookie,
+            mint_cookie,
+            change_type,
+            voter_weight,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_mint_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        mint_cookie: &MintCookie,
+        change_type: CollectionItemChangeType,
+        voter_weight: u64,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<MintConfigCookie, BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_token_haver::instruction::ConfigureMint {
+                change_type,
+                voter_weight,
+            });
+
+        let accounts = gpl_token_haver::accounts::ConfigureMint {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            mint: mint_cookie.address,
+        };
+
+        let mut configure_mint_ix = Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_mint_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_mint_ix], Some(signers))
+            .await?;
+
+        let mint_config = MintConfig {
+            mint: mint_cookie.address,
+            voter_weight,
+            reserved: [0; 8],
+        };
+
+        Ok(MintConfigCookie { mint_config })
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_registrar_account(&mut self, registrar: &Pubkey) -> Registrar {
+        self.bench.get_anchor_account::<Registrar>(*registrar).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_max_voter_weight_record(
+        &self,
+        max_voter_weight_record: &Pubkey,
+    ) -> MaxVoterWeightRecord {
+        self.bench
+            .get_anchor_account(*max_voter_weight_record)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_voter_weight_record(&self, voter_weight_record: &Pubkey) -> VoterWeightRecord {
+        self.bench.get_anchor_account(*voter_weight_record).await
+    }
+}
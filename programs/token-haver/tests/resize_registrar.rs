@@ -0,0 +1,119 @@
+use gpl_token_haver::error::TokenHaverError;
+use gpl_token_haver::state::{CollectionItemChangeType, Registrar};
+use program_test::token_haver_test::TokenHaverTest;
+use program_test::tools::assert_token_haver_err;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_resize_registrar() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    let mint_config_cookie = token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie,
+            CollectionItemChangeType::Upsert,
+            10,
+        )
+        .await?;
+
+    let max_mints = registrar_cookie.max_mints + 5;
+
+    // Act
+    token_haver_test
+        .resize_registrar(&mut registrar_cookie, max_mints)
+        .await?;
+
+    // Assert
+    let registrar_account = token_haver_test
+        .bench
+        .get_account(&registrar_cookie.address)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        registrar_account.data.len(),
+        Registrar::get_space(max_mints)
+    );
+
+    let registrar = token_haver_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.mint_configs.len(), 1);
+    assert_eq!(registrar.mint_configs[0], mint_config_cookie.mint_config);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resize_registrar_with_invalid_max_mints_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie,
+            CollectionItemChangeType::Upsert,
+            10,
+        )
+        .await?;
+
+    // Act
+    let err = token_haver_test
+        .resize_registrar(&mut registrar_cookie, 0)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::InvalidMaxMints);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resize_registrar_with_invalid_realm_authority_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = token_haver_test
+        .resize_registrar_using_ix(
+            &mut registrar_cookie,
+            15,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::InvalidRealmAuthority);
+
+    Ok(())
+}
@@ -0,0 +1,376 @@
+use gpl_token_haver::error::TokenHaverError;
+use gpl_token_haver::state::*;
+use program_test::token_haver_test::TokenHaverTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_update_voter_weight_record() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie,
+            CollectionItemChangeType::Upsert,
+            10,
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie = token_haver_test
+        .bench
+        .with_tokens(&mint_cookie, &voter_cookie.address, 1)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    token_haver_test.bench.advance_clock().await;
+    let clock = token_haver_test.bench.get_clock().await;
+
+    // Act
+    token_haver_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[&token_account_cookie],
+        )
+        .await?;
+
+    // Assert
+
+    let voter_weight_record = token_haver_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+    assert_eq!(voter_weight_record.weight_action, None);
+    assert_eq!(voter_weight_record.weight_action_target, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_multiple_mints() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie1 = token_haver_test.bench.with_mint().await?;
+    let mint_cookie2 = token_haver_test.bench.with_mint().await?;
+
+    token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie1,
+            CollectionItemChangeType::Upsert,
+            10,
+        )
+        .await?;
+
+    token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie2,
+            CollectionItemChangeType::Upsert,
+            25,
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie1 = token_haver_test
+        .bench
+        .with_tokens(&mint_cookie1, &voter_cookie.address, 100)
+        .await?;
+
+    let token_account_cookie2 = token_haver_test
+        .bench
+        .with_tokens(&mint_cookie2, &voter_cookie.address, 1)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    token_haver_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[&token_account_cookie1, &token_account_cookie2],
+        )
+        .await?;
+
+    // Assert
+
+    // Holding tokens of several mints doesn't accumulate the voter weight and the highest mint voter weight is used
+    let voter_weight_record = token_haver_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 25);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_empty_token_account() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie,
+            CollectionItemChangeType::Upsert,
+            10,
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie = token_haver_test
+        .bench
+        .with_tokens(&mint_cookie, &voter_cookie.address, 0)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    token_haver_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[&token_account_cookie],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = token_haver_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_mint_not_configured_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie = token_haver_test
+        .bench
+        .with_tokens(&mint_cookie, &voter_cookie.address, 1)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = token_haver_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[&token_account_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::MintNotConfigured);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_voter_does_not_own_token_account_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie,
+            CollectionItemChangeType::Upsert,
+            10,
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+    let voter_cookie2 = token_haver_test.bench.with_wallet().await;
+
+    // Try to use tokens owned by another voter
+    let token_account_cookie = token_haver_test
+        .bench
+        .with_tokens(&mint_cookie, &voter_cookie2.address, 1)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = token_haver_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[&token_account_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::VoterDoesNotOwnTokenAccount);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_token_account_frozen_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie,
+            CollectionItemChangeType::Upsert,
+            10,
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie = token_haver_test
+        .bench
+        .with_tokens(&mint_cookie, &voter_cookie.address, 1)
+        .await?;
+
+    token_haver_test
+        .bench
+        .freeze_token_account(&mint_cookie, &token_account_cookie.address)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = token_haver_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[&token_account_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::TokenAccountFrozen);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_duplicated_token_account_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    token_haver_test
+        .configure_mint(
+            &registrar_cookie,
+            &mint_cookie,
+            CollectionItemChangeType::Upsert,
+            10,
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie = token_haver_test
+        .bench
+        .with_tokens(&mint_cookie, &voter_cookie.address, 1)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = token_haver_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[&token_account_cookie, &token_account_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::DuplicatedTokenAccountDetected);
+
+    Ok(())
+}